@@ -24,7 +24,9 @@ pub enum Error {
     Stream(Box<StreamError>),
     /// Error from converting received message to filter
     #[error("Can't retrieve subscription filter: {0}")]
-    CantRetrieveSubscriptionFilter(#[from] ErrorTryFromEnum<EventSubscriberMessage, FilterBox>),
+    CantRetrieveSubscriptionFilter(
+        #[from] ErrorTryFromEnum<EventSubscriberMessage, EventSubscriptionRequest>,
+    ),
     /// Error, that occurs when client answered not with `EventReceived` message
     #[error("Got unexpected response. Expected `EventReceived`")]
     ExpectedEventReceived,
@@ -35,6 +37,16 @@ pub enum Error {
     /// Error that occurs than `WebSocket::next()` call returns `None`
     #[error("Can't receive message from stream")]
     CantReceiveMessage,
+    /// Error that occurs when the data events of the requested block are no longer kept
+    #[error(
+        "Events of block {requested} are no longer kept, the earliest kept block is {earliest}"
+    )]
+    FromHeightNotKept {
+        /// Requested height to start the subscription from
+        requested: u64,
+        /// Height of the earliest block whose events are kept
+        earliest: u64,
+    },
 }
 
 impl From<StreamError> for Error {
@@ -46,23 +58,79 @@ impl From<StreamError> for Error {
 /// Result type for `Consumer`
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Position of an event among the events of the committed blocks, which
+/// identifies it across subscriptions. Written as `<block_height>:<index>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventPosition {
+    /// Height of the block which produced the event.
+    pub block_height: u64,
+    /// Position of the event among all events of the block.
+    pub index: u64,
+}
+
+impl EventPosition {
+    /// Parse the position from `<block_height>:<index>`.
+    pub fn parse(position: &str) -> Option<Self> {
+        let (block_height, index) = position.trim().split_once(':')?;
+        Some(Self {
+            block_height: block_height.parse().ok()?,
+            index: index.parse().ok()?,
+        })
+    }
+}
+
+impl core::fmt::Display for EventPosition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.block_height, self.index)
+    }
+}
+
+/// Let the subscriber know why the subscription is rejected or ended.
+async fn send_rejection(stream: &mut WebSocket, error: &Error) -> Result<()> {
+    stream
+        .send(VersionedEventPublisherMessage::from(
+            EventPublisherMessage::SubscriptionRejected(error.to_string()),
+        ))
+        .await?;
+    Ok(())
+}
+
 /// Consumer for Iroha `Event`(s).
 /// Passes the events over the corresponding connection `stream` if they match the `filter`.
 #[derive(Debug)]
 pub struct Consumer {
     stream: WebSocket,
     filter: FilterBox,
+    from_height: Option<u64>,
+    sequence_number: u64,
 }
 
 impl Consumer {
     /// Constructs `Consumer`, which consumes `Event`s and forwards it through the `stream`.
     ///
+    /// Subscriptions from a height below `earliest_height`, whose events are no
+    /// longer kept, are rejected.
+    ///
     /// # Errors
-    /// Can fail due to timeout or without message at websocket or during decoding request
+    /// Can fail due to timeout or without message at websocket or during decoding request,
+    /// or if the subscription is rejected
     #[iroha_futures::telemetry_future]
-    pub async fn new(mut stream: WebSocket) -> Result<Self> {
+    pub async fn new(mut stream: WebSocket, earliest_height: u64) -> Result<Self> {
         let subscription_request: VersionedEventSubscriberMessage = stream.recv().await?;
-        let filter = subscription_request.into_v1().try_into()?;
+        let EventSubscriptionRequest {
+            filter,
+            from_height,
+        } = subscription_request.into_v1().try_into()?;
+
+        if let Some(requested) = from_height.filter(|height| *height < earliest_height) {
+            let error = Error::FromHeightNotKept {
+                requested,
+                earliest: earliest_height,
+            };
+            send_rejection(&mut stream, &error).await?;
+            stream.close().await?;
+            return Err(error);
+        }
 
         stream
             .send(VersionedEventPublisherMessage::from(
@@ -70,27 +138,46 @@ impl Consumer {
             ))
             .await?;
 
-        Ok(Consumer { stream, filter })
+        Ok(Consumer {
+            stream,
+            filter,
+            from_height,
+            sequence_number: 0,
+        })
+    }
+
+    /// Height of the committed block the subscription was requested to start from.
+    pub const fn from_height(&self) -> Option<u64> {
+        self.from_height
     }
 
     /// Forwards the `event` over the `stream` if it matches the `filter`.
     ///
+    /// `position` is the position of the `event` among the events of the
+    /// committed blocks, if it's known.
+    ///
     /// # Errors
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
-    pub async fn consume(&mut self, event: Event) -> Result<()> {
+    pub async fn consume(&mut self, event: Event, position: Option<EventPosition>) -> Result<()> {
         if !self.filter.matches(&event) {
             return Ok(());
         }
 
         self.stream
             .send(VersionedEventPublisherMessage::from(
-                EventPublisherMessage::from(event),
+                EventPublisherMessage::from(SequencedEvent {
+                    sequence_number: self.sequence_number,
+                    block_height: position.map(|position| position.block_height),
+                    index_in_block: position.map(|position| position.index),
+                    event,
+                }),
             ))
             .await?;
 
         let message: VersionedEventSubscriberMessage = self.stream.recv().await?;
         if let EventSubscriberMessage::EventReceived = message.into_v1() {
+            self.sequence_number += 1;
             Ok(())
         } else {
             Err(Error::ExpectedEventReceived)
@@ -111,6 +198,16 @@ impl Consumer {
         Err(Error::CantReceiveMessage)
     }
 
+    /// End the subscription because of `error`, e.g. after the subscriber
+    /// fell so far behind that the events it needs are no longer kept.
+    ///
+    /// # Errors
+    /// Fails if the error can't be sent or the stream can't be closed
+    pub async fn reject(mut self, error: &Error) -> Result<()> {
+        send_rejection(&mut self.stream, error).await?;
+        self.close_stream().await
+    }
+
     /// Close stream. See [`WebSocket::close()`]
     ///
     /// # Errors
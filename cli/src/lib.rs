@@ -221,7 +221,7 @@ where
             config.sumeragi.trusted_peers.peers.clone(),
         );
         let wsv = Arc::new(WorldStateView::from_configuration(
-            config.wsv,
+            config.wsv.clone(),
             world,
             events_sender.clone(),
         ));
@@ -230,6 +230,7 @@ where
 
         let transaction_validator = TransactionValidator::new(
             config.sumeragi.transaction_limits,
+            config.genesis.chain_id.clone(),
             Arc::from(instruction_judge),
             Arc::clone(&query_judge),
             Arc::clone(&wsv),
@@ -244,7 +245,11 @@ where
 
         let notify_shutdown = Arc::new(Notify::new());
 
-        let queue = Arc::new(Queue::from_configuration(&config.queue, Arc::clone(&wsv)));
+        let queue = Arc::new(Queue::from_configuration(
+            &config.queue,
+            config.genesis.chain_id.clone(),
+            Arc::clone(&wsv),
+        ));
         let telemetry_started = Self::start_telemetry(telemetry, &config).await?;
         let kura = Kura::from_configuration(&config.kura, Arc::clone(&wsv), broker.clone())?;
 
@@ -254,7 +259,7 @@ where
             &config.sumeragi,
             events_sender.clone(),
             Arc::clone(&wsv),
-            transaction_validator,
+            transaction_validator.clone(),
             telemetry_started,
             genesis,
             Arc::clone(&queue),
@@ -284,6 +289,7 @@ where
             Arc::clone(&wsv),
             Arc::clone(&queue),
             query_judge,
+            transaction_validator,
             events_sender,
             network_addr.clone(),
             Arc::clone(&notify_shutdown),
@@ -0,0 +1,361 @@
+//! Storage for the rest of paginated query results, so that clients
+//! can fetch the following pages with a [`Cursor`] instead of
+//! re-executing the query against a possibly changed world state.
+//!
+//! The number and the total size of the results kept for every account
+//! are bounded. The oldest cursors of the account are evicted to make
+//! room for its new ones, so that one account can't flush the cursors
+//! of the others.
+#![allow(clippy::std_instead_of_core, clippy::arithmetic)]
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use iroha_core::smartcontracts::isi::query::Error as QueryError;
+use iroha_data_model::{predicate::PredicateBox, prelude::*};
+use parity_scale_codec::Encode;
+
+/// Query result kept on the peer between cursor requests.
+#[derive(Debug)]
+struct StoredQuery {
+    account_id: AccountId,
+    query: QueryBox,
+    /// Filter and sorting the values were produced with
+    filter: PredicateBox,
+    sorting: Sorting,
+    values: Vec<Value>,
+    /// Encoded size of `values`
+    size: usize,
+    expires_at: Instant,
+}
+
+/// Page of a stored query result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    /// Values on this page.
+    pub values: Vec<Value>,
+    /// Length of the whole result.
+    pub total: u64,
+    /// Cursor to the next page, if there is one.
+    pub cursor: Option<Cursor>,
+}
+
+/// Expiring store of query results, addressed by [`Cursor`].
+#[derive(Debug)]
+pub struct CursorStore {
+    ttl: Duration,
+    max_cursors_per_account: usize,
+    max_size_per_account: usize,
+    next_query_id: AtomicU64,
+    /// Stored queries by increasing id, i.e. from the oldest to the newest
+    queries: Mutex<BTreeMap<u64, StoredQuery>>,
+}
+
+impl CursorStore {
+    /// Construct [`Self`] which keeps results for `ttl` after the last access.
+    ///
+    /// At most `max_cursors_per_account` results are kept for every account,
+    /// with their total encoded size not exceeding `max_size_per_account` bytes.
+    pub fn new(ttl: Duration, max_cursors_per_account: u32, max_size_per_account: u64) -> Self {
+        Self {
+            ttl,
+            max_cursors_per_account: usize::try_from(max_cursors_per_account).unwrap_or(usize::MAX),
+            max_size_per_account: usize::try_from(max_size_per_account).unwrap_or(usize::MAX),
+            next_query_id: AtomicU64::new(0),
+            queries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn queries(&self) -> MutexGuard<'_, BTreeMap<u64, StoredQuery>> {
+        self.queries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Keep `values` of `query` made by `account_id`, filtered with `filter`
+    /// and sorted with `sorting`, and return a cursor pointing at `position`
+    /// in them. Returns `None` if cursors are disabled.
+    ///
+    /// Evicts the oldest cursors of `account_id` to stay within the limits.
+    ///
+    /// # Errors
+    /// Fails with [`QueryError::ResultTooLarge`] if `values` can't be kept
+    /// within the limits at all.
+    pub fn insert(
+        &self,
+        account_id: AccountId,
+        query: QueryBox,
+        filter: PredicateBox,
+        sorting: Sorting,
+        values: Vec<Value>,
+        position: u64,
+    ) -> Result<Option<Cursor>, QueryError> {
+        if self.max_cursors_per_account == 0 {
+            return Ok(None);
+        }
+        let size = values.encoded_size();
+        if size > self.max_size_per_account {
+            return Err(QueryError::ResultTooLarge);
+        }
+
+        let query_id = self.next_query_id.fetch_add(1, Ordering::Relaxed);
+        let mut queries = self.queries();
+        Self::prune(&mut queries);
+
+        let account_queries: Vec<_> = queries
+            .iter()
+            .filter(|(_, stored)| stored.account_id == account_id)
+            .map(|(query_id, stored)| (*query_id, stored.size))
+            .collect();
+        let mut count = account_queries.len() + 1;
+        let mut account_size = account_queries.iter().map(|(_, size)| size).sum::<usize>() + size;
+        for (evicted_id, evicted_size) in account_queries {
+            if count <= self.max_cursors_per_account && account_size <= self.max_size_per_account {
+                break;
+            }
+            queries.remove(&evicted_id);
+            count -= 1;
+            account_size -= evicted_size;
+        }
+
+        queries.insert(
+            query_id,
+            StoredQuery {
+                account_id,
+                query,
+                filter,
+                sorting,
+                values,
+                size,
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+        Ok(Some(Cursor::new(query_id, position)))
+    }
+
+    /// Take at most `limit` values starting from `cursor`.
+    ///
+    /// # Errors
+    /// - [`QueryError::UnknownCursor`] if the cursor is unknown, has expired
+    /// or was issued for another account or query.
+    /// - [`QueryError::CursorMismatch`] if the cursor was issued for the query
+    /// with another `filter` or `sorting`, as the pages would be at odds with them.
+    pub fn next_page(
+        &self,
+        cursor: Cursor,
+        account_id: &AccountId,
+        query: &QueryBox,
+        filter: &PredicateBox,
+        sorting: &Sorting,
+        limit: Option<u32>,
+    ) -> Result<Page, QueryError> {
+        let mut queries = self.queries();
+        Self::prune(&mut queries);
+        let stored = queries
+            .get_mut(&cursor.query_id())
+            .filter(|stored| &stored.account_id == account_id && &stored.query == query)
+            .ok_or(QueryError::UnknownCursor)?;
+        if &stored.filter != filter || &stored.sorting != sorting {
+            return Err(QueryError::CursorMismatch);
+        }
+
+        let total = stored.values.len();
+        let start = usize::try_from(cursor.position())
+            .map_err(|_| QueryError::UnknownCursor)?
+            .min(total);
+        let end = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .map_or(total, |limit| start.saturating_add(limit).min(total));
+        let values = stored.values[start..end].to_vec();
+
+        let cursor = if end < total {
+            stored.expires_at = Instant::now() + self.ttl;
+            Some(Cursor::new(cursor.query_id(), end as u64))
+        } else {
+            queries.remove(&cursor.query_id());
+            None
+        };
+        Ok(Page {
+            values,
+            total: total as u64,
+            cursor,
+        })
+    }
+
+    fn prune(queries: &mut BTreeMap<u64, StoredQuery>) {
+        let now = Instant::now();
+        queries.retain(|_, stored| stored.expires_at > now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::restriction)]
+
+    use std::str::FromStr as _;
+
+    use iroha_data_model::predicate::{string, value};
+
+    use super::*;
+
+    fn query() -> QueryBox {
+        FindAllAccounts::new().into()
+    }
+
+    fn insert(
+        store: &CursorStore,
+        account_id: &AccountId,
+        values: Vec<Value>,
+        position: u64,
+    ) -> Result<Option<Cursor>, QueryError> {
+        store.insert(
+            account_id.clone(),
+            query(),
+            PredicateBox::default(),
+            Sorting::default(),
+            values,
+            position,
+        )
+    }
+
+    fn next_page(
+        store: &CursorStore,
+        cursor: Cursor,
+        account_id: &AccountId,
+        limit: Option<u32>,
+    ) -> Result<Page, QueryError> {
+        store.next_page(
+            cursor,
+            account_id,
+            &query(),
+            &PredicateBox::default(),
+            &Sorting::default(),
+            limit,
+        )
+    }
+
+    #[test]
+    fn pages_follow_cursor_until_exhausted() {
+        let store = CursorStore::new(Duration::from_secs(60), 16, u64::MAX);
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+        let values: Vec<_> = (0_u32..5).map(Value::U32).collect();
+
+        let cursor = insert(&store, &alice, values, 2)
+            .expect("Within limits")
+            .expect("Cursors are enabled");
+        let page = next_page(&store, cursor, &alice, Some(2)).expect("Cursor is known");
+        assert_eq!(page.values, vec![Value::U32(2), Value::U32(3)]);
+        assert_eq!(page.total, 5);
+
+        let page = next_page(
+            &store,
+            page.cursor.expect("One value left"),
+            &alice,
+            Some(2),
+        )
+        .expect("Cursor is known");
+        assert_eq!(page.values, vec![Value::U32(4)]);
+        assert!(page.cursor.is_none());
+        assert!(matches!(
+            next_page(&store, cursor, &alice, None),
+            Err(QueryError::UnknownCursor)
+        ));
+    }
+
+    #[test]
+    fn cursor_is_bound_to_account_and_expires() {
+        let store = CursorStore::new(Duration::from_millis(10), 16, u64::MAX);
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+        let bob = AccountId::from_str("bob@wonderland").expect("Valid");
+
+        let cursor = insert(&store, &alice, vec![Value::U32(0); 4], 1)
+            .expect("Within limits")
+            .expect("Cursors are enabled");
+        assert!(next_page(&store, cursor, &bob, Some(1)).is_err());
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(next_page(&store, cursor, &alice, Some(1)).is_err());
+    }
+
+    #[test]
+    fn cursor_is_bound_to_filter_and_sorting() {
+        let store = CursorStore::new(Duration::from_secs(60), 16, u64::MAX);
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+
+        let cursor = insert(&store, &alice, vec![Value::U32(0); 4], 1)
+            .expect("Within limits")
+            .expect("Cursors are enabled");
+        let sorting = Sorting::by_keys([SortKey::descending(SortField::Id)]);
+        assert!(matches!(
+            store.next_page(
+                cursor,
+                &alice,
+                &query(),
+                &PredicateBox::default(),
+                &sorting,
+                Some(1)
+            ),
+            Err(QueryError::CursorMismatch)
+        ));
+        let filter = PredicateBox::new(value::Predicate::Display(string::Predicate::is("alice")));
+        assert!(matches!(
+            store.next_page(
+                cursor,
+                &alice,
+                &query(),
+                &filter,
+                &Sorting::default(),
+                Some(1)
+            ),
+            Err(QueryError::CursorMismatch)
+        ));
+        assert!(next_page(&store, cursor, &alice, Some(1)).is_ok());
+    }
+
+    #[test]
+    fn oldest_cursors_of_account_are_evicted_over_limits() {
+        let values = vec![Value::U32(0); 4];
+        let size = values.encoded_size() as u64;
+        let store = CursorStore::new(Duration::from_secs(60), 3, 2 * size);
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+        let bob = AccountId::from_str("bob@wonderland").expect("Valid");
+        let keep = |account_id: &AccountId| {
+            insert(&store, account_id, values.clone(), 1)
+                .expect("Within limits")
+                .expect("Cursors are enabled")
+        };
+
+        let alice_first = keep(&alice);
+        let alice_second = keep(&alice);
+        let alice_third = keep(&alice);
+        assert!(next_page(&store, alice_first, &alice, Some(1)).is_err());
+        assert!(next_page(&store, alice_second, &alice, Some(1)).is_ok());
+
+        // Cursors of another account don't count towards the limits of Alice
+        let bob_first = keep(&bob);
+        let bob_second = keep(&bob);
+        assert!(next_page(&store, alice_second, &alice, Some(1)).is_ok());
+        assert!(next_page(&store, alice_third, &alice, Some(1)).is_ok());
+        assert!(next_page(&store, bob_first, &bob, Some(1)).is_ok());
+        assert!(next_page(&store, bob_second, &bob, Some(1)).is_ok());
+
+        // Result which doesn't fit at all
+        assert!(matches!(
+            insert(&store, &alice, vec![Value::U32(0); 16], 1),
+            Err(QueryError::ResultTooLarge)
+        ));
+    }
+
+    #[test]
+    fn no_cursors_when_disabled() {
+        let store = CursorStore::new(Duration::from_secs(60), 0, u64::MAX);
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+        assert!(matches!(
+            insert(&store, &alice, vec![Value::U32(0); 4], 1),
+            Ok(None)
+        ));
+    }
+}
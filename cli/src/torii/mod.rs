@@ -15,6 +15,7 @@ use iroha_core::{
     prelude::*,
     queue::{self, Queue},
     smartcontracts::isi::query,
+    tx::TransactionValidator,
     EventsSender, IrohaNetwork,
 };
 use thiserror::Error;
@@ -29,7 +30,9 @@ use warp::{
 
 #[macro_use]
 pub(crate) mod utils;
+pub mod cursor;
 pub mod routing;
+pub mod webhook;
 
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
@@ -38,6 +41,8 @@ pub struct Torii {
     queue: Arc<Queue>,
     events: EventsSender,
     query_judge: QueryJudgeArc,
+    transaction_validator: TransactionValidator,
+    cursors: Arc<cursor::CursorStore>,
     network: iroha_actor::Addr<IrohaNetwork>,
     notify_shutdown: Arc<Notify>,
 }
@@ -48,6 +53,9 @@ pub enum Error {
     /// Failed to execute or validate query
     #[error("Failed to execute or validate query")]
     Query(#[from] query::Error),
+    /// Failed to sign query response
+    #[error("Failed to sign query response")]
+    SignQueryResponse(#[source] iroha_crypto::Error),
     /// Failed to decode transaction
     #[error("Failed to decode transaction")]
     VersionedTransaction(#[source] iroha_version::error::Error),
@@ -72,6 +80,9 @@ pub enum Error {
     /// Failed to push into queue
     #[error("Failed to push into queue")]
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Transaction is meant for another chain
+    #[error("Transaction is meant for another chain")]
+    ChainIdMismatch,
     #[cfg(feature = "telemetry")]
     /// Error while getting status
     #[error("Failed to get status")]
@@ -89,10 +100,13 @@ pub enum Error {
 pub(crate) const fn query_status_code(query_error: &query::Error) -> StatusCode {
     use query::Error::*;
     match query_error {
-        Decode(_) | Evaluate(_) | Conversion(_) => StatusCode::BAD_REQUEST,
+        Decode(_) | Evaluate(_) | Conversion(_) | ChainIdMismatch | CursorMismatch => {
+            StatusCode::BAD_REQUEST
+        }
         Signature(_) | Unauthorized => StatusCode::UNAUTHORIZED,
         Permission(_) => StatusCode::FORBIDDEN,
-        Find(_) => StatusCode::NOT_FOUND,
+        Find(_) | UnknownCursor => StatusCode::NOT_FOUND,
+        ResultTooLarge => StatusCode::INSUFFICIENT_STORAGE,
     }
 }
 
@@ -125,6 +139,7 @@ impl Error {
             AcceptTransaction(_)
             | RequestPendingTransactions(_)
             | ConfigurationReload(_)
+            | ChainIdMismatch
             | TxTooBig => StatusCode::BAD_REQUEST,
             Config(_) => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
@@ -132,6 +147,7 @@ impl Error {
                 queue::Error::SignatureCondition { .. } => StatusCode::UNAUTHORIZED,
                 _ => StatusCode::BAD_REQUEST,
             },
+            SignQueryResponse(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "telemetry")]
             Prometheus(_) | Status(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
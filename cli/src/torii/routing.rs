@@ -2,7 +2,7 @@
 //! Iroha you should add it here by creating a `handle_*` function,
 //! and add it to impl Torii. This module also defines the `VerifiedQueryRequest`,
 //! which is the only kind of query that is permitted to execute.
-use std::num::TryFromIntError;
+use std::{num::TryFromIntError, time::Duration};
 
 use eyre::WrapErr;
 use iroha_actor::Addr;
@@ -19,10 +19,10 @@ use iroha_core::{
     },
     smartcontracts::{
         isi::query::{Error as QueryError, ValidQueryRequest},
-        permissions::prelude::*,
+        permissions::{check_query_permissions, prelude::*},
     },
 };
-use iroha_crypto::SignatureOf;
+use iroha_crypto::{KeyPair, SignatureOf};
 use iroha_data_model::{
     predicate::PredicateBox,
     prelude::*,
@@ -33,11 +33,14 @@ use iroha_telemetry::metrics::Status;
 use parity_scale_codec::{Decode, Encode};
 use tokio::task;
 
-use super::*;
-use crate::stream::{Sink, Stream};
+use super::{cursor::CursorStore, *};
+use crate::{
+    event::EventPosition,
+    stream::{Sink, Stream},
+};
 
 /// Query Request verified on the Iroha node side.
-#[derive(Debug, Decode, Encode)]
+#[derive(Debug, Clone, Decode, Encode)]
 pub struct VerifiedQueryRequest {
     /// Payload.
     payload: query::Payload,
@@ -53,11 +56,16 @@ impl VerifiedQueryRequest {
     /// - Account doesn't exist.
     /// - Account doesn't have the correct public key.
     /// - Account has incorrect permissions.
+    /// - Query is meant for another chain.
     pub fn validate(
         self,
+        chain_id: &ChainId,
         wsv: &WorldStateView,
         query_judge: &dyn Judge<Operation = QueryBox>,
     ) -> Result<(ValidQueryRequest, PredicateBox), QueryError> {
+        if &self.payload.chain_id != chain_id {
+            return Err(QueryError::ChainIdMismatch);
+        }
         let account_has_public_key = wsv.map_account(&self.payload.account_id, |account| {
             account.contains_signatory(self.signature.public_key())
         })?;
@@ -66,9 +74,13 @@ impl VerifiedQueryRequest {
                 "Signature public key doesn't correspond to the account.",
             )));
         }
-        query_judge
-            .judge(&self.payload.account_id, &self.payload.query, wsv)
-            .map_err(QueryError::Permission)?;
+        check_query_permissions(
+            &self.payload.account_id,
+            &self.payload.query,
+            wsv,
+            query_judge,
+        )
+        .map_err(QueryError::Permission)?;
         Ok((
             ValidQueryRequest::new(self.payload.query),
             self.payload.filter,
@@ -115,68 +127,158 @@ pub(crate) async fn handle_instructions(
 }
 
 #[iroha_futures::telemetry_future]
+pub(crate) async fn handle_simulate_transaction(
+    iroha_cfg: Configuration,
+    transaction_validator: TransactionValidator,
+    transaction: VersionedTransaction,
+) -> Result<Scale<VersionedTransactionSimulation>> {
+    let transaction: Transaction = transaction.into_v1();
+    if transaction.payload.chain_id != iroha_cfg.genesis.chain_id {
+        return Err(Error::ChainIdMismatch);
+    }
+    let transaction = VersionedAcceptedTransaction::from_transaction(
+        transaction,
+        &iroha_cfg.sumeragi.transaction_limits,
+    )
+    .map_err(Error::AcceptTransaction)?;
+    Ok(Scale(
+        transaction_validator.simulate(transaction.as_v1()).into(),
+    ))
+}
+
+#[iroha_futures::telemetry_future]
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn handle_queries(
+    chain_id: ChainId,
     wsv: Arc<WorldStateView>,
     query_judge: QueryJudgeArc,
+    cursors: Arc<CursorStore>,
+    key_pair: KeyPair,
     pagination: Pagination,
     sorting: Sorting,
+    projection: Projection,
+    cursor: CursorRequest,
+    signing: ResponseSigningRequest,
     request: VerifiedQueryRequest,
 ) -> Result<Scale<VersionedPaginatedQueryResult>> {
-    let (valid_request, filter) = request.validate(&wsv, query_judge.as_ref())?;
-    let original_result = valid_request.execute(&wsv)?;
-    let result = filter.filter(original_result);
+    let account_id = request.payload.account_id.clone();
+    let query_hash = request.payload.hash();
+    let (valid_request, filter) = request.validate(&chain_id, &wsv, query_judge.as_ref())?;
+
+    if let Some(cursor) = cursor.cursor {
+        let page = cursors.next_page(
+            cursor,
+            &account_id,
+            valid_request.query(),
+            &filter,
+            &sorting,
+            pagination.limit,
+        )?;
+        let paginated_result = PaginatedQueryResult {
+            result: QueryResult(projection.project(Value::Vec(page.values))),
+            pagination,
+            filter,
+            total: page.total,
+            cursor: page.cursor,
+            // Pages are taken from the result of an earlier execution,
+            // so they can't be signed with the current block height.
+            signature: None,
+        };
+        return Ok(Scale(paginated_result.into()));
+    }
 
-    let (total, result) = if let Value::Vec(vec_of_val) = result {
-        let len = vec_of_val.len();
-        let vec_of_val = apply_sorting_and_pagination(vec_of_val, &sorting, pagination);
+    // The height is read in the same consistent view as the result, so
+    // that a signed response never pairs a result with another block.
+    let (block_height, original_result) = wsv
+        .consistent_read(|wsv| {
+            valid_request
+                .execute(wsv)
+                .map(|result| (wsv.height(), result))
+        })
+        .await?;
 
-        (len, Value::Vec(vec_of_val))
-    } else {
-        (1, result)
+    let (total, result, cursor) = match original_result {
+        // Outputs of a batch are filtered, sorted and paginated one by one.
+        Value::Vec(outputs) if matches!(valid_request.query(), QueryBox::FindBatch(_)) => {
+            let len = outputs.len();
+            let outputs = outputs
+                .into_iter()
+                .map(|output| match filter.filter(output) {
+                    Value::Vec(mut vec_of_val) => {
+                        sorting.sort(&mut vec_of_val);
+                        projection.project(Value::Vec(
+                            vec_of_val.into_iter().paginate(pagination).collect(),
+                        ))
+                    }
+                    output => projection.project(output),
+                })
+                .collect();
+
+            (len, Value::Vec(outputs), None)
+        }
+        original_result => match filter.filter(original_result) {
+            Value::Vec(mut vec_of_val) => {
+                let len = vec_of_val.len();
+                sorting.sort(&mut vec_of_val);
+                let page: Vec<_> = vec_of_val.iter().paginate(pagination).cloned().collect();
+
+                let next = pagination
+                    .limit
+                    .map(|limit| pagination.start.unwrap_or(0).saturating_add(limit))
+                    .and_then(|next| usize::try_from(next).ok())
+                    .filter(|&next| next < len);
+                let cursor = match next {
+                    Some(next) => cursors.insert(
+                        account_id,
+                        valid_request.query().clone(),
+                        filter.clone(),
+                        sorting.clone(),
+                        vec_of_val,
+                        next as u64,
+                    )?,
+                    None => None,
+                };
+
+                (len, projection.project(Value::Vec(page)), cursor)
+            }
+            result => (1, projection.project(result), None),
+        },
     };
 
     let total = total
         .try_into()
         .map_err(|e: TryFromIntError| QueryError::Conversion(e.to_string()))?;
     let result = QueryResult(result);
+    let signature = if signing.sign_response {
+        let payload = QueryResponsePayload {
+            query_hash,
+            pagination,
+            sorting,
+            projection,
+            result: result.clone(),
+            total,
+            cursor,
+            block_height,
+        };
+        let signature = SignatureOf::new(key_pair, &payload).map_err(Error::SignQueryResponse)?;
+        Some(QueryResponseSignature {
+            block_height,
+            signature,
+        })
+    } else {
+        None
+    };
     let paginated_result = PaginatedQueryResult {
         result,
         pagination,
         filter,
         total,
+        cursor,
+        signature,
     };
     Ok(Scale(paginated_result.into()))
 }
 
-fn apply_sorting_and_pagination(
-    mut vec_of_val: Vec<Value>,
-    sorting: &Sorting,
-    pagination: Pagination,
-) -> Vec<Value> {
-    if let Some(ref key) = sorting.sort_by_metadata_key {
-        let f = |value1: &Value| {
-            if let Value::U128(num) = value1 {
-                *num
-            } else {
-                0
-            }
-        };
-
-        vec_of_val.sort_by_key(|value0| match value0 {
-            Value::Identifiable(IdentifiableBox::Asset(asset)) => match asset.value() {
-                AssetValue::Store(store) => store.get(key).map_or(0, f),
-                _ => 0,
-            },
-            Value::Identifiable(v) => TryInto::<&dyn HasMetadata>::try_into(v)
-                .map(|has_metadata| has_metadata.metadata().get(key).map_or(0, f))
-                .unwrap_or(0),
-            _ => 0,
-        });
-    }
-
-    vec_of_val.into_iter().paginate(pagination).collect()
-}
-
 #[derive(serde::Serialize)]
 #[non_exhaustive]
 enum Health {
@@ -297,6 +399,360 @@ async fn stream_blocks(
     Ok(())
 }
 
+#[iroha_futures::telemetry_future]
+async fn handle_query_subscription(
+    chain_id: &ChainId,
+    wsv: &WorldStateView,
+    query_judge: &dyn Judge<Operation = QueryBox>,
+    mut stream: WebSocket,
+) -> eyre::Result<()> {
+    let subscription_request: VersionedQuerySubscriberMessage = stream.recv().await?;
+    let request: VerifiedQueryRequest = match subscription_request.into_v1() {
+        QuerySubscriberMessage::SubscriptionRequest(request) => request.try_into()?,
+        QuerySubscriberMessage::OutputReceived => {
+            return Err(eyre!("Expected `SubscriptionRequest` message"))
+        }
+    };
+    let account_id = request.payload.account_id.clone();
+    let account_permissions = || {
+        wsv.map_account(&account_id, |account| {
+            wsv.account_permission_tokens(account)
+        })
+        .ok()
+    };
+
+    // Subscribe before the first evaluation not to miss any block
+    let mut new_blocks = wsv.subscribe_to_new_block_notifications();
+    let mut permissions = account_permissions();
+    let (query, filter) = match request.clone().validate(chain_id, wsv, query_judge) {
+        Ok(valid_request) => valid_request,
+        Err(error) => return end_query_subscription(stream, error.to_string()).await,
+    };
+    stream
+        .send(VersionedQueryPublisherMessage::from(
+            QueryPublisherMessage::SubscriptionAccepted,
+        ))
+        .await?;
+
+    let mut output = match wsv.consistent_read(|wsv| query.execute(wsv)).await {
+        Ok(output) => filter.filter(output),
+        Err(error) => return end_query_subscription(stream, error.to_string()).await,
+    };
+    publish_query_output(&mut stream, QueryPublisherMessage::Result(output.clone())).await?;
+
+    loop {
+        new_blocks.changed().await?;
+
+        // Permissions are judged again only when the account's permissions change
+        let current_permissions = account_permissions();
+        if current_permissions != permissions {
+            if let Err(error) = request.clone().validate(chain_id, wsv, query_judge) {
+                return end_query_subscription(stream, error.to_string()).await;
+            }
+            permissions = current_permissions;
+        }
+
+        let new_output = match wsv.consistent_read(|wsv| query.execute(wsv)).await {
+            Ok(new_output) => filter.filter(new_output),
+            Err(error) => return end_query_subscription(stream, error.to_string()).await,
+        };
+        let message = match (&output, &new_output) {
+            (Value::Vec(old), Value::Vec(new)) => {
+                let delta = QueryDelta::between(old, new);
+                if delta.is_empty() {
+                    continue;
+                }
+                QueryPublisherMessage::Delta(delta)
+            }
+            (old, new) if old == new => continue,
+            (_, new) => QueryPublisherMessage::Result(new.clone()),
+        };
+        output = new_output;
+        publish_query_output(&mut stream, message).await?;
+    }
+}
+
+async fn publish_query_output(
+    stream: &mut WebSocket,
+    message: QueryPublisherMessage,
+) -> eyre::Result<()> {
+    stream
+        .send(VersionedQueryPublisherMessage::from(message))
+        .await?;
+
+    let message: VersionedQuerySubscriberMessage = stream.recv().await?;
+    if let QuerySubscriberMessage::OutputReceived = message.into_v1() {
+        Ok(())
+    } else {
+        Err(eyre!("Expected `OutputReceived` message"))
+    }
+}
+
+async fn end_query_subscription(mut stream: WebSocket, reason: String) -> eyre::Result<()> {
+    stream
+        .send(VersionedQueryPublisherMessage::from(
+            QueryPublisherMessage::SubscriptionEnded(reason),
+        ))
+        .await?;
+    stream.close().await.map_err(Into::into)
+}
+
+/// Data and pipeline events of the committed `block` with their positions,
+/// in the order they were produced.
+///
+/// # Errors
+/// Fails with [`FromHeightNotKept`](crate::event::Error::FromHeightNotKept) if
+/// the data events of the `block` are no longer kept, rather than passing its
+/// pipeline events alone as if it had none.
+pub(super) fn committed_block_events(
+    wsv: &WorldStateView,
+    block: &VersionedCommittedBlock,
+) -> Result<Vec<(EventPosition, Event)>, crate::event::Error> {
+    let block_height = block.header().height;
+    let data_events = wsv.block_data_events(block_height).ok_or_else(|| {
+        crate::event::Error::FromHeightNotKept {
+            requested: block_height,
+            earliest: wsv.earliest_data_events_height(),
+        }
+    })?;
+    let pipeline_events = Vec::<Event>::from(block);
+
+    Ok(data_events
+        .into_iter()
+        .map(Event::Data)
+        .chain(pipeline_events)
+        .zip(0_u64..)
+        .map(|(event, index)| {
+            let position = EventPosition {
+                block_height,
+                index,
+            };
+            (position, event)
+        })
+        .collect())
+}
+
+mod sse {
+    //! Event and block streams over Server-Sent Events, with the items encoded as JSON.
+
+    use futures::{stream, Stream, StreamExt as _};
+    use iroha_core::wsv::NewBlockNotificationReceiver;
+    use serde::{de::Error as _, Deserialize, Deserializer};
+    use tokio::sync::broadcast::error::RecvError;
+    use warp::sse;
+
+    use super::*;
+
+    /// URL query parameters of the event stream.
+    #[derive(Debug, Deserialize)]
+    pub struct EventStreamParams {
+        /// JSON-encoded filter the sent events have to match.
+        #[serde(deserialize_with = "json_filter")]
+        filter: FilterBox,
+        /// Height of the committed block to start the stream from,
+        /// see [`EventSubscriptionRequest::from_height`].
+        from_height: Option<u64>,
+    }
+
+    /// URL query parameters of the block stream.
+    #[derive(Debug, Deserialize)]
+    pub struct BlockStreamParams {
+        /// Height of the block to start the stream from.
+        height: u64,
+    }
+
+    fn json_filter<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FilterBox, D::Error> {
+        let filter = String::deserialize(deserializer)?;
+        serde_json::from_str(&filter).map_err(D::Error::custom)
+    }
+
+    /// Stream the events matching the filter as [`SequencedEvent`]s,
+    /// the same way they are sent over `WebSocket`.
+    ///
+    /// Events of committed blocks have their position as the SSE `id`, and
+    /// a reconnecting client which sends it back in `Last-Event-ID` resumes
+    /// right after that event, regardless of `from_height`.
+    ///
+    /// Replies with `400 Bad Request` if `Last-Event-ID` isn't a position and
+    /// with `410 Gone` if the events of the block to start from are no longer kept.
+    /// If the events of a later block are gone by the time it's streamed, the
+    /// stream ends with an `error` event instead of skipping them.
+    pub fn handle_events(
+        events: EventsSender,
+        wsv: Arc<WorldStateView>,
+        params: EventStreamParams,
+        last_event_id: Option<String>,
+    ) -> Response {
+        let EventStreamParams {
+            filter,
+            from_height,
+        } = params;
+
+        let last_event = match last_event_id
+            .map(|id| EventPosition::parse(&id).ok_or(id))
+            .transpose()
+        {
+            Ok(last_event) => last_event,
+            Err(id) => {
+                let error =
+                    format!("Invalid `Last-Event-ID` `{id}`, expected `<block_height>:<index>`");
+                return reply::with_status(error, StatusCode::BAD_REQUEST).into_response();
+            }
+        };
+        let from_height = last_event.map_or(from_height, |last| Some(last.block_height));
+
+        let earliest_height = wsv.earliest_data_events_height();
+        if let Some(requested) = from_height.filter(|height| *height < earliest_height) {
+            let error = crate::event::Error::FromHeightNotKept {
+                requested,
+                earliest: earliest_height,
+            };
+            return reply::with_status(error.to_string(), StatusCode::GONE).into_response();
+        }
+
+        let events = match from_height {
+            Some(from_height) => committed_events(wsv, from_height).boxed(),
+            None => live_events(&events).boxed(),
+        };
+        let events = events
+            .filter(move |item| {
+                let passes = match item {
+                    Ok((position, event)) => {
+                        let resumed = match (last_event, position) {
+                            (Some(last), Some(position)) => *position > last,
+                            _ => true,
+                        };
+                        resumed && filter.matches(event)
+                    }
+                    Err(_) => true,
+                };
+                futures::future::ready(passes)
+            })
+            .zip(stream::iter(0_u64..))
+            .map(|(item, sequence_number)| {
+                let (position, event) = match item {
+                    Ok(item) => item,
+                    Err(error) => {
+                        return Ok(sse::Event::default().event("error").data(error.to_string()))
+                    }
+                };
+                let sse_event = match position {
+                    Some(position) => sse::Event::default().id(position.to_string()),
+                    None => sse::Event::default(),
+                };
+                sse_event.json_data(SequencedEvent {
+                    sequence_number,
+                    block_height: position.map(|position| position.block_height),
+                    index_in_block: position.map(|position| position.index),
+                    event,
+                })
+            });
+
+        sse::reply(sse::keep_alive().stream(events)).into_response()
+    }
+
+    /// Stream the committed blocks starting with the block of the given height.
+    pub fn handle_blocks(wsv: Arc<WorldStateView>, params: BlockStreamParams) -> impl Reply {
+        let blocks = stream::unfold(
+            CommittedBlocks::new(wsv, params.height),
+            |mut blocks| async move {
+                let block = blocks.next().await?;
+                Some((block, blocks))
+            },
+        )
+        .map(|block| {
+            sse::Event::default()
+                .id(block.header().height.to_string())
+                .json_data(block.into_value())
+        });
+
+        sse::reply(sse::keep_alive().stream(blocks))
+    }
+
+    /// Item of an event stream: the event along with its position, if it's
+    /// known, or the reason why the stream ends.
+    type EventItem = Result<(Option<EventPosition>, Event), crate::event::Error>;
+
+    fn live_events(events: &EventsSender) -> impl Stream<Item = EventItem> {
+        stream::unfold(events.subscribe(), |mut events| async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => return Some((Ok((None, event)), events)),
+                    Err(RecvError::Lagged(skipped)) => {
+                        iroha_logger::warn!(
+                            skipped,
+                            "Event stream lagged behind, events were skipped"
+                        );
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    fn committed_events(
+        wsv: Arc<WorldStateView>,
+        from_height: u64,
+    ) -> impl Stream<Item = EventItem> {
+        stream::unfold(
+            Some(CommittedBlocks::new(wsv, from_height)),
+            |blocks| async move {
+                let mut blocks = blocks?;
+                let block = blocks.next().await?;
+                match committed_block_events(&blocks.wsv, &block) {
+                    Ok(events) => {
+                        let events: Vec<_> = events
+                            .into_iter()
+                            .map(|(position, event)| Ok((Some(position), event)))
+                            .collect();
+                        Some((stream::iter(events), Some(blocks)))
+                    }
+                    // The stream fell behind the kept events, so it ends here
+                    Err(error) => Some((stream::iter(vec![Err(error)]), None)),
+                }
+            },
+        )
+        .flatten()
+    }
+
+    /// Committed blocks, waiting for new blocks once the latest one is reached.
+    struct CommittedBlocks {
+        wsv: Arc<WorldStateView>,
+        next_height: u64,
+        new_blocks: NewBlockNotificationReceiver,
+    }
+
+    impl CommittedBlocks {
+        fn new(wsv: Arc<WorldStateView>, from_height: u64) -> Self {
+            let new_blocks = wsv.subscribe_to_new_block_notifications();
+            Self {
+                wsv,
+                next_height: from_height,
+                new_blocks,
+            }
+        }
+
+        async fn next(&mut self) -> Option<VersionedCommittedBlock> {
+            loop {
+                #[allow(clippy::expect_used)]
+                let block = self
+                    .wsv
+                    .blocks_from_height(
+                        self.next_height
+                            .try_into()
+                            .expect("Blockchain size limit reached"),
+                    )
+                    .next();
+                if let Some(block) = block {
+                    self.next_height = block.header().height + 1;
+                    return Some(block);
+                }
+                self.new_blocks.changed().await.ok()?;
+            }
+        }
+    }
+}
+
 mod subscription {
     //! Contains the `handle_subscription` functions and used for general routing.
 
@@ -312,6 +768,9 @@ mod subscription {
         /// Event receiving error
         #[error("Event receiving error: {0}")]
         Event(#[from] tokio::sync::broadcast::error::RecvError),
+        /// New block notification receiving error
+        #[error("New block notification receiving error: {0}")]
+        NewBlock(#[from] tokio::sync::watch::error::RecvError),
         /// Error from provided websocket
         #[error("WebSocket error: {0}")]
         WebSocket(#[from] warp::Error),
@@ -337,15 +796,31 @@ mod subscription {
 
     /// Handle subscription request
     ///
-    /// Subscribes `stream` for `events` filtered by filter that is received through the `stream`
+    /// Subscribes `stream` for `events` filtered by filter that is received through the `stream`.
+    /// If the subscription is requested from a block height, the events of the committed
+    /// blocks are taken from `wsv` instead.
     ///
     /// There should be a [`warp::filters::ws::Message::close()`] message to end subscription
     #[iroha_futures::telemetry_future]
-    pub async fn handle_subscription(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
-        let mut consumer = event::Consumer::new(stream).await?;
+    pub async fn handle_subscription(
+        events: EventsSender,
+        wsv: Arc<WorldStateView>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        let mut consumer = event::Consumer::new(stream, wsv.earliest_data_events_height()).await?;
 
-        match subscribe_forever(events, &mut consumer).await {
+        let subscription = match consumer.from_height() {
+            Some(from_height) => subscribe_from_height(&wsv, from_height, &mut consumer).await,
+            None => subscribe_forever(events, &mut consumer).await,
+        };
+        match subscription {
             Ok(()) | Err(Error::CloseMessage) => consumer.close_stream().await.map_err(Into::into),
+            Err(Error::Consumer(error))
+                if matches!(*error, event::Error::FromHeightNotKept { .. }) =>
+            {
+                consumer.reject(&error).await?;
+                Err((*error).into())
+            }
             Err(err) => Err(err.into()),
         }
     }
@@ -369,13 +844,68 @@ mod subscription {
                 event = events.recv() => {
                     let event = event?;
                     iroha_logger::trace!(?event);
-                    consumer.consume(event).await?;
+                    consumer.consume(event, None).await?;
+                }
+                // Else branch to prevent panic
+                else => ()
+            }
+        }
+    }
+
+    /// Make endless `consumer` subscription for the events of the committed blocks
+    /// starting with the block of the given `from_height`
+    ///
+    /// Ideally should return `Result<!>` cause it either runs forever either returns `Err` variant
+    async fn subscribe_from_height(
+        wsv: &WorldStateView,
+        mut from_height: u64,
+        consumer: &mut event::Consumer,
+    ) -> Result<()> {
+        let mut new_blocks = wsv.subscribe_to_new_block_notifications();
+        consume_blocks(wsv, &mut from_height, consumer).await?;
+
+        loop {
+            tokio::select! {
+                // This branch catches `Close` and unexpected messages
+                closed = consumer.stream_closed() => {
+                    match closed {
+                        Ok(()) => return Err(Error::CloseMessage),
+                        Err(err) => return Err(err.into())
+                    }
+                }
+                // This branch sends the events of the newly committed blocks
+                changed = new_blocks.changed() => {
+                    changed?;
+                    consume_blocks(wsv, &mut from_height, consumer).await?;
                 }
                 // Else branch to prevent panic
                 else => ()
             }
         }
     }
+
+    /// Pass the data and pipeline events of the committed blocks starting with
+    /// the block of the given `from_height` to the `consumer`, in the order they were produced
+    async fn consume_blocks(
+        wsv: &WorldStateView,
+        from_height: &mut u64,
+        consumer: &mut event::Consumer,
+    ) -> Result<()> {
+        #[allow(clippy::expect_used)]
+        for block in wsv.blocks_from_height(
+            (*from_height)
+                .try_into()
+                .expect("Blockchain size limit reached"),
+        ) {
+            for (position, event) in committed_block_events(wsv, &block)? {
+                iroha_logger::trace!(?event);
+                consumer.consume(event, Some(position)).await?;
+            }
+            *from_height = block.header().height + 1;
+        }
+
+        Ok(())
+    }
 }
 
 #[iroha_futures::telemetry_future]
@@ -443,18 +973,25 @@ impl Torii {
         wsv: Arc<WorldStateView>,
         queue: Arc<Queue>,
         query_judge: QueryJudgeArc,
+        transaction_validator: TransactionValidator,
         events: EventsSender,
         network: Addr<IrohaNetwork>,
         notify_shutdown: Arc<Notify>,
     ) -> Self {
         Self {
-            iroha_cfg,
             wsv,
             events,
             query_judge,
+            transaction_validator,
+            cursors: Arc::new(CursorStore::new(
+                Duration::from_millis(iroha_cfg.torii.query_cursor_ttl_ms),
+                iroha_cfg.torii.max_query_cursors_per_account,
+                iroha_cfg.torii.max_query_cursors_size,
+            )),
             queue,
             network,
             notify_shutdown,
+            iroha_cfg,
         }
     }
 
@@ -499,7 +1036,28 @@ impl Torii {
                 warp::path(uri::CONFIGURATION)
                     .and(add_state!(self.iroha_cfg))
                     .and(warp::body::json()),
-            ));
+            ))
+            .or(uri::SUBSCRIPTION_SSE
+                .split('/')
+                .skip_while(|p| p.is_empty())
+                .fold(warp::any().boxed(), |path_filter, path| {
+                    path_filter.and(warp::path(path)).boxed()
+                })
+                .and(warp::path::end())
+                .and(add_state!(self.events, self.wsv))
+                .and(warp::query())
+                .and(warp::header::optional::<String>("last-event-id"))
+                .map(sse::handle_events))
+            .or(uri::BLOCKS_STREAM_SSE
+                .split('/')
+                .skip_while(|p| p.is_empty())
+                .fold(warp::any().boxed(), |path_filter, path| {
+                    path_filter.and(warp::path(path)).boxed()
+                })
+                .and(warp::path::end())
+                .and(add_state!(self.wsv))
+                .and(warp::query())
+                .map(sse::handle_blocks));
 
         #[cfg(feature = "schema-endpoint")]
         let get_router = get_router.or(warp::path(uri::SCHEMA)
@@ -514,12 +1072,30 @@ impl Torii {
                 ))
                 .and(body::versioned()),
         )
-        .or(endpoint5(
+        .or(endpoint3(
+            handle_simulate_transaction,
+            warp::path(uri::SIMULATE_TRANSACTION)
+                .and(add_state!(self.iroha_cfg, self.transaction_validator))
+                .and(warp::body::content_length_limit(
+                    self.iroha_cfg.torii.max_content_len.into(),
+                ))
+                .and(body::versioned()),
+        ))
+        .or(endpoint11(
             handle_queries,
             warp::path(uri::QUERY)
-                .and(add_state!(self.wsv, self.query_judge))
+                .and(add_state!(
+                    self.iroha_cfg.genesis.chain_id,
+                    self.wsv,
+                    self.query_judge,
+                    self.cursors,
+                    self.iroha_cfg.sumeragi.key_pair
+                ))
                 .and(paginate())
                 .and(sorting())
+                .and(projection())
+                .and(cursor())
+                .and(query::response_signing())
                 .and(body::query()),
         ))
         .or(endpoint2(
@@ -530,11 +1106,13 @@ impl Torii {
         ));
 
         let events_ws_router = warp::path(uri::SUBSCRIPTION)
-            .and(add_state!(self.events))
+            .and(add_state!(self.events, self.wsv))
             .and(warp::ws())
-            .map(|events, ws: Ws| {
+            .map(|events, wsv, ws: Ws| {
                 ws.on_upgrade(|this_ws| async move {
-                    if let Err(error) = subscription::handle_subscription(events, this_ws).await {
+                    if let Err(error) =
+                        subscription::handle_subscription(events, wsv, this_ws).await
+                    {
                         iroha_logger::error!(%error, "Failure during subscription");
                     }
                 })
@@ -560,7 +1138,36 @@ impl Torii {
                 })
             });
 
-        let ws_router = events_ws_router.or(blocks_ws_router);
+        let query_ws_router = uri::QUERY_SUBSCRIPTION
+            .split('/')
+            .skip_while(|p| p.is_empty())
+            .fold(warp::any().boxed(), |path_filter, path| {
+                path_filter.and(warp::path(path)).boxed()
+            })
+            .and(add_state!(
+                self.iroha_cfg.genesis.chain_id,
+                self.wsv,
+                self.query_judge
+            ))
+            .and(warp::ws())
+            .map(
+                |chain_id: ChainId, wsv: Arc<_>, query_judge: QueryJudgeArc, ws: Ws| {
+                    ws.on_upgrade(|this_ws| async move {
+                        if let Err(error) = handle_query_subscription(
+                            &chain_id,
+                            &wsv,
+                            query_judge.as_ref(),
+                            this_ws,
+                        )
+                        .await
+                        {
+                            iroha_logger::error!(%error, "Failure during query subscription");
+                        }
+                    })
+                },
+            );
+
+        let ws_router = events_ws_router.or(blocks_ws_router).or(query_ws_router);
 
         ws_router
             .or(warp::post().and(post_router))
@@ -641,6 +1248,14 @@ impl Torii {
         #[cfg(feature = "telemetry")]
         handles.extend(Arc::clone(&torii).start_telemetry()?);
         handles.extend(Arc::clone(&torii).start_api()?);
+        handles.extend(
+            webhook::start(
+                &torii.iroha_cfg.torii,
+                &torii.events,
+                &torii.notify_shutdown,
+            )
+            .await?,
+        );
 
         handles
             .into_iter()
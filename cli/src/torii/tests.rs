@@ -56,7 +56,11 @@ async fn create_torii() -> (Torii, KeyPair) {
         )
         .is_none());
     wsv.domains().insert(domain_id, domain);
-    let queue = Arc::new(Queue::from_configuration(&config.queue, Arc::clone(&wsv)));
+    let queue = Arc::new(Queue::from_configuration(
+        &config.queue,
+        config.genesis.chain_id.clone(),
+        Arc::clone(&wsv),
+    ));
     let network = IrohaNetwork::new(
         Broker::new(),
         config.torii.p2p_addr.clone(),
@@ -68,12 +72,21 @@ async fn create_torii() -> (Torii, KeyPair) {
     .start()
     .await;
 
+    let transaction_validator = TransactionValidator::new(
+        config.sumeragi.transaction_limits,
+        config.genesis.chain_id.clone(),
+        Arc::new(AllowAll::new()),
+        Arc::new(AllowAll::new()),
+        Arc::clone(&wsv),
+    );
+
     (
         Torii::from_configuration(
             config,
             wsv,
             queue,
             Arc::new(AllowAll::new()),
+            transaction_validator,
             events,
             network,
             Arc::new(Notify::new()),
@@ -110,10 +123,16 @@ async fn torii_pagination() {
 
         let pagination = Pagination::new(start, limit);
         handle_queries(
+            torii.iroha_cfg.genesis.chain_id.clone(),
             Arc::clone(&torii.wsv),
             Arc::clone(&torii.query_judge),
+            Arc::clone(&torii.cursors),
+            torii.iroha_cfg.sumeragi.key_pair.clone(),
             pagination,
             Sorting::default(),
+            Projection::default(),
+            CursorRequest::default(),
+            ResponseSigningRequest::default(),
             query,
         )
         .map(|result| {
@@ -144,6 +163,310 @@ async fn torii_pagination() {
     }
 }
 
+#[tokio::test]
+async fn torii_rejects_query_for_another_chain() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+
+    let query: VerifiedQueryRequest = QueryRequest::new(
+        QueryBox::FindAllDomains(Default::default()),
+        AccountId::from_str("alice@wonderland").expect("Valid"),
+        PredicateBox::default(),
+    )
+    .with_chain_id(ChainId::new("another-chain"))
+    .sign(keys)
+    .expect("Failed to sign query with keys")
+    .try_into()
+    .expect("Failed to verify");
+
+    let result = handle_queries(
+        torii.iroha_cfg.genesis.chain_id.clone(),
+        Arc::clone(&torii.wsv),
+        Arc::clone(&torii.query_judge),
+        Arc::clone(&torii.cursors),
+        torii.iroha_cfg.sumeragi.key_pair.clone(),
+        Pagination::default(),
+        Sorting::default(),
+        Projection::default(),
+        CursorRequest::default(),
+        ResponseSigningRequest::default(),
+        query,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Query(query::Error::ChainIdMismatch))
+    ));
+}
+
+#[tokio::test]
+async fn torii_signs_query_response_on_request() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+
+    let request = QueryRequest::new(
+        QueryBox::FindAllDomains(Default::default()),
+        AccountId::from_str("alice@wonderland").expect("Valid"),
+        PredicateBox::default(),
+    )
+    .sign(keys)
+    .expect("Failed to sign query with keys");
+    let query_hash = request.payload.hash();
+
+    let execute = |signing| {
+        let query: VerifiedQueryRequest = request.clone().try_into().expect("Failed to verify");
+        handle_queries(
+            torii.iroha_cfg.genesis.chain_id.clone(),
+            Arc::clone(&torii.wsv),
+            Arc::clone(&torii.query_judge),
+            Arc::clone(&torii.cursors),
+            torii.iroha_cfg.sumeragi.key_pair.clone(),
+            Pagination::default(),
+            Sorting::default(),
+            Projection::default(),
+            CursorRequest::default(),
+            ResponseSigningRequest::new(signing),
+            query,
+        )
+        .map(|result| result.expect("Query is valid").0.into_v1())
+    };
+
+    let unsigned = execute(false).await;
+    assert!(unsigned.signature.is_none());
+
+    let signed = execute(true).await;
+    assert_eq!(
+        signed.signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::default(),
+            &Projection::default()
+        ),
+        Some(torii.iroha_cfg.sumeragi.key_pair.public_key())
+    );
+    let mut tampered = signed.clone();
+    tampered.result = QueryResult(Value::Vec(Vec::new()));
+    assert!(tampered
+        .signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::default(),
+            &Projection::default()
+        )
+        .is_none());
+    let mut tampered = signed.clone();
+    tampered.total += 1;
+    assert!(tampered
+        .signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::default(),
+            &Projection::default()
+        )
+        .is_none());
+    let mut tampered = signed.clone();
+    tampered.cursor = Some(Cursor::new(0, 0));
+    assert!(tampered
+        .signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::default(),
+            &Projection::default()
+        )
+        .is_none());
+
+    // The signature vouches for the parameters the query was sent with
+    assert!(signed
+        .signed_by(
+            query_hash,
+            Pagination::new(None, Some(1)),
+            &Sorting::default(),
+            &Projection::default()
+        )
+        .is_none());
+    assert!(signed
+        .signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::by_keys([SortKey::descending(SortField::Id)]),
+            &Projection::default()
+        )
+        .is_none());
+    assert!(signed
+        .signed_by(
+            query_hash,
+            Pagination::default(),
+            &Sorting::default(),
+            &Projection::fields([SortField::Id])
+        )
+        .is_none());
+}
+
+#[tokio::test]
+async fn torii_cursor_pagination() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+
+    let request = |query: QueryBox, cursor| {
+        let query: VerifiedQueryRequest = QueryRequest::new(
+            query,
+            AccountId::from_str("alice@wonderland").expect("Valid"),
+            PredicateBox::default(),
+        )
+        .sign(keys.clone())
+        .expect("Failed to sign query with keys")
+        .try_into()
+        .expect("Failed to verify");
+
+        handle_queries(
+            torii.iroha_cfg.genesis.chain_id.clone(),
+            Arc::clone(&torii.wsv),
+            Arc::clone(&torii.query_judge),
+            Arc::clone(&torii.cursors),
+            torii.iroha_cfg.sumeragi.key_pair.clone(),
+            Pagination::new(None, Some(10)),
+            Sorting::default(),
+            Projection::default(),
+            CursorRequest::new(cursor),
+            ResponseSigningRequest::default(),
+            query,
+        )
+        .map(|result| {
+            result.map(|Scale(query_result)| {
+                let PaginatedQueryResult { result, cursor, .. } = query_result.into_v1();
+                if let QueryResult(Value::Vec(domains)) = result {
+                    (domains, cursor)
+                } else {
+                    unreachable!()
+                }
+            })
+        })
+    };
+    let find_all_domains = || QueryBox::FindAllDomains(Default::default());
+
+    let (mut domains, mut cursor) = request(find_all_domains(), None).await.unwrap();
+    let first_cursor = cursor.expect("27 domains don't fit on one page");
+    assert!(matches!(
+        request(QueryBox::FindAllAccounts(Default::default()), cursor).await,
+        Err(Error::Query(query::Error::UnknownCursor))
+    ));
+    while let Some(next) = cursor {
+        let (page, next) = request(find_all_domains(), Some(next)).await.unwrap();
+        domains.extend(page);
+        cursor = next;
+    }
+    // 27 = a..=z
+    assert_eq!(domains.len(), 27);
+    assert_eq!(domains.iter().collect::<BTreeSet<_>>().len(), 27);
+    assert!(matches!(
+        request(find_all_domains(), Some(first_cursor)).await,
+        Err(Error::Query(query::Error::UnknownCursor))
+    ));
+}
+
+#[tokio::test]
+async fn torii_batch_query() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+    let query: VerifiedQueryRequest = QueryRequest::new(
+        FindBatch::new([
+            QueryBox::FindAllDomains(Default::default()),
+            QueryBox::FindAllAccounts(Default::default()),
+        ])
+        .into(),
+        AccountId::from_str("alice@wonderland").expect("Valid"),
+        PredicateBox::default(),
+    )
+    .sign(keys)
+    .expect("Failed to sign query with keys")
+    .try_into()
+    .expect("Failed to verify");
+
+    let Scale(query_result) = handle_queries(
+        torii.iroha_cfg.genesis.chain_id.clone(),
+        Arc::clone(&torii.wsv),
+        Arc::clone(&torii.query_judge),
+        Arc::clone(&torii.cursors),
+        torii.iroha_cfg.sumeragi.key_pair.clone(),
+        Pagination::new(None, Some(5)),
+        Sorting::default(),
+        Projection::default(),
+        CursorRequest::default(),
+        ResponseSigningRequest::default(),
+        query,
+    )
+    .await
+    .expect("Batch is valid");
+    let PaginatedQueryResult { result, cursor, .. } = query_result.into_v1();
+
+    assert!(cursor.is_none());
+    let outputs = if let QueryResult(Value::Vec(outputs)) = result {
+        outputs
+    } else {
+        unreachable!()
+    };
+    assert_eq!(outputs.len(), 2);
+    // Every output is paginated on its own
+    assert!(matches!(&outputs[0], Value::Vec(domains) if domains.len() == 5));
+    assert!(matches!(&outputs[1], Value::Vec(accounts) if accounts.len() == 1));
+}
+
+#[tokio::test]
+async fn torii_simulates_transaction_without_applying_it() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+    let simulate = |instructions: Vec<Instruction>| {
+        let tx = Transaction::new(
+            AccountId::from_str("alice@wonderland").expect("Valid"),
+            instructions.into(),
+            100_000,
+        )
+        .sign(keys.clone())
+        .expect("Failed to sign transaction");
+
+        handle_simulate_transaction(
+            torii.iroha_cfg.clone(),
+            torii.transaction_validator.clone(),
+            tx.into(),
+        )
+        .map(|result| result.expect("Failed to simulate transaction").0.into_v1())
+    };
+
+    let domain_id = DomainId::from_str("simulated").expect("Valid");
+    let simulation = simulate(vec![RegisterBox::new(Domain::new(domain_id.clone())).into()]).await;
+    assert!(simulation.is_accepted());
+    assert!(!simulation.events.is_empty());
+    assert!(matches!(
+        simulation.state_diff.as_slice(),
+        [StateChange { id: IdBox::DomainId(id), before: None, after: Some(_) }] if *id == domain_id
+    ));
+    assert!(torii.wsv.domain(&domain_id).is_err());
+
+    let simulation = simulate(vec![FailBox::new("Always fail").into()]).await;
+    assert!(matches!(
+        simulation.rejection_reason,
+        Some(TransactionRejectionReason::InstructionExecution(_))
+    ));
+    assert!(simulation.state_diff.is_empty());
+
+    let tx = Transaction::new(
+        AccountId::from_str("alice@wonderland").expect("Valid"),
+        vec![RegisterBox::new(Domain::new(domain_id)).into()].into(),
+        100_000,
+    )
+    .with_chain_id(ChainId::new("another-chain"))
+    .sign(keys)
+    .expect("Failed to sign transaction");
+    let result = handle_simulate_transaction(
+        torii.iroha_cfg.clone(),
+        torii.transaction_validator.clone(),
+        tx.into(),
+    )
+    .await;
+    assert!(matches!(result, Err(Error::ChainIdMismatch)));
+}
+
 #[derive(Default)]
 struct QuerySet {
     instructions: Vec<Instruction>,
@@ -753,6 +1076,73 @@ async fn blocks_stream() {
     assert_eq!(block.header().height, BLOCK_COUNT as u64 + 1);
 }
 
+#[tokio::test]
+async fn query_subscription_pushes_deltas() {
+    prepare_test_for_nextest!();
+    let (torii, keys) = create_torii().await;
+    let router = torii.create_api_router();
+
+    let mut client = warp::test::ws()
+        .path("/query/subscription")
+        .handshake(router)
+        .await
+        .unwrap();
+
+    let request = QueryRequest::new(
+        QueryBox::FindAllDomains(Default::default()),
+        AccountId::from_str("alice@wonderland").expect("Valid"),
+        PredicateBox::default(),
+    )
+    .with_chain_id(torii.iroha_cfg.genesis.chain_id.clone())
+    .sign(keys)
+    .expect("Failed to sign query with keys");
+    <WsClient as Sink<_>>::send(
+        &mut client,
+        VersionedQuerySubscriberMessage::from(QuerySubscriberMessage::SubscriptionRequest(request)),
+    )
+    .await
+    .unwrap();
+
+    let message: VersionedQueryPublisherMessage =
+        <WsClient as Stream<_>>::recv(&mut client).await.unwrap();
+    assert!(matches!(
+        message.into_v1(),
+        QueryPublisherMessage::SubscriptionAccepted
+    ));
+    let message: VersionedQueryPublisherMessage =
+        <WsClient as Stream<_>>::recv(&mut client).await.unwrap();
+    // 27 = a..=z + wonderland
+    assert!(matches!(
+        message.into_v1(),
+        QueryPublisherMessage::Result(Value::Vec(domains)) if domains.len() == 27
+    ));
+    <WsClient as Sink<_>>::send(
+        &mut client,
+        VersionedQuerySubscriberMessage::from(QuerySubscriberMessage::OutputReceived),
+    )
+    .await
+    .unwrap();
+
+    let domain_id = DomainId::from_str("looking_glass").expect("Valid");
+    torii
+        .wsv
+        .domains()
+        .insert(domain_id.clone(), Domain::new(domain_id.clone()).build());
+    torii.wsv.apply(new_dummy().commit().into()).await.unwrap();
+
+    let message: VersionedQueryPublisherMessage =
+        <WsClient as Stream<_>>::recv(&mut client).await.unwrap();
+    if let QueryPublisherMessage::Delta(QueryDelta { added, removed }) = message.into_v1() {
+        assert!(removed.is_empty());
+        assert!(matches!(
+            added.as_slice(),
+            [Value::Identifiable(IdentifiableBox::Domain(domain))] if domain.id() == &domain_id
+        ));
+    } else {
+        panic!("Expected a delta with the new domain");
+    }
+}
+
 #[test]
 fn hash_should_be_the_same() {
     prepare_test_for_nextest!();
@@ -789,6 +1179,7 @@ fn hash_should_be_the_same() {
     )));
     let valid_tx_hash = TransactionValidator::new(
         tx_limits,
+        ChainId::default(),
         Arc::new(AllowAll::new()),
         Arc::new(AllowAll::new()),
         wsv,
@@ -822,7 +1213,7 @@ async fn test_subscription_websocket_clean_closing() {
     let event_filter =
         FilterBox::Pipeline(pipeline::EventFilter::new().entity_kind(pipeline::EntityKind::Block));
     let subscribe_message = VersionedEventSubscriberMessage::from(
-        EventSubscriberMessage::SubscriptionRequest(event_filter),
+        EventSubscriberMessage::SubscriptionRequest(event_filter.into()),
     );
     Sink::send(&mut endpoint, subscribe_message).await.unwrap();
 
@@ -839,3 +1230,178 @@ async fn test_subscription_websocket_clean_closing() {
     endpoint.send(close_message).await;
     assert!(endpoint.recv_closed().await.is_ok());
 }
+
+#[tokio::test]
+async fn events_are_streamed_over_sse() {
+    prepare_test_for_nextest!();
+    use warp::hyper::body::HttpBody as _;
+
+    let (torii, _) = create_torii().await;
+    let router = torii.create_api_router();
+
+    let filter =
+        FilterBox::Pipeline(PipelineEventFilter::new().entity_kind(PipelineEntityKind::Block));
+    let encoded_filter: String = serde_json::to_string(&filter)
+        .unwrap()
+        .bytes()
+        .map(|byte| format!("%{byte:02X}"))
+        .collect();
+    let reply = warp::test::request()
+        .path(&format!("/events/sse?filter={encoded_filter}"))
+        .filter(&router)
+        .await
+        .unwrap();
+    let response = reply.into_response();
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+    let mut body = response.into_body();
+
+    let event_of = |entity_kind| {
+        Event::from(PipelineEvent::new(
+            entity_kind,
+            PipelineStatus::Committed,
+            Hash::zeroed(),
+        ))
+    };
+    torii
+        .events
+        .send(event_of(PipelineEntityKind::Transaction))
+        .unwrap();
+    torii
+        .events
+        .send(event_of(PipelineEntityKind::Block))
+        .unwrap();
+
+    let chunk = time::timeout(Duration::from_secs(5), body.data())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+    let data = chunk
+        .lines()
+        .find_map(|line| line.strip_prefix("data:"))
+        .unwrap();
+    // Live events aren't tied to a block, so there's no position to resume from
+    assert!(!chunk.lines().any(|line| line.starts_with("id:")));
+    assert_eq!(
+        serde_json::from_str::<SequencedEvent>(data).unwrap(),
+        SequencedEvent {
+            sequence_number: 0,
+            block_height: None,
+            index_in_block: None,
+            event: event_of(PipelineEntityKind::Block),
+        }
+    );
+}
+
+#[tokio::test]
+async fn sse_resumes_after_last_event_id() {
+    prepare_test_for_nextest!();
+    use warp::hyper::body::HttpBody as _;
+
+    let (torii, _) = create_torii().await;
+    let router = torii.create_api_router();
+
+    let mut block = new_dummy().commit();
+    for height in 1..=3 {
+        block.header.height = height;
+        torii.wsv.apply(block.clone().into()).await.unwrap();
+    }
+
+    let filter =
+        FilterBox::Pipeline(PipelineEventFilter::new().entity_kind(PipelineEntityKind::Block));
+    let encoded_filter: String = serde_json::to_string(&filter)
+        .unwrap()
+        .bytes()
+        .map(|byte| format!("%{byte:02X}"))
+        .collect();
+    let path = format!("/events/sse?filter={encoded_filter}&from_height=1");
+
+    let first_event = |last_event_id: Option<&str>| {
+        let mut request = warp::test::request().path(&path);
+        if let Some(last_event_id) = last_event_id {
+            request = request.header("last-event-id", last_event_id);
+        }
+        let router = router.clone();
+        async move {
+            let mut body = request
+                .filter(&router)
+                .await
+                .unwrap()
+                .into_response()
+                .into_body();
+            let chunk = time::timeout(Duration::from_secs(5), body.data())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+            let id = chunk
+                .lines()
+                .find_map(|line| line.strip_prefix("id:"))
+                .unwrap()
+                .to_owned();
+            let data = chunk
+                .lines()
+                .find_map(|line| line.strip_prefix("data:"))
+                .unwrap();
+            let event = serde_json::from_str::<SequencedEvent>(data).unwrap();
+            assert_eq!(
+                format!(
+                    "{}:{}",
+                    event.block_height.unwrap(),
+                    event.index_in_block.unwrap()
+                ),
+                id
+            );
+            (id, event.block_height)
+        }
+    };
+
+    let (id, block_height) = first_event(None).await;
+    assert_eq!(block_height, Some(1));
+    let (resumed_id, resumed_block_height) = first_event(Some(&id)).await;
+    assert_eq!(resumed_block_height, Some(2));
+    assert!(resumed_id.starts_with("2:"));
+
+    let response = warp::test::request()
+        .path(&path)
+        .header("last-event-id", "7")
+        .filter(&router)
+        .await
+        .unwrap()
+        .into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn committed_block_events_fail_once_no_longer_kept() {
+    let config = iroha_config::wsv::Configuration {
+        data_events_retention_blocks: 1,
+        ..iroha_config::wsv::Configuration::default()
+    };
+    let (events_sender, _) = tokio::sync::broadcast::channel(1);
+    let wsv = WorldStateView::from_configuration(config, World::default(), events_sender);
+
+    let mut block = new_dummy().commit();
+    let mut blocks = Vec::new();
+    for height in 1..=3 {
+        block.header.height = height;
+        let block: VersionedCommittedBlock = block.clone().into();
+        wsv.apply(block.clone()).await.unwrap();
+        blocks.push(block);
+    }
+
+    let events = committed_block_events(&wsv, &blocks[2]).unwrap();
+    assert!(!events.is_empty());
+    for ((position, _), index) in events.iter().zip(0_u64..) {
+        assert_eq!(position.to_string(), format!("3:{index}"));
+    }
+    assert!(matches!(
+        committed_block_events(&wsv, &blocks[0]),
+        Err(crate::event::Error::FromHeightNotKept {
+            requested: 1,
+            earliest: 3
+        })
+    ));
+}
@@ -0,0 +1,590 @@
+//! Delivery of events to webhooks: HTTP endpoints to which the events
+//! matching the configured filters are sent via `POST`.
+//!
+//! Every matching event is first written to the on-disk [`Outbox`] of
+//! the webhook, so that it is delivered even if the endpoint is down
+//! for a while or the peer restarts before delivering it. Events are
+//! delivered one by one in the order they happened and failed
+//! deliveries are retried with exponential backoff.
+//!
+//! Only the events produced while the peer is running are collected:
+//! the ones of the blocks committed while it's down are never sent.
+//! Events which are lost while it's running, because the outbox is
+//! full or the sink fell behind the event stream, still take up
+//! delivery numbers, so that endpoints can detect the loss by a gap.
+#![allow(clippy::std_instead_of_core, clippy::arithmetic)]
+
+use std::{
+    collections::VecDeque,
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use eyre::{eyre, Result};
+use hmac::{Hmac, Mac, NewMac};
+use hyper::{client::HttpConnector, header::CONTENT_TYPE, Body, Client, Request, Uri};
+use iroha_config::torii::{Configuration, Webhook};
+use iroha_core::{EventsReceiver, EventsSender};
+use iroha_data_model::prelude::*;
+use sha2::{Digest, Sha256};
+use tokio::{
+    fs,
+    sync::{broadcast::error::RecvError, Notify},
+    task::JoinHandle,
+};
+
+/// Header with the hex-encoded HMAC-SHA256 of the payload, keyed with the webhook secret.
+pub const SIGNATURE_HEADER: &str = "x-iroha-signature";
+/// Header with the number of the delivery. Unique per webhook, so
+/// that endpoints can recognise retried deliveries. A gap in the
+/// numbers means that events were lost.
+pub const DELIVERY_HEADER: &str = "x-iroha-delivery";
+
+const PAYLOAD_EXTENSION: &str = "json";
+const NEXT_DELIVERY_FILE: &str = "next_delivery";
+
+/// Start delivering the events sent through `events` to the webhooks
+/// listed in the `config`, until shutdown is notified.
+///
+/// # Errors
+/// Fails if the URL of a webhook isn't supported or its outbox can't be opened
+pub async fn start(
+    config: &Configuration,
+    events: &EventsSender,
+    notify_shutdown: &Arc<Notify>,
+) -> Result<Vec<JoinHandle<()>>> {
+    let mut handles = Vec::with_capacity(config.webhooks.len());
+    for webhook in &config.webhooks {
+        let sink = WebhookSink::new(webhook.clone(), config).await?;
+        let events = events.subscribe();
+        let notify_shutdown = Arc::clone(notify_shutdown);
+        handles.push(tokio::task::spawn(async move {
+            sink.run(events, &notify_shutdown).await;
+        }));
+    }
+    Ok(handles)
+}
+
+/// HMAC-SHA256 of `payload` keyed with `secret`, hex-encoded.
+pub fn sign(secret: &str, payload: &[u8]) -> String {
+    #[allow(clippy::expect_used)]
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(payload);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Payloads waiting to be delivered to a webhook, one file per payload.
+///
+/// Files are named after the numbers of the deliveries, which keep
+/// increasing across restarts.
+#[derive(Debug)]
+pub struct Outbox {
+    dir: PathBuf,
+    capacity: usize,
+    state: Mutex<OutboxState>,
+}
+
+#[derive(Debug)]
+struct OutboxState {
+    next_delivery: u64,
+    pending: VecDeque<u64>,
+}
+
+impl Outbox {
+    /// Open the outbox of the webhook with the given `url` in
+    /// `outbox_path`, creating it if it doesn't exist. At most
+    /// `capacity` payloads are kept at a time.
+    ///
+    /// # Errors
+    /// Fails if the outbox directory can't be created or read
+    pub async fn open(
+        outbox_path: impl AsRef<Path>,
+        url: &str,
+        capacity: usize,
+    ) -> io::Result<Self> {
+        let dir = outbox_path
+            .as_ref()
+            .join(hex::encode(Sha256::digest(url.as_bytes())));
+        fs::create_dir_all(&dir).await?;
+
+        let mut pending = Vec::new();
+        let mut entries = fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension() != Some(PAYLOAD_EXTENSION.as_ref()) {
+                continue;
+            }
+            if let Some(delivery) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u64>().ok())
+            {
+                pending.push(delivery);
+            }
+        }
+        pending.sort_unstable();
+
+        let stored_next_delivery = match fs::read_to_string(dir.join(NEXT_DELIVERY_FILE)).await {
+            Ok(next_delivery) => next_delivery.trim().parse().unwrap_or_default(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => 0,
+            Err(error) => return Err(error),
+        };
+        let next_delivery = pending.last().map_or(stored_next_delivery, |last| {
+            stored_next_delivery.max(last + 1)
+        });
+
+        Ok(Self {
+            dir,
+            capacity,
+            state: Mutex::new(OutboxState {
+                next_delivery,
+                pending: pending.into(),
+            }),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<'_, OutboxState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn path(&self, delivery: u64) -> PathBuf {
+        self.dir
+            .join(format!("{delivery:020}"))
+            .with_extension(PAYLOAD_EXTENSION)
+    }
+
+    /// Keep `payload` until it's delivered and return the number of its delivery.
+    ///
+    /// The number is taken even if the payload can't be kept, so that
+    /// the loss shows up as a gap in the delivered numbers.
+    ///
+    /// # Errors
+    /// Fails if the outbox is full or the payload can't be written to disk
+    pub async fn push(&self, payload: &[u8]) -> io::Result<u64> {
+        let (delivery, is_full) = {
+            let state = self.state();
+            (state.next_delivery, state.pending.len() >= self.capacity)
+        };
+        self.skip(1).await?;
+        if is_full {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("outbox is full ({} payloads)", self.capacity),
+            ));
+        }
+        write_atomically(&self.path(delivery), payload).await?;
+
+        self.state().pending.push_back(delivery);
+        Ok(delivery)
+    }
+
+    /// Take up the numbers of `count` deliveries of lost payloads,
+    /// leaving a gap before the next delivered one.
+    ///
+    /// # Errors
+    /// Fails if the next delivery number can't be written to disk
+    pub async fn skip(&self, count: u64) -> io::Result<()> {
+        let next_delivery = self.state().next_delivery.saturating_add(count);
+        write_atomically(
+            &self.dir.join(NEXT_DELIVERY_FILE),
+            next_delivery.to_string(),
+        )
+        .await?;
+        self.state().next_delivery = next_delivery;
+        Ok(())
+    }
+
+    /// Number of the oldest undelivered payload, if there is one.
+    pub fn first(&self) -> Option<u64> {
+        self.state().pending.front().copied()
+    }
+
+    /// Payload of the given `delivery`.
+    ///
+    /// # Errors
+    /// Fails if the payload can't be read from disk
+    pub async fn read(&self, delivery: u64) -> io::Result<Vec<u8>> {
+        fs::read(self.path(delivery)).await
+    }
+
+    /// Forget the payload of the given `delivery`, after it's delivered or dropped.
+    ///
+    /// # Errors
+    /// Fails if the payload can't be removed from disk
+    pub async fn remove(&self, delivery: u64) -> io::Result<()> {
+        self.state().pending.retain(|pending| *pending != delivery);
+        match fs::remove_file(self.path(delivery)).await {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Write `contents` to a temporary file first and then move it to
+/// `path`, so that a crash never leaves a partially written file.
+async fn write_atomically(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents).await?;
+    fs::rename(&tmp_path, path).await
+}
+
+/// Delivers the events matching the filters of a [`Webhook`] to it.
+#[derive(Debug)]
+pub struct WebhookSink {
+    webhook: Webhook,
+    outbox: Outbox,
+    new_payload: Notify,
+    client: Client<HttpConnector>,
+    request_timeout: Duration,
+    retry_delay: Duration,
+    max_retry_delay: Duration,
+    max_attempts: u32,
+}
+
+impl WebhookSink {
+    /// Construct [`Self`] for `webhook`, opening its outbox.
+    ///
+    /// # Errors
+    /// Fails if the URL of the webhook isn't a valid `http` URL or the
+    /// outbox can't be opened
+    pub async fn new(webhook: Webhook, config: &Configuration) -> io::Result<Self> {
+        let uri = webhook
+            .url
+            .parse::<Uri>()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        if uri.scheme_str() != Some("http") {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "webhook URL `{}` isn't supported, only `http` URLs are",
+                    webhook.url
+                ),
+            ));
+        }
+        let outbox = Outbox::open(
+            &config.webhook_outbox_path,
+            &webhook.url,
+            usize::try_from(config.webhook_max_outbox_size).unwrap_or(usize::MAX),
+        )
+        .await?;
+
+        Ok(Self {
+            webhook,
+            outbox,
+            new_payload: Notify::new(),
+            client: Client::new(),
+            request_timeout: Duration::from_millis(config.webhook_request_timeout_ms),
+            retry_delay: Duration::from_millis(config.webhook_retry_delay_ms),
+            max_retry_delay: Duration::from_millis(config.webhook_max_retry_delay_ms),
+            max_attempts: config.webhook_max_attempts,
+        })
+    }
+
+    /// Keep the matching `events` in the outbox and deliver them until
+    /// shutdown is notified. Events left in the outbox are delivered
+    /// first.
+    pub async fn run(&self, events: EventsReceiver, notify_shutdown: &Notify) {
+        tokio::select! {
+            _ = self.collect(events) => (),
+            _ = self.deliver() => (),
+            _ = notify_shutdown.notified() => (),
+        }
+    }
+
+    async fn collect(&self, mut events: EventsReceiver) {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(url = %self.webhook.url, skipped, "Webhook sink lagged behind, events were skipped");
+                    // It's unknown which of them matched, so every one of them takes up a number
+                    if let Err(error) = self.outbox.skip(skipped).await {
+                        iroha_logger::error!(%error, url = %self.webhook.url, "Failed to record skipped events in webhook outbox");
+                    }
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+            if !self
+                .webhook
+                .filters
+                .iter()
+                .any(|filter| filter.matches(&event))
+            {
+                continue;
+            }
+            if let Err(error) = self.enqueue(&event).await {
+                iroha_logger::error!(%error, url = %self.webhook.url, "Failed to keep event in webhook outbox");
+            }
+        }
+    }
+
+    async fn enqueue(&self, event: &Event) -> Result<()> {
+        let payload = serde_json::to_vec(event)?;
+        self.outbox.push(&payload).await?;
+        self.new_payload.notify_one();
+        Ok(())
+    }
+
+    async fn deliver(&self) {
+        loop {
+            let delivery = match self.outbox.first() {
+                Some(delivery) => delivery,
+                None => {
+                    self.new_payload.notified().await;
+                    continue;
+                }
+            };
+
+            match self.outbox.read(delivery).await {
+                Ok(payload) => {
+                    if !self.post_with_retries(delivery, &payload).await {
+                        iroha_logger::error!(url = %self.webhook.url, delivery, attempts = self.max_attempts, "Dropping event which couldn't be delivered to webhook");
+                    }
+                }
+                Err(error) => {
+                    iroha_logger::error!(%error, url = %self.webhook.url, delivery, "Failed to read event from webhook outbox");
+                }
+            }
+
+            if let Err(error) = self.outbox.remove(delivery).await {
+                iroha_logger::error!(%error, url = %self.webhook.url, delivery, "Failed to remove event from webhook outbox");
+            }
+        }
+    }
+
+    /// Returns whether `payload` was delivered within the allowed number of attempts.
+    async fn post_with_retries(&self, delivery: u64, payload: &[u8]) -> bool {
+        let mut retry_delay = self.retry_delay;
+        for attempt in 1..=self.max_attempts {
+            match self.post(delivery, payload).await {
+                Ok(()) => return true,
+                Err(error) => {
+                    iroha_logger::warn!(%error, url = %self.webhook.url, delivery, attempt, "Failed to deliver event to webhook");
+                }
+            }
+            if attempt < self.max_attempts {
+                tokio::time::sleep(retry_delay).await;
+                retry_delay = (retry_delay * 2).min(self.max_retry_delay);
+            }
+        }
+        false
+    }
+
+    async fn post(&self, delivery: u64, payload: &[u8]) -> Result<()> {
+        let request = Request::post(self.webhook.url.as_str())
+            .header(CONTENT_TYPE, "application/json")
+            .header(DELIVERY_HEADER, delivery)
+            .header(SIGNATURE_HEADER, sign(&self.webhook.secret, payload))
+            .body(Body::from(payload.to_vec()))?;
+
+        let response = tokio::time::timeout(self.request_timeout, self.client.request(request))
+            .await
+            .map_err(|_elapsed| {
+                eyre!(
+                    "Webhook didn't respond within {} ms",
+                    self.request_timeout.as_millis()
+                )
+            })??;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(eyre!("Webhook responded with {}", response.status()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::restriction)]
+
+    use std::{
+        net::SocketAddr,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use iroha_core::prelude::Hash;
+    use tokio::sync::{broadcast, mpsc};
+    use warp::{http::StatusCode, hyper::body::Bytes, Filter as _};
+
+    use super::*;
+
+    /// Local HTTP stand-in for a webhook endpoint, which fails the
+    /// first `failures` requests and passes the rest to the receiver.
+    fn stand_in(
+        failures: usize,
+    ) -> (
+        SocketAddr,
+        mpsc::UnboundedReceiver<(warp::http::HeaderMap, Bytes)>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let failures = Arc::new(AtomicUsize::new(failures));
+        let route = warp::post()
+            .and(warp::header::headers_cloned())
+            .and(warp::body::bytes())
+            .map(move |headers, body| {
+                if failures
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                        left.checked_sub(1)
+                    })
+                    .is_ok()
+                {
+                    return StatusCode::INTERNAL_SERVER_ERROR;
+                }
+                sender.send((headers, body)).unwrap();
+                StatusCode::OK
+            });
+        let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+        tokio::spawn(server);
+        (addr, receiver)
+    }
+
+    fn pipeline_event(status: PipelineStatus) -> Event {
+        PipelineEvent::new(PipelineEntityKind::Transaction, status, Hash::zeroed()).into()
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_matching_events_with_retries() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let (addr, mut requests) = stand_in(2);
+        let config = Configuration {
+            webhooks: vec![Webhook {
+                url: format!("http://{addr}/hook"),
+                filters: vec![FilterBox::Pipeline(
+                    PipelineEventFilter::new().status_kind(PipelineStatusKind::Committed),
+                )],
+                secret: "secret".to_owned(),
+            }],
+            webhook_outbox_path: outbox_dir.path().to_str().unwrap().to_owned(),
+            webhook_retry_delay_ms: 10,
+            ..Configuration::default()
+        };
+        let (events, _) = broadcast::channel(16);
+        let notify_shutdown = Arc::new(Notify::new());
+        let handles = start(&config, &events, &notify_shutdown).await.unwrap();
+
+        let committed = pipeline_event(PipelineStatus::Committed);
+        events
+            .send(pipeline_event(PipelineStatus::Validating))
+            .unwrap();
+        events.send(committed.clone()).unwrap();
+
+        let (headers, body) = tokio::time::timeout(Duration::from_secs(5), requests.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(serde_json::from_slice::<Event>(&body).unwrap(), committed);
+        assert_eq!(headers[SIGNATURE_HEADER], sign("secret", &body));
+        assert_eq!(headers[DELIVERY_HEADER], "0");
+
+        notify_shutdown.notify_waiters();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn outbox_keeps_payloads_until_removed() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let url = "http://127.0.0.1:1/hook";
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 16).await.unwrap();
+        assert_eq!(outbox.push(b"first").await.unwrap(), 0);
+        assert_eq!(outbox.push(b"second").await.unwrap(), 1);
+        outbox.remove(0).await.unwrap();
+        drop(outbox);
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 16).await.unwrap();
+        assert_eq!(outbox.first(), Some(1));
+        assert_eq!(outbox.read(1).await.unwrap(), b"second");
+        outbox.remove(1).await.unwrap();
+        drop(outbox);
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 16).await.unwrap();
+        assert_eq!(outbox.first(), None);
+        assert_eq!(outbox.push(b"third").await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn full_outbox_refuses_payloads() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let url = "http://127.0.0.1:1/hook";
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 2).await.unwrap();
+        assert_eq!(outbox.push(b"first").await.unwrap(), 0);
+        assert_eq!(outbox.push(b"second").await.unwrap(), 1);
+        assert!(outbox.push(b"third").await.is_err());
+
+        // The refused payload leaves a gap in the numbers
+        outbox.remove(0).await.unwrap();
+        assert_eq!(outbox.push(b"fourth").await.unwrap(), 3);
+        assert_eq!(outbox.read(3).await.unwrap(), b"fourth");
+    }
+
+    #[tokio::test]
+    async fn skipped_deliveries_leave_gap_across_restarts() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let url = "http://127.0.0.1:1/hook";
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 16).await.unwrap();
+        assert_eq!(outbox.push(b"first").await.unwrap(), 0);
+        outbox.skip(3).await.unwrap();
+        assert_eq!(outbox.push(b"second").await.unwrap(), 4);
+        outbox.skip(2).await.unwrap();
+        drop(outbox);
+
+        let outbox = Outbox::open(outbox_dir.path(), url, 16).await.unwrap();
+        assert_eq!(outbox.push(b"third").await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_http_urls() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let config = Configuration {
+            webhook_outbox_path: outbox_dir.path().to_str().unwrap().to_owned(),
+            ..Configuration::default()
+        };
+        let webhook = |url: &str| Webhook {
+            url: url.to_owned(),
+            filters: Vec::new(),
+            secret: "secret".to_owned(),
+        };
+
+        for url in ["https://127.0.0.1:1/hook", "127.0.0.1:1/hook", "not a url"] {
+            let error = WebhookSink::new(webhook(url), &config).await.unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{url}");
+        }
+        assert!(
+            WebhookSink::new(webhook("http://127.0.0.1:1/hook"), &config)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn unresponsive_webhook_times_out() {
+        let outbox_dir = tempfile::tempdir().unwrap();
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let config = Configuration {
+            webhook_outbox_path: outbox_dir.path().to_str().unwrap().to_owned(),
+            webhook_request_timeout_ms: 10,
+            ..Configuration::default()
+        };
+        let webhook = Webhook {
+            url: format!("http://{}/hook", listener.local_addr().unwrap()),
+            filters: Vec::new(),
+            secret: "secret".to_owned(),
+        };
+        let sink = WebhookSink::new(webhook, &config).await.unwrap();
+
+        let error = tokio::time::timeout(Duration::from_secs(5), sink.post(0, b"payload"))
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert!(error.to_string().contains("didn't respond"), "{error}");
+    }
+}
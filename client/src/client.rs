@@ -6,7 +6,12 @@
     clippy::std_instead_of_alloc
 )]
 use std::{
-    collections::HashMap, fmt::Debug, marker::PhantomData, sync::mpsc, thread, time::Duration,
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    marker::PhantomData,
+    sync::mpsc,
+    thread,
+    time::Duration,
 };
 
 use derive_more::{DebugCustom, Display};
@@ -14,8 +19,12 @@ use eyre::{eyre, Result, WrapErr};
 use http_default::WebSocketStream;
 use iroha_config::{client::Configuration, torii::uri, GetConfiguration, PostConfiguration};
 use iroha_core::smartcontracts::isi::query::Error as QueryError;
-use iroha_crypto::{HashOf, KeyPair};
-use iroha_data_model::{predicate::PredicateBox, prelude::*, query::SignedQueryRequest};
+use iroha_crypto::{HashOf, KeyPair, PublicKey, SignatureOf};
+use iroha_data_model::{
+    predicate::PredicateBox,
+    prelude::*,
+    query::{self, SignedQueryRequest},
+};
 use iroha_logger::prelude::*;
 use iroha_primitives::small::SmallStr;
 use iroha_telemetry::metrics::Status;
@@ -62,42 +71,40 @@ where
     type Output = QueryHandlerResult<ClientQueryOutput<R>>;
 
     fn handle(self, resp: Response<Vec<u8>>) -> Self::Output {
-        // Separate-compilation friendly response handling
-        fn _handle_query_response_base(
-            resp: &Response<Vec<u8>>,
-        ) -> QueryHandlerResult<VersionedPaginatedQueryResult> {
-            match resp.status() {
-                StatusCode::OK => {
-                    let res =
-                        try_decode_all_or_just_decode!(VersionedPaginatedQueryResult, resp.body());
-                    res.wrap_err(
-                        "Failed to decode the whole response body as `VersionedPaginatedQueryResult`",
-                    )
-                    .map_err(Into::into)
-                }
-                StatusCode::BAD_REQUEST
-                | StatusCode::UNAUTHORIZED
-                | StatusCode::FORBIDDEN
-                | StatusCode::NOT_FOUND => {
-                    let mut res = QueryError::decode_all(&mut resp.body().as_ref());
-                    if res.is_err() {
-                        warn!("Can't decode query error, not all bytes were consumed");
-                        res = QueryError::decode(&mut resp.body().as_ref());
-                    }
-                    let err =
-                        res.wrap_err("Failed to decode the whole response body as `QueryError`")?;
-                    Err(ClientQueryError::QueryError(err))
-                }
-                _ => Err(ResponseReport::with_msg("Unexpected query response", resp).into()),
-            }
-        }
-
-        _handle_query_response_base(&resp).and_then(|VersionedPaginatedQueryResult::V1(result)| {
+        handle_query_response_base(&resp).and_then(|VersionedPaginatedQueryResult::V1(result)| {
             ClientQueryOutput::try_from(result).map_err(Into::into)
         })
     }
 }
 
+// Separate-compilation friendly response handling
+fn handle_query_response_base(
+    resp: &Response<Vec<u8>>,
+) -> QueryHandlerResult<VersionedPaginatedQueryResult> {
+    match resp.status() {
+        StatusCode::OK => {
+            let res = try_decode_all_or_just_decode!(VersionedPaginatedQueryResult, resp.body());
+            res.wrap_err(
+                "Failed to decode the whole response body as `VersionedPaginatedQueryResult`",
+            )
+            .map_err(Into::into)
+        }
+        StatusCode::BAD_REQUEST
+        | StatusCode::UNAUTHORIZED
+        | StatusCode::FORBIDDEN
+        | StatusCode::NOT_FOUND => {
+            let mut res = QueryError::decode_all(&mut resp.body().as_ref());
+            if res.is_err() {
+                warn!("Can't decode query error, not all bytes were consumed");
+                res = QueryError::decode(&mut resp.body().as_ref());
+            }
+            let err = res.wrap_err("Failed to decode the whole response body as `QueryError`")?;
+            Err(ClientQueryError::QueryError(err))
+        }
+        _ => Err(ResponseReport::with_msg("Unexpected query response", resp).into()),
+    }
+}
+
 /// Different errors as a result of query response handling
 #[derive(Debug, thiserror::Error)]
 // `QueryError` variant is too large (32 bytes), but I think that this enum is not
@@ -198,6 +205,10 @@ where
     pub pagination: Pagination,
     /// See [`iroha_data_model::prelude::PaginatedQueryResult`]
     pub total: u64,
+    /// See [`iroha_data_model::prelude::PaginatedQueryResult`]
+    pub cursor: Option<Cursor>,
+    /// See [`iroha_data_model::prelude::PaginatedQueryResult`]
+    pub signature: Option<QueryResponseSignature>,
 }
 
 impl<R> ClientQueryOutput<R>
@@ -224,6 +235,8 @@ where
             pagination,
             total,
             filter,
+            cursor,
+            signature,
         }: PaginatedQueryResult,
     ) -> Result<Self> {
         let QueryResult(result) = result;
@@ -236,10 +249,41 @@ where
             pagination,
             total,
             filter,
+            cursor,
+            signature,
         })
     }
 }
 
+/// Torii endpoint of a peer whose signed query responses count towards a quorum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumPeer {
+    /// Url for accessing the peer
+    pub torii_url: SmallStr,
+    /// Key the peer signs query responses with
+    pub public_key: PublicKey,
+}
+
+/// Output of a query answered identically by a quorum of peers, together
+/// with the evidence: the peers' signatures over the response.
+#[derive(Clone, Debug)]
+pub struct QuorumQueryOutput<R>
+where
+    R: Query + Into<QueryBox> + Debug,
+    <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
+{
+    /// Query output
+    pub output: R::Output,
+    /// Hash of the payload of the query which the peers answered
+    pub query_hash: HashOf<query::Payload>,
+    /// Total number of results, which the peers signed along with the output
+    pub total: u64,
+    /// Height of the latest block committed when the peers executed the query
+    pub block_height: u64,
+    /// Signatures of the peers over the [`QueryResponsePayload`]
+    pub signatures: Vec<SignatureOf<QueryResponsePayload>>,
+}
+
 /// Iroha client
 #[derive(Clone, DebugCustom, Display)]
 #[debug(
@@ -248,6 +292,8 @@ where
 )]
 #[display(fmt = "{}@{torii_url}", "key_pair.public_key()")]
 pub struct Client {
+    /// Identifier of the chain transactions and queries are meant for
+    chain_id: ChainId,
     /// Url for accessing iroha node
     torii_url: SmallStr,
     /// Url to report status for administration
@@ -298,6 +344,7 @@ impl Client {
         }
 
         Ok(Self {
+            chain_id: configuration.chain_id.clone(),
             torii_url: configuration.torii_api_url.clone(),
             telemetry_url: configuration.torii_telemetry_url.clone(),
             transaction_limits: configuration.transaction_limits,
@@ -328,7 +375,8 @@ impl Client {
             self.account_id.clone(),
             instructions,
             self.proposed_transaction_ttl_ms,
-        );
+        )
+        .with_chain_id(self.chain_id.clone());
 
         let transaction_with_metadata = if self.add_transaction_nonce {
             let nonce = rand::thread_rng().gen::<u32>();
@@ -434,6 +482,37 @@ impl Client {
         Ok(hash)
     }
 
+    /// Execute the prebuilt transaction on the peer without submitting it.
+    /// Returns the validation result, events, fuel and state changes the transaction would produce.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn simulate_transaction(&self, transaction: Transaction) -> Result<TransactionSimulation> {
+        transaction.check_limits(&self.transaction_limits)?;
+        let transaction: VersionedTransaction = transaction.into();
+        let hash = transaction.hash();
+        let response = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            format!("{}/{}", &self.torii_url, uri::SIMULATE_TRANSACTION),
+        )
+        .headers(self.headers.clone())
+        .body(transaction.encode_versioned())
+        .build()?
+        .send()
+        .wrap_err_with(|| format!("Failed to simulate transaction with hash {:?}", hash))?;
+
+        if response.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to simulate transaction with HTTP status: {}, {}",
+                response.status(),
+                std::str::from_utf8(response.body()).unwrap_or(""),
+            ));
+        }
+        let simulation =
+            try_decode_all_or_just_decode!(VersionedTransactionSimulation, response.body())?;
+        Ok(simulation.into_v1())
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -654,6 +733,50 @@ impl Client {
         sorting: Sorting,
         filter: PredicateBox,
     ) -> Result<(B, QueryResponseHandler<R>)>
+    where
+        R: Query + Into<QueryBox> + Debug,
+        <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
+        B: RequestBuilder,
+    {
+        self.prepare_query_request_with_cursor(request, pagination, sorting, filter, None)
+    }
+
+    /// Same as [`Self::prepare_query_request`], but continues a previous query from `cursor`.
+    ///
+    /// # Errors
+    /// Fails if query signing fails.
+    pub fn prepare_query_request_with_cursor<R, B>(
+        &self,
+        request: R,
+        pagination: Pagination,
+        sorting: Sorting,
+        filter: PredicateBox,
+        cursor: Option<Cursor>,
+    ) -> Result<(B, QueryResponseHandler<R>)>
+    where
+        R: Query + Into<QueryBox> + Debug,
+        <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
+        B: RequestBuilder,
+    {
+        self.prepare_projected_query_request(
+            request,
+            pagination,
+            sorting,
+            Projection::default(),
+            filter,
+            cursor,
+        )
+    }
+
+    fn prepare_projected_query_request<R, B>(
+        &self,
+        request: R,
+        pagination: Pagination,
+        sorting: Sorting,
+        projection: Projection,
+        filter: PredicateBox,
+        cursor: Option<Cursor>,
+    ) -> Result<(B, QueryResponseHandler<R>)>
     where
         R: Query + Into<QueryBox> + Debug,
         <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
@@ -661,7 +784,10 @@ impl Client {
     {
         let pagination: Vec<_> = pagination.into();
         let sorting: Vec<_> = sorting.into();
-        let request = QueryRequest::new(request.into(), self.account_id.clone(), filter);
+        let projection: Vec<_> = projection.into();
+        let cursor: Vec<_> = CursorRequest::new(cursor).into();
+        let request = QueryRequest::new(request.into(), self.account_id.clone(), filter)
+            .with_chain_id(self.chain_id.clone());
         let request: VersionedSignedQueryRequest = self.sign_query(request)?.into();
 
         Ok((
@@ -671,6 +797,8 @@ impl Client {
             )
             .params(pagination)
             .params(sorting)
+            .params(projection)
+            .params(cursor)
             .headers(self.headers.clone())
             .body(request.encode_versioned()),
             QueryResponseHandler::default(),
@@ -779,6 +907,158 @@ impl Client {
         self.request_with_pagination_and_sorting(request, Pagination::default(), sorting)
     }
 
+    /// Requests the next page of a query started earlier, using the
+    /// cursor returned in [`ClientQueryOutput::cursor`]. The `request`
+    /// must be the same query that produced the cursor.
+    ///
+    /// # Errors
+    /// Fails if sending request fails or the cursor has expired
+    pub fn request_with_cursor<R>(
+        &self,
+        request: R,
+        cursor: Cursor,
+        pagination: Pagination,
+    ) -> QueryHandlerResult<ClientQueryOutput<R>>
+    where
+        R: Query + Into<QueryBox> + Debug,
+        <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
+    {
+        iroha_logger::trace!(?request, %cursor, %pagination);
+        let (req, resp_handler) = self
+            .prepare_query_request_with_cursor::<R, DefaultRequestBuilder>(
+                request,
+                pagination,
+                Sorting::default(),
+                PredicateBox::default(),
+                Some(cursor),
+            )?;
+        let response = req.build()?.send()?;
+        resp_handler.handle(response)
+    }
+
+    /// Requests only the `projection` fields of the query result entries.
+    /// Every entry of the output is a [`Value::LimitedMetadata`] mapping
+    /// the names of the fields to their values.
+    ///
+    /// # Errors
+    /// Fails if sending request fails
+    pub fn request_with_projection(
+        &self,
+        request: impl Into<QueryBox> + Debug,
+        pagination: Pagination,
+        projection: Projection,
+    ) -> QueryHandlerResult<ClientQueryOutput<QueryBox>> {
+        iroha_logger::trace!(?request, %pagination, ?projection);
+        let (req, resp_handler) = self
+            .prepare_projected_query_request::<QueryBox, DefaultRequestBuilder>(
+                request.into(),
+                pagination,
+                Sorting::default(),
+                projection,
+                PredicateBox::default(),
+                None,
+            )?;
+        let response = req.build()?.send()?;
+        resp_handler.handle(response)
+    }
+
+    /// Requests the query from `peers` one by one, until `max_faulty_peers + 1`
+    /// of them return the same response signed with their keys. As at most
+    /// `max_faulty_peers` peers are faulty, at least one honest peer vouches for it.
+    ///
+    /// # Errors
+    /// Fails if signing the query fails or if not enough peers return matching signed responses
+    pub fn request_with_quorum<R>(
+        &self,
+        request: R,
+        peers: &[QuorumPeer],
+        max_faulty_peers: usize,
+    ) -> QueryHandlerResult<QuorumQueryOutput<R>>
+    where
+        R: Query + Into<QueryBox> + Debug,
+        <R::Output as TryFrom<Value>>::Error: Into<eyre::Error>,
+    {
+        iroha_logger::trace!(?request, max_faulty_peers);
+        let request = QueryRequest::new(
+            request.into(),
+            self.account_id.clone(),
+            PredicateBox::default(),
+        )
+        .with_chain_id(self.chain_id.clone());
+        let request = self.sign_query(request)?;
+        let query_hash = request.payload.hash();
+        let body = VersionedSignedQueryRequest::from(request).encode_versioned();
+        let signing: Vec<_> = ResponseSigningRequest::new(true).into();
+        let quorum = max_faulty_peers.saturating_add(1);
+
+        let mut responses: BTreeMap<(u64, u64, Value), Vec<SignatureOf<QueryResponsePayload>>> =
+            BTreeMap::new();
+        for peer in peers {
+            let response = DefaultRequestBuilder::new(
+                HttpMethod::POST,
+                format!("{}/{}", &peer.torii_url, uri::QUERY),
+            )
+            .params(signing.clone())
+            .headers(self.headers.clone())
+            .body(body.clone())
+            .build()
+            .and_then(|request| request.send())
+            .map_err(Into::into)
+            .and_then(|response| handle_query_response_base(&response));
+            let result = match response {
+                Ok(VersionedPaginatedQueryResult::V1(result)) => result,
+                Err(error) => {
+                    warn!(torii_url = %peer.torii_url, %error, "Peer failed to answer the query");
+                    continue;
+                }
+            };
+            let signed_by = result.signed_by(
+                query_hash,
+                Pagination::default(),
+                &Sorting::default(),
+                &Projection::default(),
+            );
+            if signed_by != Some(&peer.public_key) {
+                warn!(torii_url = %peer.torii_url, "Peer didn't sign the response with its key");
+                continue;
+            }
+            let signature = match result.signature {
+                Some(signature) => signature,
+                None => continue,
+            };
+
+            // The query isn't paginated, so the signed cursor is always `None`
+            let key = (signature.block_height, result.total, result.result.0);
+            let signatures = responses.entry(key.clone()).or_default();
+            if signatures
+                .iter()
+                .any(|known| known.public_key() == signature.signature.public_key())
+            {
+                continue;
+            }
+            signatures.push(signature.signature);
+            if signatures.len() >= quorum {
+                let (block_height, total, output) = key;
+                let output = R::Output::try_from(output)
+                    .map_err(Into::into)
+                    .wrap_err("Unexpected type")?;
+                return Ok(QuorumQueryOutput {
+                    output,
+                    query_hash,
+                    total,
+                    block_height,
+                    signatures: core::mem::take(signatures),
+                });
+            }
+        }
+
+        let matching = responses.values().map(Vec::len).max().unwrap_or(0);
+        Err(eyre!(
+            "Only {matching} of the required {quorum} peers returned matching signed responses"
+        )
+        .into())
+    }
+
     /// Query API entry point. Requests queries from `Iroha` peers.
     ///
     /// # Errors
@@ -792,6 +1072,28 @@ impl Client {
             .map(ClientQueryOutput::only_output)
     }
 
+    /// Requests the proof of inclusion of the committed transaction with `hash`
+    /// and verifies it offline, so that the answering peer doesn't have to be trusted.
+    ///
+    /// # Errors
+    /// Fails if sending request fails, if the proof is for another transaction
+    /// or if the block isn't signed by at least `min_signatures` of `trusted_peers`
+    pub fn request_verified_transaction_proof(
+        &self,
+        hash: HashOf<VersionedTransaction>,
+        trusted_peers: &[PublicKey],
+        min_signatures: usize,
+    ) -> QueryHandlerResult<TransactionProof> {
+        let proof = self.request(transaction::proof_by_hash(Hash::from(hash)))?;
+        if proof.transaction.hash() != hash {
+            return Err(eyre!("Proof is for transaction {}", proof.transaction.hash()).into());
+        }
+        proof
+            .verify(trusted_peers, min_signatures)
+            .wrap_err("Transaction proof is invalid")?;
+        Ok(proof)
+    }
+
     /// Connects through `WebSocket` to listen for `Iroha` pipeline and data events.
     ///
     /// # Errors
@@ -801,7 +1103,27 @@ impl Client {
         event_filter: FilterBox,
     ) -> Result<impl Iterator<Item = Result<Event>>> {
         iroha_logger::trace!(?event_filter);
-        events_api::EventIterator::new(self.events_handler(event_filter)?)
+        let events = events_api::EventIterator::new(self.events_handler(event_filter)?)?;
+        Ok(events.map(|event| event.map(|sequenced| sequenced.event)))
+    }
+
+    /// Connects through `WebSocket` to listen for `Iroha` pipeline and data events
+    /// of the committed blocks, starting with the block of the given `height`.
+    ///
+    /// The events of the already committed blocks are replayed first, so a subscription
+    /// can be resumed after a disconnect from the height of the last received event.
+    ///
+    /// # Errors
+    /// Fails if subscribing to websocket fails
+    pub fn listen_for_events_from_height(
+        &self,
+        event_filter: FilterBox,
+        height: u64,
+    ) -> Result<impl Iterator<Item = Result<SequencedEvent>>> {
+        iroha_logger::trace!(?event_filter, height);
+        events_api::EventIterator::new(
+            self.events_handler(EventSubscriptionRequest::new(event_filter).from_height(height))?,
+        )
     }
 
     /// Constructs an Events API handler. With it, you can use any WS client you want.
@@ -809,9 +1131,12 @@ impl Client {
     /// # Errors
     /// Fails if handler construction fails
     #[inline]
-    pub fn events_handler(&self, event_filter: FilterBox) -> Result<events_api::flow::Init> {
+    pub fn events_handler(
+        &self,
+        request: impl Into<EventSubscriptionRequest>,
+    ) -> Result<events_api::flow::Init> {
         events_api::flow::Init::new(
-            event_filter,
+            request.into(),
             self.headers.clone(),
             &format!("{}/{}", &self.torii_url, uri::SUBSCRIPTION),
         )
@@ -997,8 +1322,8 @@ pub mod events_api {
 
         /// Initialization struct for Events API flow.
         pub struct Init {
-            /// Event filter
-            filter: FilterBox,
+            /// Subscription request
+            request: EventSubscriptionRequest,
             /// HTTP request headers
             headers: HashMap<String, String>,
             /// TORII URL
@@ -1006,18 +1331,18 @@ pub mod events_api {
         }
 
         impl Init {
-            /// Construct new item with provided filter, headers and url.
+            /// Construct new item with provided subscription request, headers and url.
             ///
             /// # Errors
             /// Fails if [`transform_ws_url`] fails.
             #[inline]
             pub(in super::super) fn new(
-                filter: FilterBox,
+                request: EventSubscriptionRequest,
                 headers: HashMap<String, String>,
                 url: impl AsRef<str>,
             ) -> Result<Self> {
                 Ok(Self {
-                    filter,
+                    request,
                     headers,
                     url: transform_ws_url(url.as_ref())?,
                 })
@@ -1029,13 +1354,13 @@ pub mod events_api {
 
             fn init(self) -> InitData<R, Self::Next> {
                 let Self {
-                    filter,
+                    request,
                     headers,
                     url,
                 } = self;
 
                 let msg =
-                    VersionedEventSubscriberMessage::from(EventSubscriberMessage::from(filter))
+                    VersionedEventSubscriberMessage::from(EventSubscriberMessage::from(request))
                         .encode_versioned();
 
                 InitData::new(
@@ -1057,13 +1382,16 @@ pub mod events_api {
             where
                 Self::Next: FlowEvents,
             {
-                if let EventPublisherMessage::SubscriptionAccepted =
-                    try_decode_all_or_just_decode!(VersionedEventPublisherMessage, &message)?
-                        .into_v1()
+                match try_decode_all_or_just_decode!(VersionedEventPublisherMessage, &message)?
+                    .into_v1()
                 {
-                    Ok(Events)
-                } else {
-                    Err(eyre!("Expected `SubscriptionAccepted`."))
+                    EventPublisherMessage::SubscriptionAccepted => Ok(Events),
+                    EventPublisherMessage::SubscriptionRejected(reason) => {
+                        Err(eyre!("Subscription rejected: {reason}"))
+                    }
+                    EventPublisherMessage::Event(_) => {
+                        Err(eyre!("Expected `SubscriptionAccepted`."))
+                    }
                 }
             }
         }
@@ -1073,7 +1401,7 @@ pub mod events_api {
         pub struct Events;
 
         impl FlowEvents for Events {
-            type Event = iroha_data_model::prelude::Event;
+            type Event = iroha_data_model::prelude::SequencedEvent;
 
             fn message(&self, message: Vec<u8>) -> Result<EventData<Self::Event>> {
                 let event_socket_message =
@@ -1133,7 +1461,7 @@ pub mod events_api {
     }
 
     impl Iterator for EventIterator {
-        type Item = Result<Event>;
+        type Item = Result<SequencedEvent>;
 
         fn next(&mut self) -> Option<Self::Item> {
             loop {
@@ -1291,6 +1619,18 @@ pub mod transaction {
     pub fn by_hash(hash: impl Into<EvaluatesTo<Hash>>) -> FindTransactionByHash {
         FindTransactionByHash::new(hash)
     }
+
+    /// Construct a query to retrieve committed transaction by hash with the proof of its inclusion in a block
+    pub fn proof_by_hash(hash: impl Into<EvaluatesTo<Hash>>) -> FindTransactionProofByHash {
+        FindTransactionProofByHash::new(hash)
+    }
+
+    /// Construct a query to retrieve the reason why transaction with given hash was rejected
+    pub fn rejection_reason_by_hash(
+        hash: impl Into<EvaluatesTo<Hash>>,
+    ) -> FindTransactionRejectionReasonByHash {
+        FindTransactionRejectionReasonByHash::new(hash)
+    }
 }
 
 pub mod trigger {
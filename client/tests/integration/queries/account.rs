@@ -1,6 +1,9 @@
 #![allow(clippy::restriction)]
 
-use std::{collections::HashSet, str::FromStr as _};
+use std::{
+    collections::{BTreeSet, HashSet},
+    str::FromStr as _,
+};
 
 use eyre::Result;
 use iroha_client::client;
@@ -67,3 +70,41 @@ fn find_accounts_with_asset() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn find_account_ids_with_projection() -> Result<()> {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().start_with_runtime();
+    wait_for_genesis_committed(&vec![test_client.clone()], 0);
+
+    let accounts = test_client.request(FindAllAccounts::new())?;
+    let output = test_client
+        .request_with_projection(
+            FindAllAccounts::new(),
+            Pagination::default(),
+            Projection::fields([SortField::Id]),
+        )?
+        .only_output();
+
+    let id_key = Name::from_str("id")?;
+    let projected_ids = if let Value::Vec(entries) = output {
+        entries
+            .into_iter()
+            .map(|entry| match entry {
+                Value::LimitedMetadata(fields) if fields.iter().len() == 1 => {
+                    fields.get(&id_key).cloned()
+                }
+                _ => None,
+            })
+            .collect::<Option<BTreeSet<_>>>()
+    } else {
+        None
+    }
+    .expect("Every entry is projected to its id");
+
+    let ids: BTreeSet<_> = accounts
+        .iter()
+        .map(|account| Value::Id(IdBox::AccountId(account.id().clone())))
+        .collect();
+    assert_eq!(projected_ids, ids);
+    Ok(())
+}
@@ -284,3 +284,48 @@ fn correct_sorting_of_asset_definitions() {
         domains
     );
 }
+
+#[test]
+fn correct_sorting_of_holders_by_quantity() {
+    let (_rt, _peer, test_client) = <PeerBuilder>::new().start_with_runtime();
+
+    let asset_definition_id = AssetDefinitionId::from_str("gold#wonderland").expect("Valid");
+    let mut instructions: Vec<Instruction> =
+        vec![RegisterBox::new(AssetDefinition::quantity(asset_definition_id.clone())).into()];
+
+    // Two holders with equal quantity are ordered by their ids
+    let quantities = [3_u32, 7, 1, 7, 5];
+    for (i, quantity) in quantities.into_iter().enumerate() {
+        let account_id = AccountId::from_str(&format!("holder{}@wonderland", i)).expect("Valid");
+        instructions.push(RegisterBox::new(Account::new(account_id.clone(), [])).into());
+        instructions.push(
+            MintBox::new(
+                Value::U32(quantity),
+                IdBox::AssetId(AssetId::new(asset_definition_id.clone(), account_id)),
+            )
+            .into(),
+        );
+    }
+
+    test_client
+        .submit_all_blocking(instructions)
+        .expect("Valid");
+
+    let res = test_client
+        .request_with_sorting(
+            FindAssetsByAssetDefinitionId::new(asset_definition_id),
+            Sorting::by_keys([
+                SortKey::descending(SortField::Quantity),
+                SortKey::ascending(SortField::AccountId),
+            ]),
+        )
+        .expect("Valid");
+
+    assert_eq!(
+        res.output
+            .iter()
+            .map(|asset| asset.id().account_id.name.as_ref())
+            .collect::<Vec<_>>(),
+        ["holder1", "holder3", "holder4", "holder0", "holder2"]
+    );
+}
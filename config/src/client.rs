@@ -68,6 +68,9 @@ pub struct BasicAuth {
 #[serde(default)]
 #[config(env_prefix = "IROHA_")]
 pub struct Configuration {
+    /// Identifier of the chain the client submits transactions and queries to.
+    #[config(serde_as_str)]
+    pub chain_id: ChainId,
     /// Public key of the user account.
     #[config(serde_as_str)]
     pub public_key: PublicKey,
@@ -96,6 +99,7 @@ impl Default for Configuration {
         let (public_key, private_key) = Self::placeholder_keypair().into();
 
         Self {
+            chain_id: ChainId::default(),
             public_key,
             private_key,
             account_id: Self::placeholder_account(),
@@ -3,6 +3,7 @@
 
 use iroha_config_base::derive::{view, Documented, LoadFromEnv, Proxy};
 use iroha_crypto::{KeyPair, PrivateKey, PublicKey};
+use iroha_data_model::ChainId;
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_WAIT_FOR_PEERS_RETRY_COUNT_LIMIT: u64 = 100;
@@ -17,6 +18,9 @@ view! {
     #[serde(rename_all = "UPPERCASE")]
     #[config(env_prefix = "IROHA_GENESIS_")]
     pub struct Configuration {
+        /// Identifier of the chain. Transactions and queries signed for another chain are rejected.
+        #[config(serde_as_str)]
+        pub chain_id: ChainId,
         /// The public key of the genesis account, should be supplied to all peers.
         #[config(serde_as_str)]
         pub account_public_key: PublicKey,
@@ -41,6 +45,7 @@ impl Default for Configuration {
         let (public_key, private_key) = Self::placeholder_keypair().into();
 
         Self {
+            chain_id: ChainId::default(),
             account_public_key: public_key,
             account_private_key: Some(private_key),
             wait_for_peers_retry_count_limit: DEFAULT_WAIT_FOR_PEERS_RETRY_COUNT_LIMIT,
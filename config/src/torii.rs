@@ -1,6 +1,7 @@
 //! `Torii` configuration as well as the default values for the URLs used for the main endpoints: `p2p`, `telemetry`, but not `api`.
 #![allow(clippy::std_instead_of_core, clippy::arithmetic)]
 use iroha_config_base::derive::{Documented, LoadFromEnv, Proxy};
+use iroha_data_model::events::FilterBox;
 use serde::{Deserialize, Serialize};
 
 /// Default socket for p2p communication
@@ -11,6 +12,24 @@ pub const DEFAULT_TORII_TELEMETRY_URL: &str = "127.0.0.1:8180";
 pub const DEFAULT_TORII_MAX_TRANSACTION_SIZE: u32 = 2_u32.pow(15);
 /// Default upper bound on `content-length` specified in the HTTP request header
 pub const DEFAULT_TORII_MAX_CONTENT_LENGTH: u32 = 2_u32.pow(12) * 4000;
+/// Default time a paginated query result is kept for cursor requests
+pub const DEFAULT_TORII_QUERY_CURSOR_TTL_MS: u64 = 300_000;
+/// Default maximum number of paginated query results kept for cursor requests of one account
+pub const DEFAULT_TORII_MAX_QUERY_CURSORS_PER_ACCOUNT: u32 = 16;
+/// Default maximum total size of paginated query results kept for cursor requests
+pub const DEFAULT_TORII_MAX_QUERY_CURSORS_SIZE: u64 = 2_u64.pow(26);
+/// Default directory where events are kept until they are delivered to webhooks
+pub const DEFAULT_TORII_WEBHOOK_OUTBOX_PATH: &str = "./storage/webhook_outbox";
+/// Default delay before the first retry of a failed webhook delivery
+pub const DEFAULT_TORII_WEBHOOK_RETRY_DELAY_MS: u64 = 1000;
+/// Default upper bound on the delay between retries of a failed webhook delivery
+pub const DEFAULT_TORII_WEBHOOK_MAX_RETRY_DELAY_MS: u64 = 300_000;
+/// Default number of attempts to deliver an event to a webhook
+pub const DEFAULT_TORII_WEBHOOK_MAX_ATTEMPTS: u32 = 20;
+/// Default time after which a webhook delivery is considered failed
+pub const DEFAULT_TORII_WEBHOOK_REQUEST_TIMEOUT_MS: u64 = 10_000;
+/// Default maximum number of events kept in the outbox of a webhook
+pub const DEFAULT_TORII_WEBHOOK_MAX_OUTBOX_SIZE: u32 = 10_000;
 
 /// Structure that defines the configuration parameters of `Torii` which is the routing module.
 /// For example the `p2p_addr`, which is used for consensus and block-synchronisation purposes,
@@ -30,6 +49,43 @@ pub struct Configuration {
     pub max_transaction_size: u32,
     /// Maximum number of bytes in raw message. Used to prevent from DOS attacks.
     pub max_content_len: u32,
+    /// Time (in milliseconds) for which the rest of a paginated query result is kept
+    /// on the peer, so that following pages can be requested with a cursor.
+    pub query_cursor_ttl_ms: u64,
+    /// Maximum number of paginated query results kept for cursor requests of one account.
+    /// The oldest results are dropped first.
+    pub max_query_cursors_per_account: u32,
+    /// Maximum total size (in bytes) of paginated query results kept for cursor requests of one account.
+    /// The oldest results are dropped first.
+    pub max_query_cursors_size: u64,
+    /// HTTP endpoints to which the matching events are delivered via `POST`.
+    /// Delivery is disabled if there are none.
+    pub webhooks: Vec<Webhook>,
+    /// Path to the directory where events are kept until they are delivered to webhooks.
+    pub webhook_outbox_path: String,
+    /// Time (in milliseconds) before the first retry of a failed webhook delivery.
+    /// Doubled with every following retry.
+    pub webhook_retry_delay_ms: u64,
+    /// Upper bound (in milliseconds) on the time between retries of a failed webhook delivery.
+    pub webhook_max_retry_delay_ms: u64,
+    /// Number of attempts to deliver an event to a webhook, after which the event is dropped.
+    pub webhook_max_attempts: u32,
+    /// Time (in milliseconds) after which a webhook delivery without a response is considered failed.
+    pub webhook_request_timeout_ms: u64,
+    /// Maximum number of events kept in the outbox of a webhook.
+    /// New events are dropped while it is full.
+    pub webhook_max_outbox_size: u32,
+}
+
+/// HTTP endpoint to which the events matching its `filters` are delivered.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Webhook {
+    /// URL to which the events are sent via `POST`. Only `http` URLs are supported.
+    pub url: String,
+    /// Filters of the delivered events. An event is delivered if it matches any of them.
+    pub filters: Vec<FilterBox>,
+    /// Secret with which the HMAC-SHA256 signatures of the payloads are computed.
+    pub secret: String,
 }
 
 impl Default for Configuration {
@@ -40,6 +96,16 @@ impl Default for Configuration {
             telemetry_url: DEFAULT_TORII_TELEMETRY_URL.to_owned(),
             max_transaction_size: DEFAULT_TORII_MAX_TRANSACTION_SIZE,
             max_content_len: DEFAULT_TORII_MAX_CONTENT_LENGTH,
+            query_cursor_ttl_ms: DEFAULT_TORII_QUERY_CURSOR_TTL_MS,
+            max_query_cursors_per_account: DEFAULT_TORII_MAX_QUERY_CURSORS_PER_ACCOUNT,
+            max_query_cursors_size: DEFAULT_TORII_MAX_QUERY_CURSORS_SIZE,
+            webhooks: Vec::new(),
+            webhook_outbox_path: DEFAULT_TORII_WEBHOOK_OUTBOX_PATH.to_owned(),
+            webhook_retry_delay_ms: DEFAULT_TORII_WEBHOOK_RETRY_DELAY_MS,
+            webhook_max_retry_delay_ms: DEFAULT_TORII_WEBHOOK_MAX_RETRY_DELAY_MS,
+            webhook_max_attempts: DEFAULT_TORII_WEBHOOK_MAX_ATTEMPTS,
+            webhook_request_timeout_ms: DEFAULT_TORII_WEBHOOK_REQUEST_TIMEOUT_MS,
+            webhook_max_outbox_size: DEFAULT_TORII_WEBHOOK_MAX_OUTBOX_SIZE,
         }
     }
 }
@@ -51,8 +117,12 @@ pub mod uri {
     pub const DEFAULT_API_URL: &str = "127.0.0.1:8080";
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "query";
+    /// The web socket uri used to subscribe to outputs of a query.
+    pub const QUERY_SUBSCRIPTION: &str = "query/subscription";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "transaction";
+    /// Simulate transaction URI is used to dry-run transactions without submitting them.
+    pub const SIMULATE_TRANSACTION: &str = "simulate_transaction";
     /// Block URI is used to handle incoming Block requests.
     pub const CONSENSUS: &str = "consensus";
     /// Health URI is used to handle incoming Healthcheck requests.
@@ -63,6 +133,10 @@ pub mod uri {
     pub const SUBSCRIPTION: &str = "events";
     /// The web socket uri used to subscribe to blocks stream.
     pub const BLOCKS_STREAM: &str = "block/stream";
+    /// The Server-Sent Events uri used to subscribe to block and transactions statuses.
+    pub const SUBSCRIPTION_SSE: &str = "events/sse";
+    /// The Server-Sent Events uri used to subscribe to blocks stream.
+    pub const BLOCKS_STREAM_SSE: &str = "block/stream/sse";
     /// Get pending transactions.
     pub const PENDING_TRANSACTIONS: &str = "pending_transactions";
     /// The URI for local config changing inspecting
@@ -2,18 +2,17 @@
 #![allow(clippy::std_instead_of_core)]
 
 use iroha_config_base::derive::{Documented, LoadFromEnv, Proxy};
-use iroha_data_model::{metadata::Limits as MetadataLimits, LengthLimits};
+use iroha_data_model::{metadata::Limits as MetadataLimits, name::Name, LengthLimits};
 use serde::{Deserialize, Serialize};
 
 use crate::wasm;
 
 const DEFAULT_METADATA_LIMITS: MetadataLimits = MetadataLimits::new(2_u32.pow(20), 2_u32.pow(12));
 const DEFAULT_IDENT_LENGTH_LIMITS: LengthLimits = LengthLimits::new(1, 2_u32.pow(7));
+const DEFAULT_DATA_EVENTS_RETENTION_BLOCKS: u64 = 1024;
 
 /// `WorldStateView` configuration.
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Proxy, LoadFromEnv, Documented,
-)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Proxy, LoadFromEnv, Documented)]
 #[config(env_prefix = "WSV_")]
 #[serde(rename_all = "UPPERCASE", default)]
 pub struct Configuration {
@@ -29,6 +28,23 @@ pub struct Configuration {
     pub ident_length_limits: LengthLimits,
     /// WASM runtime configuration
     pub wasm_runtime_config: wasm::Configuration,
+    /// Metadata keys to keep lookup indexes for.
+    pub metadata_indexes: MetadataIndexes,
+    /// Number of the latest committed blocks whose data events are kept for
+    /// subscriptions started from a block height.
+    pub data_events_retention_blocks: u64,
+}
+
+/// Metadata keys under which values are indexed, so that entities
+/// can be looked up by them without scanning the whole world.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MetadataIndexes {
+    /// Keys of account metadata.
+    pub account: Vec<Name>,
+    /// Keys of asset definition metadata.
+    pub asset_definition: Vec<Name>,
+    /// Keys of the metadata stored in assets.
+    pub asset: Vec<Name>,
 }
 
 impl Default for Configuration {
@@ -40,6 +56,8 @@ impl Default for Configuration {
             domain_metadata_limits: DEFAULT_METADATA_LIMITS,
             ident_length_limits: DEFAULT_IDENT_LENGTH_LIMITS,
             wasm_runtime_config: wasm::Configuration::default(),
+            metadata_indexes: MetadataIndexes::default(),
+            data_events_retention_blocks: DEFAULT_DATA_EVENTS_RETENTION_BLOCKS,
         }
     }
 }
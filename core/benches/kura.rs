@@ -45,6 +45,7 @@ async fn measure_block_size_for_n_validators(n_validators: u32) {
         .chain_first()
         .validate(&TransactionValidator::new(
             transaction_limits,
+            ChainId::default(),
             Arc::new(AllowAll::new()),
             Arc::new(AllowAll::new()),
             Arc::new(WorldStateView::new(World::new())),
@@ -123,6 +123,7 @@ fn validate_transaction(criterion: &mut Criterion) {
     let _ = criterion.bench_function("validate", move |b| {
         let transaction_validator = TransactionValidator::new(
             TRANSACTION_LIMITS,
+            ChainId::default(),
             Arc::new(AllowAll::new()),
             Arc::new(AllowAll::new()),
             Arc::new(build_test_wsv(keys.clone())),
@@ -172,6 +173,7 @@ fn sign_blocks(criterion: &mut Criterion) {
     .expect("Failed to accept transaction.");
     let transaction_validator = TransactionValidator::new(
         TRANSACTION_LIMITS,
+        ChainId::default(),
         Arc::new(AllowAll::new()),
         Arc::new(AllowAll::new()),
         Arc::new(build_test_wsv(keys)),
@@ -215,6 +217,7 @@ fn validate_blocks(criterion: &mut Criterion) {
     let block = PendingBlock::new(vec![transaction.into()], Vec::new()).chain_first();
     let transaction_validator = TransactionValidator::new(
         TRANSACTION_LIMITS,
+        ChainId::default(),
         Arc::new(AllowAll::new()),
         Arc::new(AllowAll::new()),
         Arc::new(WorldStateView::new(World::with([domain], BTreeSet::new()))),
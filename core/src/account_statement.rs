@@ -0,0 +1,220 @@
+//! History of the changes of account asset quantities, which backs
+//! the `FindAccountStatement` query.
+#![allow(clippy::std_instead_of_core)]
+
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use iroha_crypto::Hash;
+use iroha_data_model::prelude::*;
+use iroha_primitives::fixed::Fixed;
+
+use crate::wsv::World;
+
+/// Decrease of a quantity which the following increase may be the
+/// other side of, as transfers decrease the source asset first.
+#[derive(Debug, Clone)]
+struct PendingTransfer {
+    account_id: AccountId,
+    position: usize,
+    definition_id: AssetDefinitionId,
+    amount: Value,
+    transaction_hash: Option<Hash>,
+}
+
+/// Statements of all accounts, built from the world events.
+#[derive(Debug, Clone, Default)]
+pub struct AccountStatements {
+    /// Last known quantities of the numeric assets.
+    balances: BTreeMap<AssetId, Value>,
+    entries: BTreeMap<AccountId, Vec<AccountStatementEntry>>,
+    pending_transfer: Option<PendingTransfer>,
+}
+
+impl AccountStatements {
+    /// Construct [`Self`] with the current quantities of the assets in
+    /// `world` and no history.
+    pub fn new(world: &World) -> Self {
+        let mut statements = Self::default();
+        for domain in world.domains.iter() {
+            for account in domain.accounts() {
+                for asset in account.assets() {
+                    if let Some(balance) = quantity(asset.value()) {
+                        statements.balances.insert(asset.id().clone(), balance);
+                    }
+                }
+            }
+        }
+        statements
+    }
+
+    /// Entries of the statement of the account with `id` in chronological order.
+    pub fn entries(&self, id: &AccountId) -> Vec<AccountStatementEntry> {
+        self.entries
+            .get(id)
+            .map(|entries| entries.to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Record the quantity change caused by `event` which happened in
+    /// `world` in the block at `block_height`.
+    pub fn handle_world_event(
+        &mut self,
+        event: &WorldEvent,
+        world: &World,
+        block_height: u64,
+        transaction_hash: Option<Hash>,
+    ) {
+        let pending_transfer = self.pending_transfer.take();
+        let event = match event {
+            WorldEvent::Domain(event) => event,
+            _ => return,
+        };
+        match event {
+            DomainEvent::Account(AccountEvent::Asset(event)) => {
+                let id = event.origin_id();
+                let balance = world
+                    .domains
+                    .get(&id.account_id.domain_id)
+                    .and_then(|domain| domain.account(&id.account_id)?.asset(id).cloned())
+                    .and_then(|asset| quantity(asset.value()));
+                self.record(
+                    id,
+                    balance,
+                    block_height,
+                    transaction_hash,
+                    pending_transfer,
+                );
+            }
+            DomainEvent::Account(event) => {
+                let id = event.origin_id();
+                let exists = world
+                    .domains
+                    .get(&id.domain_id)
+                    .map_or(false, |domain| domain.account(id).is_some());
+                if !exists {
+                    self.balances
+                        .retain(|asset_id, _| &asset_id.account_id != id);
+                    self.entries.remove(id);
+                }
+            }
+            DomainEvent::AssetDefinition(AssetDefinitionEvent::Deleted(id)) => {
+                self.balances
+                    .retain(|asset_id, _| &asset_id.definition_id != id);
+            }
+            DomainEvent::Deleted(domain_id) => {
+                self.balances
+                    .retain(|asset_id, _| &asset_id.account_id.domain_id != domain_id);
+                self.entries.retain(|id, _| &id.domain_id != domain_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn record(
+        &mut self,
+        id: &AssetId,
+        balance: Option<Value>,
+        block_height: u64,
+        transaction_hash: Option<Hash>,
+        pending_transfer: Option<PendingTransfer>,
+    ) {
+        let previous = match &balance {
+            Some(balance) => self.balances.insert(id.clone(), balance.clone()),
+            None => self.balances.remove(id),
+        };
+        let (previous, balance) = match (previous, balance) {
+            (None, None) => return,
+            (Some(previous), None) => {
+                let balance = zero(&previous);
+                (previous, balance)
+            }
+            (None, Some(balance)) => (zero(&balance), balance),
+            (Some(previous), Some(balance)) => (previous, balance),
+        };
+        let delta = match delta(&previous, &balance) {
+            Some(delta) => delta,
+            None => return,
+        };
+
+        let counterparty = match (&delta, pending_transfer) {
+            (AssetDelta::Increase(amount), Some(pending))
+                if pending.definition_id == id.definition_id
+                    && &pending.amount == amount
+                    && pending.transaction_hash == transaction_hash =>
+            {
+                if let Some(entry) = self
+                    .entries
+                    .get_mut(&pending.account_id)
+                    .and_then(|entries| entries.get_mut(pending.position))
+                {
+                    entry.counterparty = Some(id.account_id.clone());
+                }
+                Some(pending.account_id)
+            }
+            _ => None,
+        };
+
+        let entries = self.entries.entry(id.account_id.clone()).or_default();
+        if let AssetDelta::Decrease(amount) = &delta {
+            self.pending_transfer = Some(PendingTransfer {
+                account_id: id.account_id.clone(),
+                position: entries.len(),
+                definition_id: id.definition_id.clone(),
+                amount: amount.clone(),
+                transaction_hash,
+            });
+        }
+        entries.push(AccountStatementEntry {
+            block_height,
+            transaction_hash,
+            asset_id: id.clone(),
+            counterparty,
+            delta,
+            balance,
+        });
+    }
+}
+
+/// Quantity of a numeric asset, as [`Value::U128`] for integer assets
+/// and as [`Value::Fixed`] for fixed-point ones.
+fn quantity(value: &AssetValue) -> Option<Value> {
+    match value {
+        AssetValue::Quantity(quantity) => Some(Value::U128((*quantity).into())),
+        AssetValue::BigQuantity(quantity) => Some(Value::U128(*quantity)),
+        AssetValue::Fixed(quantity) => Some(Value::Fixed(*quantity)),
+        AssetValue::Store(_) => None,
+    }
+}
+
+/// Zero quantity of the same kind as `quantity`.
+fn zero(quantity: &Value) -> Value {
+    match quantity {
+        Value::Fixed(_) => Value::Fixed(Fixed::ZERO),
+        _ => Value::U128(0),
+    }
+}
+
+/// Change from `previous` to `current` quantity, `None` if it didn't change.
+fn delta(previous: &Value, current: &Value) -> Option<AssetDelta> {
+    match (previous, current) {
+        (Value::U128(previous), Value::U128(current)) => match current.cmp(previous) {
+            Ordering::Greater => Some(AssetDelta::Increase(Value::U128(
+                current.checked_sub(*previous)?,
+            ))),
+            Ordering::Less => Some(AssetDelta::Decrease(Value::U128(
+                previous.checked_sub(*current)?,
+            ))),
+            Ordering::Equal => None,
+        },
+        (Value::Fixed(previous), Value::Fixed(current)) => match current.cmp(previous) {
+            Ordering::Greater => Some(AssetDelta::Increase(Value::Fixed(
+                current.checked_sub(*previous).ok()?,
+            ))),
+            Ordering::Less => Some(AssetDelta::Decrease(Value::Fixed(
+                previous.checked_sub(*current).ok()?,
+            ))),
+            Ordering::Equal => None,
+        },
+        _ => None,
+    }
+}
@@ -17,9 +17,9 @@ use eyre::{eyre, Context, Result};
 use iroha_config::sumeragi::{
     DEFAULT_BLOCK_TIME_MS, DEFAULT_COMMIT_TIME_LIMIT_MS, DEFAULT_TX_RECEIPT_TIME_LIMIT_MS,
 };
-use iroha_crypto::{HashOf, KeyPair, MerkleTree, SignatureOf, SignaturesOf};
+use iroha_crypto::{HashOf, KeyPair, MerkleTree, Signature, SignatureOf, SignaturesOf};
 use iroha_data_model::{
-    block_value::{BlockHeaderValue, BlockValue},
+    block_value::{BlockHeaderValue, BlockValue, EncodedBlockHeader},
     current_time,
     events::prelude::*,
     transaction::prelude::*,
@@ -318,6 +318,29 @@ impl BlockHeader {
     pub const fn is_genesis(&self) -> bool {
         self.height == 1
     }
+
+    /// Header in the form which lets clients check that it hashes into
+    /// the block hash, see [`EncodedBlockHeader`].
+    pub fn encoded(&self) -> EncodedBlockHeader {
+        let prefix_len = (
+            self.timestamp,
+            self.consensus_estimation,
+            self.height,
+            self.previous_block_hash,
+            self.transactions_hash,
+            self.rejected_transactions_hash,
+        )
+            .encoded_size();
+        EncodedBlockHeader {
+            timestamp: self.timestamp,
+            consensus_estimation: self.consensus_estimation,
+            height: self.height,
+            previous_block_hash: self.previous_block_hash.into(),
+            transactions_hash: self.transactions_hash,
+            rejected_transactions_hash: self.rejected_transactions_hash,
+            rest: self.encode().split_off(prefix_len),
+        }
+    }
 }
 
 impl ChainedBlock {
@@ -325,9 +348,15 @@ impl ChainedBlock {
     pub fn validate(self, transaction_validator: &TransactionValidator) -> VersionedValidBlock {
         let mut txs = Vec::new();
         let mut rejected = Vec::new();
+        let mut block_state =
+            transaction_validator.new_block_state(self.header.height, self.header.timestamp);
 
         for tx in self.transactions {
-            match transaction_validator.validate(tx.into_v1(), self.header.is_genesis()) {
+            match transaction_validator.validate_in_block(
+                tx.into_v1(),
+                self.header.is_genesis(),
+                &mut block_state,
+            ) {
                 Ok(tx) => txs.push(tx),
                 Err(tx) => {
                     iroha_logger::warn!(
@@ -787,6 +816,36 @@ impl CommittedBlock {
     pub fn verified_signatures(&self) -> impl Iterator<Item = &SignatureOf<Self>> {
         self.signatures.verified_by_hash(self.hash())
     }
+
+    /// Proof of inclusion of the transaction with `hash` in this block,
+    /// if the transaction was committed in it.
+    pub fn transaction_proof(
+        &self,
+        hash: &HashOf<VersionedTransaction>,
+    ) -> Option<TransactionProof> {
+        let idx = self
+            .transactions
+            .iter()
+            .position(|transaction| transaction.hash() == *hash)?;
+        let merkle_proof = self
+            .transactions
+            .iter()
+            .map(VersionedValidTransaction::hash)
+            .collect::<MerkleTree<_>>()
+            .get_proof(idx)?;
+
+        Some(TransactionProof {
+            transaction: self.transactions.get(idx)?.clone(),
+            merkle_proof,
+            block_header: self.header.encoded(),
+            signatures: self
+                .signatures
+                .iter()
+                .cloned()
+                .map(Signature::from)
+                .collect(),
+        })
+    }
 }
 
 impl From<CommittedBlock> for ValidBlock {
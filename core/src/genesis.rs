@@ -199,7 +199,13 @@ impl GenesisNetworkTrait for GenesisNetwork {
                             .ok_or_else(|| eyre!("Genesis account private key is empty."))?,
                     )?;
 
-                    raw_transaction.sign_and_accept(genesis_key_pair, tx_limits)
+                    let chain_id = genesis_config
+                        .as_ref()
+                        .expect("Should be `Some` when `submit_genesis` is true")
+                        .chain_id
+                        .clone();
+
+                    raw_transaction.sign_and_accept(chain_id, genesis_key_pair, tx_limits)
                 })
                 .enumerate()
                 .filter_map(|(i, res)| {
@@ -300,6 +306,7 @@ impl GenesisTransaction {
     /// Fails if signing or accepting fails
     pub fn sign_and_accept(
         &self,
+        chain_id: ChainId,
         genesis_key_pair: KeyPair,
         limits: &TransactionLimits,
     ) -> Result<VersionedAcceptedTransaction> {
@@ -308,6 +315,7 @@ impl GenesisTransaction {
             self.isi.clone().into(),
             GENESIS_TRANSACTIONS_TTL_MS,
         )
+        .with_chain_id(chain_id)
         .sign(genesis_key_pair)?;
         VersionedAcceptedTransaction::from_transaction(transaction, limits)
     }
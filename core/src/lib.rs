@@ -1,9 +1,11 @@
 //! Iroha - A simple, enterprise-grade decentralized ledger.
 
+pub mod account_statement;
 pub mod block;
 pub mod block_sync;
 pub mod genesis;
 pub mod kura;
+pub mod metadata_index;
 pub mod modules;
 pub mod queue;
 pub mod smartcontracts;
@@ -0,0 +1,246 @@
+//! Secondary indexes from metadata values to the entities holding
+//! them, which back the `Find*ByMetadataKeyValue` queries.
+#![allow(clippy::std_instead_of_core)]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use iroha_config::wsv::MetadataIndexes;
+use iroha_data_model::prelude::*;
+
+use crate::wsv::World;
+
+/// Index from values under the configured metadata keys to the ids
+/// of the entities holding them.
+#[derive(Debug, Clone)]
+pub struct KeyValueIndex<Id> {
+    keys: BTreeSet<Name>,
+    ids: BTreeMap<(Name, Value), BTreeSet<Id>>,
+    entries: BTreeMap<Id, Vec<(Name, Value)>>,
+}
+
+impl<Id> Default for KeyValueIndex<Id> {
+    fn default() -> Self {
+        Self {
+            keys: BTreeSet::new(),
+            ids: BTreeMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Id: Clone + Ord> KeyValueIndex<Id> {
+    /// Construct an empty [`Self`] indexing values under `keys`.
+    pub fn new(keys: impl IntoIterator<Item = Name>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Ids of the entities with `value` under `key`.
+    ///
+    /// Returns `None` if `key` isn't indexed.
+    pub fn get(&self, key: &Name, value: &Value) -> Option<Vec<Id>> {
+        if !self.keys.contains(key) {
+            return None;
+        }
+        Some(
+            self.ids
+                .get(&(key.clone(), value.clone()))
+                .map(|ids| ids.iter().cloned().collect())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Replace the indexed values of the entity with `id` by the
+    /// ones in its current `metadata`, or drop them if the entity is gone.
+    pub fn update(&mut self, id: &Id, metadata: Option<&Metadata>) {
+        if self.keys.is_empty() {
+            return;
+        }
+        self.remove(id);
+
+        let entries: Vec<_> = metadata
+            .into_iter()
+            .flat_map(|metadata| {
+                self.keys
+                    .iter()
+                    .filter_map(|key| metadata.get(key).map(|value| (key.clone(), value.clone())))
+            })
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+        for entry in &entries {
+            self.ids
+                .entry(entry.clone())
+                .or_default()
+                .insert(id.clone());
+        }
+        self.entries.insert(id.clone(), entries);
+    }
+
+    /// Drop the indexed values of the entities whose ids don't satisfy `keep`.
+    pub fn retain(&mut self, keep: impl Fn(&Id) -> bool) {
+        let removed: Vec<_> = self
+            .entries
+            .keys()
+            .filter(|id| !keep(id))
+            .cloned()
+            .collect();
+        for id in removed {
+            self.remove(&id);
+        }
+    }
+
+    fn remove(&mut self, id: &Id) {
+        for entry in self.entries.remove(id).into_iter().flatten() {
+            if let Some(ids) = self.ids.get_mut(&entry) {
+                ids.remove(id);
+                if ids.is_empty() {
+                    self.ids.remove(&entry);
+                }
+            }
+        }
+    }
+}
+
+/// Metadata indexes of accounts, asset definitions and assets.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataIndex {
+    /// Index of account metadata.
+    pub accounts: KeyValueIndex<AccountId>,
+    /// Index of asset definition metadata.
+    pub asset_definitions: KeyValueIndex<AssetDefinitionId>,
+    /// Index of the metadata stored in assets.
+    pub assets: KeyValueIndex<AssetId>,
+}
+
+impl MetadataIndex {
+    /// Construct [`Self`] indexing the keys in `config` of all entities in `world`.
+    pub fn new(config: &MetadataIndexes, world: &World) -> Self {
+        let mut index = Self {
+            accounts: KeyValueIndex::new(config.account.iter().cloned()),
+            asset_definitions: KeyValueIndex::new(config.asset_definition.iter().cloned()),
+            assets: KeyValueIndex::new(config.asset.iter().cloned()),
+        };
+        for domain in world.domains.iter() {
+            for entry in domain.asset_definitions() {
+                let definition = entry.definition();
+                index
+                    .asset_definitions
+                    .update(definition.id(), Some(definition.metadata()));
+            }
+            for account in domain.accounts() {
+                index
+                    .accounts
+                    .update(account.id(), Some(account.metadata()));
+                for asset in account.assets() {
+                    index.assets.update(asset.id(), store(asset));
+                }
+            }
+        }
+        index
+    }
+
+    /// Bring the indexes up to date with `world` after `event` happened in it.
+    pub fn handle_world_event(&mut self, event: &WorldEvent, world: &World) {
+        let event = match event {
+            WorldEvent::Domain(event) => event,
+            _ => return,
+        };
+        match event {
+            DomainEvent::Account(AccountEvent::Asset(event)) => {
+                let id = event.origin_id();
+                let asset = world
+                    .domains
+                    .get(&id.account_id.domain_id)
+                    .and_then(|domain| domain.account(&id.account_id)?.asset(id).cloned());
+                self.assets.update(id, asset.as_ref().and_then(store));
+            }
+            DomainEvent::Account(event) => {
+                let id = event.origin_id();
+                let metadata = world.domains.get(&id.domain_id).and_then(|domain| {
+                    domain.account(id).map(|account| account.metadata().clone())
+                });
+                if metadata.is_none() {
+                    self.assets.retain(|asset_id| &asset_id.account_id != id);
+                }
+                self.accounts.update(id, metadata.as_ref());
+            }
+            DomainEvent::AssetDefinition(event) => {
+                let id = event.origin_id();
+                let metadata = world.domains.get(&id.domain_id).and_then(|domain| {
+                    domain
+                        .asset_definition(id)
+                        .map(|entry| entry.definition().metadata().clone())
+                });
+                if metadata.is_none() {
+                    self.assets.retain(|asset_id| &asset_id.definition_id != id);
+                }
+                self.asset_definitions.update(id, metadata.as_ref());
+            }
+            DomainEvent::Deleted(domain_id) => {
+                self.accounts.retain(|id| &id.domain_id != domain_id);
+                self.asset_definitions
+                    .retain(|id| &id.domain_id != domain_id);
+                self.assets
+                    .retain(|id| &id.account_id.domain_id != domain_id);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Metadata stored in `asset`, if it's a store asset.
+fn store(asset: &Asset) -> Option<&Metadata> {
+    if let AssetValue::Store(metadata) = asset.value() {
+        Some(metadata)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::restriction)]
+
+    use std::str::FromStr as _;
+
+    use super::*;
+
+    fn metadata(key: &Name, value: u32) -> Metadata {
+        let mut metadata = Metadata::new();
+        metadata
+            .insert_with_limits(key.clone(), value.into(), MetadataLimits::new(10, 100))
+            .expect("Valid");
+        metadata
+    }
+
+    #[test]
+    fn index_follows_updates_and_removals() {
+        let key = Name::from_str("customer").expect("Valid");
+        let alice = AccountId::from_str("alice@wonderland").expect("Valid");
+        let bob = AccountId::from_str("bob@wonderland").expect("Valid");
+        let mut index = KeyValueIndex::new([key.clone()]);
+
+        index.update(&alice, Some(&metadata(&key, 1)));
+        index.update(&bob, Some(&metadata(&key, 1)));
+        assert_eq!(
+            index.get(&key, &1_u32.into()),
+            Some(vec![alice.clone(), bob.clone()])
+        );
+
+        index.update(&alice, Some(&metadata(&key, 2)));
+        assert_eq!(index.get(&key, &1_u32.into()), Some(vec![bob.clone()]));
+        assert_eq!(index.get(&key, &2_u32.into()), Some(vec![alice.clone()]));
+
+        index.update(&bob, None);
+        index.retain(|id| id != &alice);
+        assert_eq!(index.get(&key, &1_u32.into()), Some(vec![]));
+        assert_eq!(index.get(&key, &2_u32.into()), Some(vec![]));
+
+        let unindexed = Name::from_str("isin").expect("Valid");
+        assert_eq!(index.get(&unindexed, &1_u32.into()), None);
+    }
+}
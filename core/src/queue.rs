@@ -7,14 +7,14 @@
     clippy::expect_used
 )]
 
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use crossbeam_queue::ArrayQueue;
 use dashmap::{mapref::entry::Entry, DashMap};
 use eyre::{eyre, Report, Result};
 use iroha_config::queue::Configuration;
 use iroha_crypto::HashOf;
-use iroha_data_model::transaction::prelude::*;
+use iroha_data_model::{transaction::prelude::*, ChainId};
 use rand::seq::IteratorRandom;
 use thiserror::Error;
 
@@ -35,6 +35,7 @@ pub struct Queue {
     max_txs: usize,
     ttl: Duration,
     future_threshold: Duration,
+    chain_id: ChainId,
 
     wsv: Arc<WorldStateView>,
 }
@@ -45,6 +46,9 @@ pub enum Error {
     /// Queue is full
     #[error("Queue is full")]
     Full,
+    /// Transaction is meant for another chain
+    #[error("Transaction is meant for another chain")]
+    ChainIdMismatch,
     /// Transaction is regarded to have been tampered to have a future timestamp
     #[error("Transaction is regarded to have been tampered to have a future timestamp")]
     InFuture,
@@ -54,6 +58,15 @@ pub enum Error {
     /// Transaction is already in blockchain
     #[error("Transaction is already applied")]
     InBlockchain,
+    /// Sequence number was already used by another transaction of the account
+    #[error("Transaction sequence number was already used")]
+    SequenceNumberReplay,
+    /// There is no committed or queued transaction of the account with the previous sequence number
+    #[error("Transaction sequence number doesn't follow the last one of the account")]
+    SequenceNumberGap,
+    /// Sequence number is `u32::MAX`, which is reserved as there would be no next one
+    #[error("Transaction sequence number is the maximum one, which is reserved")]
+    SequenceNumberExhausted,
     /// Signature condition check failed
     #[error("Failure during signature condition execution, tx hash: {tx_hash}, reason: {reason}")]
     SignatureCondition {
@@ -66,7 +79,11 @@ pub enum Error {
 
 impl Queue {
     /// Makes queue from configuration
-    pub fn from_configuration(cfg: &Configuration, wsv: Arc<WorldStateView>) -> Self {
+    pub fn from_configuration(
+        cfg: &Configuration,
+        chain_id: ChainId,
+        wsv: Arc<WorldStateView>,
+    ) -> Self {
         Self {
             queue: ArrayQueue::new(cfg.maximum_transactions_in_queue as usize),
             txs: DashMap::new(),
@@ -74,6 +91,7 @@ impl Queue {
             txs_in_block: cfg.maximum_transactions_in_block as usize,
             ttl: Duration::from_millis(cfg.transaction_time_to_live_ms),
             future_threshold: Duration::from_millis(cfg.future_threshold_ms),
+            chain_id,
             wsv,
         }
     }
@@ -104,12 +122,25 @@ impl Queue {
     }
 
     fn check_tx(&self, tx: &VersionedAcceptedTransaction) -> Result<(), Error> {
+        if tx.payload().chain_id != self.chain_id {
+            return Err(Error::ChainIdMismatch);
+        }
         if tx.is_expired(self.ttl) {
             return Err(Error::Expired);
         }
         if tx.is_in_blockchain(&self.wsv) {
             return Err(Error::InBlockchain);
         }
+        if tx.payload().sequence_number == Some(u32::MAX) {
+            return Err(Error::SequenceNumberExhausted);
+        }
+        if matches!(
+            tx.payload().sequence_number,
+            Some(sequence_number)
+                if sequence_number < self.wsv.next_sequence_number(&tx.payload().account_id)
+        ) {
+            return Err(Error::SequenceNumberReplay);
+        }
         tx.check_signature_condition(&self.wsv)
             .and_then(|success| {
                 success
@@ -123,6 +154,94 @@ impl Queue {
             })
     }
 
+    /// Checks that `tx` directly follows the last committed or queued transaction of the same account.
+    fn check_sequence_number(
+        &self,
+        tx: &VersionedAcceptedTransaction,
+        hash: &HashOf<VersionedTransaction>,
+    ) -> Result<(), Error> {
+        let sequence_number = match tx.payload().sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => return Ok(()),
+        };
+        let account_id = &tx.payload().account_id;
+
+        let mut follows_previous = sequence_number == self.wsv.next_sequence_number(account_id);
+        for queued in self.txs.iter() {
+            // MST case: the same transaction with more signatures
+            if queued.key() == hash || &queued.payload().account_id != account_id {
+                continue;
+            }
+            match queued.payload().sequence_number {
+                Some(queued_number) if queued_number == sequence_number => {
+                    return Err(Error::SequenceNumberReplay)
+                }
+                Some(queued_number) if queued_number.checked_add(1) == Some(sequence_number) => {
+                    follows_previous = true;
+                }
+                _ => {}
+            }
+        }
+
+        follows_previous
+            .then_some(())
+            .ok_or(Error::SequenceNumberGap)
+    }
+
+    /// Takes sequence number of `tx` if it's the next one expected for its account.
+    ///
+    /// Transactions without sequence number are always accepted.
+    fn take_sequence_number(
+        &self,
+        tx: &VersionedAcceptedTransaction,
+        next_sequence_numbers: &mut HashMap<AccountId, u32>,
+    ) -> bool {
+        let sequence_number = match tx.payload().sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => return true,
+        };
+        let account_id = &tx.payload().account_id;
+        let expected = next_sequence_numbers
+            .entry(account_id.clone())
+            .or_insert_with(|| self.wsv.next_sequence_number(account_id));
+
+        if *expected != sequence_number {
+            return false;
+        }
+        *expected = expected.saturating_add(1);
+        true
+    }
+
+    /// Evicts the deferred transactions which can't be taken, because the
+    /// sequence number expected next for their account is neither taken
+    /// nor queued, e.g. after its transaction expired or was rejected.
+    /// Otherwise they would stay in the queue until their TTL.
+    fn evict_after_gaps(
+        &self,
+        deferred: Vec<VersionedAcceptedTransaction>,
+        next_sequence_numbers: &HashMap<AccountId, u32>,
+    ) {
+        for tx in deferred {
+            let account_id = &tx.payload().account_id;
+            let expected = next_sequence_numbers
+                .get(account_id)
+                .copied()
+                .unwrap_or_else(|| self.wsv.next_sequence_number(account_id));
+            let expected_is_queued = self.txs.iter().any(|queued| {
+                &queued.payload().account_id == account_id
+                    && queued.payload().sequence_number == Some(expected)
+            });
+            if !expected_is_queued {
+                iroha_logger::debug!(
+                    tx_hash = %tx.hash(),
+                    expected,
+                    "Evicting transaction which follows a missing sequence number"
+                );
+                self.txs.remove(&tx.hash());
+            }
+        }
+    }
+
     /// Pushes transaction into queue.
     ///
     /// # Errors
@@ -147,6 +266,9 @@ impl Queue {
         }
 
         let hash = tx.hash();
+        if let Err(e) = self.check_sequence_number(&tx, &hash) {
+            return Err((tx, e));
+        }
         let entry = match self.txs.entry(hash) {
             Entry::Occupied(mut old_tx) => {
                 // MST case
@@ -215,16 +337,43 @@ impl Queue {
 
     /// Gets transactions till they fill whole block or till the end of queue.
     ///
+    /// Transactions of the same account with sequence numbers are ordered by them.
+    /// Ones which don't follow the previous sequence number are left in the queue,
+    /// unless the transaction with that number is missing from it, in which case
+    /// they're evicted.
+    ///
     /// BEWARE: Shouldn't be called in parallel with itself.
     #[allow(clippy::missing_panics_doc, clippy::unwrap_in_result)]
     pub fn get_transactions_for_block(&self) -> Vec<VersionedAcceptedTransaction> {
         let mut seen = Vec::new();
+        let mut next_sequence_numbers = HashMap::new();
+        let mut deferred = Vec::new();
+        let mut out = Vec::new();
+
+        while out.len() < self.txs_in_block {
+            let tx = match self.pop(&mut seen) {
+                Some(tx) => tx,
+                None => break,
+            };
+            if !self.take_sequence_number(&tx, &mut next_sequence_numbers) {
+                deferred.push(tx);
+                continue;
+            }
+            out.push(tx);
+
+            // Transactions popped earlier may follow the one just taken
+            while out.len() < self.txs_in_block {
+                match deferred
+                    .iter()
+                    .position(|tx| self.take_sequence_number(tx, &mut next_sequence_numbers))
+                {
+                    Some(position) => out.push(deferred.swap_remove(position)),
+                    None => break,
+                }
+            }
+        }
 
-        let out = std::iter::repeat_with(|| self.pop(&mut seen))
-            .take_while(Option::is_some)
-            .map(Option::unwrap)
-            .take(self.txs_in_block)
-            .collect::<Vec<_>>();
+        self.evict_after_gaps(deferred, &next_sequence_numbers);
 
         #[allow(clippy::expect_used)]
         seen.into_iter()
@@ -301,6 +450,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
 
@@ -309,6 +459,118 @@ mod tests {
             .expect("Failed to push tx into queue");
     }
 
+    #[test]
+    fn sequence_numbers_order_transactions() {
+        let key_pair = KeyPair::generate().unwrap();
+        let wsv = Arc::new(WorldStateView::new(world_with_test_domains([key_pair
+            .public_key()
+            .clone()])));
+
+        let queue = Queue::from_configuration(
+            &Configuration {
+                maximum_transactions_in_block: 10,
+                transaction_time_to_live_ms: 100_000,
+                maximum_transactions_in_queue: 100,
+                ..Configuration::default()
+            },
+            ChainId::default(),
+            wsv,
+        );
+        let sequenced_tx = |sequence_number| {
+            let instructions: Vec<Instruction> = vec![FailBox::new("fail").into()];
+            let tx = Transaction::new(
+                AccountId::from_str("alice@wonderland").expect("Valid"),
+                instructions.into(),
+                100_000,
+            )
+            .with_sequence_number(sequence_number)
+            .with_nonce(rand::random())
+            .sign(key_pair.clone())
+            .expect("Failed to sign.");
+            let limits = TransactionLimits {
+                max_instruction_number: 4096,
+                max_wasm_size_bytes: 0,
+            };
+            VersionedAcceptedTransaction::from_transaction(tx, &limits)
+                .expect("Failed to accept Transaction.")
+        };
+
+        assert!(matches!(
+            queue.push(sequenced_tx(1)),
+            Err((_, Error::SequenceNumberGap))
+        ));
+        queue
+            .push(sequenced_tx(0))
+            .expect("Failed to push tx into queue");
+        assert!(matches!(
+            queue.push(sequenced_tx(0)),
+            Err((_, Error::SequenceNumberReplay))
+        ));
+        queue
+            .push(sequenced_tx(1))
+            .expect("Failed to push tx into queue");
+
+        let sequence_numbers = queue
+            .get_transactions_for_block()
+            .iter()
+            .map(|tx| tx.payload().sequence_number)
+            .collect::<Vec<_>>();
+        assert_eq!(sequence_numbers, vec![Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn transactions_after_sequence_gap_are_evicted() {
+        let key_pair = KeyPair::generate().unwrap();
+        let wsv = Arc::new(WorldStateView::new(world_with_test_domains([key_pair
+            .public_key()
+            .clone()])));
+
+        let queue = Queue::from_configuration(
+            &Configuration {
+                maximum_transactions_in_block: 10,
+                transaction_time_to_live_ms: 100_000,
+                maximum_transactions_in_queue: 100,
+                ..Configuration::default()
+            },
+            ChainId::default(),
+            wsv,
+        );
+        let sequenced_tx = |sequence_number| {
+            let instructions: Vec<Instruction> = vec![FailBox::new("fail").into()];
+            let tx = Transaction::new(
+                AccountId::from_str("alice@wonderland").expect("Valid"),
+                instructions.into(),
+                100_000,
+            )
+            .with_sequence_number(sequence_number)
+            .with_nonce(rand::random())
+            .sign(key_pair.clone())
+            .expect("Failed to sign.");
+            let limits = TransactionLimits {
+                max_instruction_number: 4096,
+                max_wasm_size_bytes: 0,
+            };
+            VersionedAcceptedTransaction::from_transaction(tx, &limits)
+                .expect("Failed to accept Transaction.")
+        };
+
+        assert!(matches!(
+            queue.push(sequenced_tx(u32::MAX)),
+            Err((_, Error::SequenceNumberExhausted))
+        ));
+
+        let first = sequenced_tx(0);
+        let first_hash = first.hash();
+        for tx in [first, sequenced_tx(1), sequenced_tx(2)] {
+            queue.push(tx).expect("Failed to push tx into queue");
+        }
+        // E.g. the first transaction expired or was rejected
+        queue.txs.remove(&first_hash);
+
+        assert!(queue.get_transactions_for_block().is_empty());
+        assert_eq!(queue.tx_len(), 0);
+    }
+
     #[test]
     fn push_tx_overflow() {
         let max_txs_in_queue = 10;
@@ -325,6 +587,7 @@ mod tests {
                 maximum_transactions_in_queue: max_txs_in_queue,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
 
@@ -364,6 +627,7 @@ mod tests {
                 maximum_transactions_in_queue: max_txs_in_queue,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
 
@@ -390,6 +654,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
         let tx = Transaction::new(
@@ -438,6 +703,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
         for _ in 0..5 {
@@ -467,6 +733,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
         assert!(matches!(queue.push(tx), Err((_, Error::InBlockchain))));
@@ -488,6 +755,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             Arc::clone(&wsv),
         );
         queue.push(tx.clone()).unwrap();
@@ -510,6 +778,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
         for _ in 0..(max_block_tx - 1) {
@@ -547,6 +816,7 @@ mod tests {
                 maximum_transactions_in_queue: 100,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
         queue
@@ -582,6 +852,7 @@ mod tests {
                 maximum_transactions_in_queue: 100_000_000,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         ));
 
@@ -646,6 +917,7 @@ mod tests {
                 future_threshold_ms,
                 ..Configuration::default()
             },
+            ChainId::default(),
             wsv,
         );
 
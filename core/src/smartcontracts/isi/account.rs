@@ -402,6 +402,84 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAccountsByMetadataKeyValue {
+        #[metrics(+"find_accounts_by_metadata_key_value")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let key = self
+                .key
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get key")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let value = self
+                .value
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get value")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            iroha_logger::trace!(%key, %value);
+            if let Some(ids) = wsv.account_ids_by_metadata(&key, &value) {
+                return ids
+                    .iter()
+                    .map(|id| wsv.map_account(id, Clone::clone))
+                    .collect();
+            }
+            let mut vec = Vec::new();
+            for domain in wsv.domains().iter() {
+                for account in domain.accounts() {
+                    if account.metadata().get(&key) == Some(&value) {
+                        vec.push(account.clone())
+                    }
+                }
+            }
+            Ok(vec)
+        }
+    }
+
+    impl ValidQuery for FindAccountStatement {
+        #[metrics(+"find_account_statement")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let id = self
+                .account_id
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get account id")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let definition_id = self
+                .asset_definition_id
+                .as_ref()
+                .map(|definition_id| definition_id.evaluate(wsv, &Context::default()))
+                .transpose()
+                .wrap_err("Failed to get asset definition id")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let start = evaluate_height(self.start_height.as_ref(), wsv)?.unwrap_or(0);
+            let end = evaluate_height(self.end_height.as_ref(), wsv)?.unwrap_or(u64::MAX);
+            iroha_logger::trace!(%id);
+            wsv.map_account(&id, |_| ())?;
+            Ok(wsv
+                .account_statement(&id)
+                .into_iter()
+                .filter(|entry| {
+                    definition_id.as_ref().map_or(true, |definition_id| {
+                        &entry.asset_id.definition_id == definition_id
+                    }) && (start..=end).contains(&entry.block_height)
+                })
+                .collect())
+        }
+    }
+
+    fn evaluate_height(
+        height: Option<&EvaluatesTo<u128>>,
+        wsv: &WorldStateView,
+    ) -> Result<Option<u64>, Error> {
+        height
+            .map(|height| {
+                let height = height
+                    .evaluate(wsv, &Context::default())
+                    .wrap_err("Failed to get height")
+                    .map_err(|e| Error::Evaluate(e.to_string()))?;
+                u64::try_from(height).map_err(|e| Error::Conversion(e.to_string()))
+            })
+            .transpose()
+    }
+
     impl ValidQuery for FindAccountsByDomainId {
         #[metrics(+"find_accounts_by_domain_id")]
         fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
@@ -436,6 +514,20 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAccountNextSequenceNumber {
+        #[metrics(+"find_account_next_sequence_number")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let id = self
+                .id
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get account id")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            iroha_logger::trace!(%id);
+            wsv.map_account(&id, |_account| ())?;
+            Ok(wsv.next_sequence_number(&id))
+        }
+    }
+
     impl ValidQuery for FindAccountsWithAsset {
         #[metrics(+"find_accounts_with_asset")]
         fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
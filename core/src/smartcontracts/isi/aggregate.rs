@@ -0,0 +1,138 @@
+//! This module contains trait implementations related to aggregation queries
+use std::{collections::BTreeMap, mem};
+
+use iroha_telemetry::metrics;
+
+use super::*;
+
+impl ValidQuery for FindAggregate {
+    #[metrics(+"find_aggregate")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let entries = match self.filter.filter(self.query.execute(wsv)?) {
+            Value::Vec(entries) => entries,
+            entry => vec![entry],
+        };
+
+        let group_by = match self.group_by {
+            Some(group_by) => group_by,
+            None => return aggregate(self.function, &entries),
+        };
+        let mut groups = BTreeMap::<IdBox, Vec<Value>>::new();
+        for entry in entries {
+            let group = group_id(group_by, &entry).ok_or_else(|| {
+                query::Error::Conversion(format!("Entry `{entry}` can't be grouped by {group_by}"))
+            })?;
+            groups.entry(group).or_default().push(entry);
+        }
+        groups
+            .into_iter()
+            .map(|(group, entries)| {
+                aggregate(self.function, &entries)
+                    .map(|value| Value::Vec(vec![Value::Id(group), value]))
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Vec)
+    }
+}
+
+/// Id of the group `entry` belongs to, if it can be grouped by `group_by`.
+fn group_id(group_by: GroupBy, entry: &Value) -> Option<IdBox> {
+    let entity = match entry {
+        Value::Identifiable(entity) => entity,
+        _ => return None,
+    };
+    match (group_by, entity) {
+        (GroupBy::AssetDefinition, IdentifiableBox::Asset(asset)) => {
+            Some(IdBox::AssetDefinitionId(asset.id().definition_id.clone()))
+        }
+        (GroupBy::AssetDefinition, IdentifiableBox::AssetDefinition(definition)) => {
+            Some(IdBox::AssetDefinitionId(definition.id().clone()))
+        }
+        (GroupBy::Domain, IdentifiableBox::Asset(asset)) => {
+            Some(IdBox::DomainId(asset.id().account_id.domain_id.clone()))
+        }
+        (GroupBy::Domain, IdentifiableBox::Account(account)) => {
+            Some(IdBox::DomainId(account.id().domain_id.clone()))
+        }
+        (GroupBy::Domain, IdentifiableBox::AssetDefinition(definition)) => {
+            Some(IdBox::DomainId(definition.id().domain_id.clone()))
+        }
+        (GroupBy::Domain, IdentifiableBox::Domain(domain)) => {
+            Some(IdBox::DomainId(domain.id().clone()))
+        }
+        (GroupBy::Account, IdentifiableBox::Asset(asset)) => {
+            Some(IdBox::AccountId(asset.id().account_id.clone()))
+        }
+        (GroupBy::Account, IdentifiableBox::Account(account)) => {
+            Some(IdBox::AccountId(account.id().clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Reduce `entries` with `function`.
+///
+/// Quantities are summed as [`Value::U128`] for integer assets and as
+/// [`Value::Fixed`] for fixed-point ones, so all entries must be of the same kind.
+fn aggregate(function: AggregateFunction, entries: &[Value]) -> Result<Value, query::Error> {
+    match function {
+        AggregateFunction::Count => u128::try_from(entries.len())
+            .map(Value::U128)
+            .map_err(|e| query::Error::Conversion(e.to_string())),
+        AggregateFunction::Sum => quantities(entries)?
+            .into_iter()
+            .try_fold(None, |sum, quantity| match (sum, quantity) {
+                (None, quantity) => Ok(Some(quantity)),
+                (Some(Value::U128(sum)), Value::U128(quantity)) => sum
+                    .checked_add(quantity)
+                    .map(|sum| Some(Value::U128(sum)))
+                    .ok_or_else(|| query::Error::Conversion("Sum overflow".to_owned())),
+                (Some(Value::Fixed(sum)), Value::Fixed(quantity)) => sum
+                    .checked_add(quantity)
+                    .map(|sum| Some(Value::Fixed(sum)))
+                    .map_err(|e| query::Error::Conversion(e.to_string())),
+                (Some(_), _) => Err(mixed_quantities()),
+            })
+            .map(|sum| sum.unwrap_or(Value::U128(0))),
+        AggregateFunction::Min => quantities(entries)?
+            .into_iter()
+            .min()
+            .ok_or_else(|| no_entries(function)),
+        AggregateFunction::Max => quantities(entries)?
+            .into_iter()
+            .max()
+            .ok_or_else(|| no_entries(function)),
+    }
+}
+
+/// Quantities of `entries`, which must all be of the same kind.
+fn quantities(entries: &[Value]) -> Result<Vec<Value>, query::Error> {
+    let quantities = entries
+        .iter()
+        .map(|entry| {
+            SortField::Quantity
+                .extract(entry)
+                .ok_or_else(|| query::Error::Conversion(format!("Entry `{entry}` has no quantity")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match quantities.first() {
+        Some(first)
+            if quantities
+                .iter()
+                .any(|quantity| mem::discriminant(quantity) != mem::discriminant(first)) =>
+        {
+            Err(mixed_quantities())
+        }
+        _ => Ok(quantities),
+    }
+}
+
+fn mixed_quantities() -> query::Error {
+    query::Error::Conversion(
+        "Can't aggregate integer and fixed-point quantities together".to_owned(),
+    )
+}
+
+fn no_entries(function: AggregateFunction) -> query::Error {
+    query::Error::Conversion(format!("Can't find {function} of no entries"))
+}
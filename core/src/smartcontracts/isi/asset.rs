@@ -443,6 +443,76 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAssetsByMetadataKeyValue {
+        #[metrics(+"find_assets_by_metadata_key_value")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let key = self
+                .key
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get key")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let value = self
+                .value
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get value")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            iroha_logger::trace!(%key, %value);
+            if let Some(ids) = wsv.asset_ids_by_metadata(&key, &value) {
+                return ids.iter().map(|id| wsv.asset(id)).collect();
+            }
+            let mut vec = Vec::new();
+            for domain in wsv.domains().iter() {
+                for account in domain.accounts() {
+                    for asset in account.assets() {
+                        if let AssetValue::Store(metadata) = asset.value() {
+                            if metadata.get(&key) == Some(&value) {
+                                vec.push(asset.clone())
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(vec)
+        }
+    }
+
+    impl ValidQuery for FindAssetDefinitionsByMetadataKeyValue {
+        #[metrics(+"find_asset_definitions_by_metadata_key_value")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let key = self
+                .key
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get key")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let value = self
+                .value
+                .evaluate(wsv, &Context::default())
+                .wrap_err("Failed to get value")
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            iroha_logger::trace!(%key, %value);
+            if let Some(ids) = wsv.asset_definition_ids_by_metadata(&key, &value) {
+                return ids
+                    .iter()
+                    .map(|id| {
+                        wsv.asset_definition_entry(id)
+                            .map(|entry| entry.definition().clone())
+                            .map_err(Into::into)
+                    })
+                    .collect();
+            }
+            let mut vec = Vec::new();
+            for domain in wsv.domains().iter() {
+                for asset_definition_entry in domain.asset_definitions() {
+                    let definition = asset_definition_entry.definition();
+                    if definition.metadata().get(&key) == Some(&value) {
+                        vec.push(definition.clone())
+                    }
+                }
+            }
+            Ok(vec)
+        }
+    }
+
     impl ValidQuery for FindAssetsByAccountId {
         #[metrics(+"find_assets_by_account_id")]
         fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
@@ -4,6 +4,7 @@ use iroha_data_model::query::block::FindBlockHeaderByHash;
 use iroha_telemetry::metrics;
 
 use super::*;
+use crate::wsv::block_transaction_values;
 
 impl ValidQuery for FindAllBlocks {
     #[metrics(+"find_all_blocks")]
@@ -50,3 +51,69 @@ impl ValidQuery for FindBlockHeaderByHash {
         Ok(block.clone().into_value().header)
     }
 }
+
+impl ValidQuery for FindBlockByHeight {
+    #[metrics(+"find_block_by_height")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let height = evaluate_height(&self.height, wsv)?;
+        Ok(block_at_height(wsv, height)?.into_value())
+    }
+}
+
+impl ValidQuery for FindBlocksInHeightRange {
+    #[metrics(+"find_blocks_in_height_range")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let start = evaluate_height(&self.start, wsv)?.max(1);
+        let end = evaluate_height(&self.end, wsv)?;
+        let len = end.saturating_add(1).saturating_sub(start);
+        let blocks = wsv
+            .blocks_from_height(usize::try_from(start).unwrap_or(usize::MAX))
+            .take(usize::try_from(len).unwrap_or(usize::MAX))
+            .map(VersionedCommittedBlock::into_value)
+            .collect(); // Sorted by height asc.
+        Ok(blocks)
+    }
+}
+
+impl ValidQuery for FindTransactionsInBlock {
+    #[metrics(+"find_transactions_in_block")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let height = evaluate_height(&self.height, wsv)?;
+        let block = block_at_height(wsv, height)?;
+        Ok(block_transaction_values(block.as_v1()))
+    }
+}
+
+impl ValidQuery for FindLatestBlockHeader {
+    #[metrics(+"find_latest_block_header")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let block = wsv
+            .blocks()
+            .last()
+            .ok_or_else(|| query::Error::Find(Box::new(FindError::BlockHeight(0))))?;
+
+        Ok(block.clone().into_value().header)
+    }
+}
+
+fn evaluate_height(height: &EvaluatesTo<u128>, wsv: &WorldStateView) -> Result<u64, query::Error> {
+    let height = height
+        .evaluate(wsv, &Context::default())
+        .wrap_err("Failed to evaluate height")
+        .map_err(|e| query::Error::Evaluate(e.to_string()))?;
+    u64::try_from(height).map_err(|e| query::Error::Conversion(e.to_string()))
+}
+
+/// Block at `height`, counting from 1 for the genesis block.
+fn block_at_height(
+    wsv: &WorldStateView,
+    height: u64,
+) -> Result<VersionedCommittedBlock, query::Error> {
+    let not_found = || query::Error::Find(Box::new(FindError::BlockHeight(height)));
+    if height == 0 {
+        return Err(not_found());
+    }
+    wsv.blocks_from_height(usize::try_from(height).map_err(|_| not_found())?)
+        .next()
+        .ok_or_else(not_found)
+}
@@ -76,13 +76,14 @@ impl Evaluate for ContextValue {
 
     fn evaluate(
         &self,
-        _wsv: &WorldStateView,
+        wsv: &WorldStateView,
         context: &Context,
     ) -> Result<Self::Value, Self::Error> {
         context
             .get(&self.value_name)
+            .cloned()
+            .or_else(|| wsv.builtin_context_value(&self.value_name))
             .ok_or_else(|| FindError::Context(self.value_name.clone()).into())
-            .map(ToOwned::to_owned)
     }
 }
 
@@ -373,7 +374,7 @@ mod tests {
     use std::{error::Error as StdError, fmt::Debug};
 
     use eyre::Result;
-    use iroha_crypto::KeyPair;
+    use iroha_crypto::{Hash, KeyPair};
     use iroha_data_model::val_vec;
     use iroha_macro::error::ErrorTryFromEnum;
     use parity_scale_codec::{Decode, Encode};
@@ -381,6 +382,48 @@ mod tests {
     use super::*;
     use crate::wsv::World;
 
+    #[test]
+    fn builtin_context_values() -> Result<()> {
+        use iroha_data_model::expression::{
+            BLOCK_HEIGHT_VALUE, BLOCK_TIMESTAMP_VALUE, TRANSACTION_CREATOR_VALUE,
+            TRANSACTION_HASH_VALUE,
+        };
+
+        let wsv = WorldStateView::new(World::new());
+        let creator: AccountId = "alice@wonderland".parse()?;
+        let hash = Hash::new(b"transaction");
+        let evaluate = |name: &str| ContextValue::new(name).evaluate(&wsv, &Context::new());
+
+        assert_eq!(evaluate(BLOCK_HEIGHT_VALUE)?, Value::U128(0));
+        assert!(evaluate(BLOCK_TIMESTAMP_VALUE).is_err());
+        assert!(evaluate(TRANSACTION_CREATOR_VALUE).is_err());
+
+        wsv.with_transaction_context(creator.clone(), hash, || -> Result<()> {
+            assert_eq!(
+                evaluate(TRANSACTION_CREATOR_VALUE)?,
+                Value::Id(IdBox::AccountId(creator.clone()))
+            );
+            assert_eq!(evaluate(TRANSACTION_HASH_VALUE)?, Value::Hash(hash));
+            // Concurrent evaluations don't see the transaction being executed
+            std::thread::scope(|scope| {
+                let is_seen_concurrently = scope
+                    .spawn(|| evaluate(TRANSACTION_CREATOR_VALUE).is_ok())
+                    .join()
+                    .expect("Evaluation doesn't panic");
+                assert!(!is_seen_concurrently);
+            });
+            Ok(())
+        })?;
+        assert!(evaluate(TRANSACTION_HASH_VALUE).is_err());
+
+        let context = Context::from([(BLOCK_HEIGHT_VALUE.to_owned(), Value::U128(42))]);
+        assert_eq!(
+            ContextValue::new(BLOCK_HEIGHT_VALUE).evaluate(&wsv, &context)?,
+            Value::U128(42)
+        );
+        Ok(())
+    }
+
     /// Example taken from [whitepaper](https://github.com/hyperledger/iroha/blob/iroha2-dev/docs/source/iroha_2_whitepaper.md#261-multisignature-transactions)
     #[test]
     fn conditional_multisignature_quorum() -> Result<()> {
@@ -7,6 +7,7 @@
     clippy::std_instead_of_alloc
 )]
 pub mod account;
+pub mod aggregate;
 pub mod asset;
 pub mod block;
 pub mod domain;
@@ -143,6 +144,9 @@ pub mod error {
         /// Block with supplied parent hash not found. More description in a string.
         #[error("Block with hash {0} not found.")]
         Block(HashOf<VersionedCommittedBlock>),
+        /// Block at given height not found.
+        #[error("Block at height {0} not found.")]
+        BlockHeight(u64),
         /// Transaction with given hash not found.
         #[error("Transaction not found")]
         Transaction(HashOf<VersionedTransaction>),
@@ -248,6 +252,8 @@ impl Execute for Instruction {
             Grant(grant_box) => grant_box.execute(authority, wsv),
             Revoke(revoke_box) => revoke_box.execute(authority, wsv),
             ExecuteTrigger(execute_trigger) => execute_trigger.execute(authority, wsv),
+            SetParameter(set_parameter) => set_parameter.execute(authority, wsv),
+            EnableTrigger(enable_trigger) => enable_trigger.execute(authority, wsv),
         }
     }
 }
@@ -532,6 +538,81 @@ impl Execute for SequenceBox {
     }
 }
 
+/// Failure of an instruction nested in composite `If`, `Pair` or `Sequence` instructions.
+#[derive(Debug)]
+pub struct LocatedFailure {
+    /// Path to the failed instruction, see [`InstructionLocation::nested_path`].
+    pub nested_path: Vec<u32>,
+    /// Instruction which execution failed.
+    pub instruction: Instruction,
+    /// Cause of the failure.
+    pub error: Error,
+}
+
+/// Execute `instruction` in the same way as [`Execute::execute`] does,
+/// but locate the failed instruction inside of composite instructions.
+///
+/// # Errors
+/// Fails if execution of any of the nested instructions fails.
+pub fn execute_locating_failure(
+    instruction: &Instruction,
+    authority: &<Account as Identifiable>::Id,
+    wsv: &WorldStateView,
+) -> Result<(), LocatedFailure> {
+    fn locate(
+        instruction: &Instruction,
+        authority: &<Account as Identifiable>::Id,
+        wsv: &WorldStateView,
+        path: &mut Vec<u32>,
+    ) -> Result<(), (Instruction, Error)> {
+        let mut nested = |index, nested: &Instruction| -> Result<(), (Instruction, Error)> {
+            path.push(index);
+            locate(nested, authority, wsv, path)?;
+            path.pop();
+            Ok(())
+        };
+
+        match instruction {
+            Instruction::Sequence(sequence) => {
+                for (index, instruction) in (0_u32..).zip(&sequence.instructions) {
+                    nested(index, instruction)?;
+                }
+                Ok(())
+            }
+            Instruction::Pair(pair) => {
+                nested(0, &pair.left_instruction)?;
+                nested(1, &pair.right_instruction)
+            }
+            Instruction::If(if_box) => {
+                let condition = if_box
+                    .condition
+                    .evaluate(wsv, &Context::new())
+                    .map_err(|error| (instruction.clone(), Error::from(error)))?;
+                if condition {
+                    nested(0, &if_box.then)
+                } else if let Some(otherwise) = &if_box.otherwise {
+                    nested(1, otherwise)
+                } else {
+                    Ok(())
+                }
+            }
+            _ => instruction
+                .clone()
+                .execute(authority.clone(), wsv)
+                .map_err(|error| (instruction.clone(), error)),
+        }
+    }
+
+    let mut nested_path = Vec::new();
+    locate(instruction, authority, wsv, &mut nested_path).map_err(|(instruction, error)| {
+        LocatedFailure {
+            nested_path,
+            instruction,
+            error,
+        }
+    })
+}
+
 impl Execute for FailBox {
     type Error = Error;
 
@@ -738,6 +819,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn failed_nested_instruction_is_located() -> Result<()> {
+        let wsv = WorldStateView::new(world_with_test_domains()?);
+        let account_id = AccountId::from_str("alice@wonderland")?;
+        let fail = Instruction::Fail(FailBox::new("fail"));
+        let instruction: Instruction = SequenceBox::new(vec![
+            Pair::new(FailBox::new("skipped"), FailBox::new("skipped")).into(),
+            If::with_otherwise(
+                false,
+                SequenceBox::new(vec![]),
+                SequenceBox::new(vec![SequenceBox::new(vec![]).into(), fail.clone()]),
+            )
+            .into(),
+        ])
+        .into();
+
+        let failure =
+            execute_locating_failure(&instruction, &account_id, &wsv).expect_err("Error expected");
+        assert_eq!(failure.nested_path, vec![0, 0]);
+
+        let instruction: Instruction = SequenceBox::new(vec![
+            SequenceBox::new(vec![]).into(),
+            If::with_otherwise(
+                false,
+                SequenceBox::new(vec![]),
+                SequenceBox::new(vec![SequenceBox::new(vec![]).into(), fail.clone()]),
+            )
+            .into(),
+        ])
+        .into();
+
+        let failure =
+            execute_locating_failure(&instruction, &account_id, &wsv).expect_err("Error expected");
+        assert_eq!(failure.nested_path, vec![1, 1, 1]);
+        assert_eq!(failure.instruction, fail);
+        assert!(matches!(failure.error, Error::FailBox(_)));
+        Ok(())
+    }
+
     #[test]
     fn executing_unregistered_trigger_should_return_error() -> Result<()> {
         let wsv = WorldStateView::new(world_with_test_domains()?);
@@ -798,4 +918,90 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn block_schedule_is_resolved_on_trigger_registration() -> Result<()> {
+        let wsv = WorldStateView::new(world_with_test_domains()?);
+        let account_id = AccountId::from_str("alice@wonderland")?;
+        let trigger_id = TriggerId::from_str("test_trigger_id")?;
+
+        let register_trigger = RegisterBox::new(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                Executable::from(Vec::new()),
+                Repeats::Indefinitely,
+                account_id.clone(),
+                FilterBox::Time(TimeEventFilter(ExecutionTime::BlockHeight(
+                    BlockSchedule::after_registration(3).with_period(2),
+                ))),
+            ),
+        ));
+        register_trigger.execute(account_id, &wsv)?;
+
+        let filter = wsv
+            .triggers()
+            .inspect_by_id(&trigger_id, |action| action.clone_and_box().filter)
+            .expect("Trigger should be registered");
+        assert_eq!(
+            filter,
+            FilterBox::Time(TimeEventFilter(ExecutionTime::BlockHeight(
+                BlockSchedule::at_height(wsv.height() + 4).with_period(2),
+            )))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn transaction_fee_is_paid_to_treasury() -> Result<()> {
+        let wsv = WorldStateView::new(world_with_test_domains()?);
+        let account_id = AccountId::from_str("alice@wonderland")?;
+        let treasury_id = AccountId::from_str("treasury@wonderland")?;
+        let fee_definition_id = AssetDefinitionId::from_str("xor#wonderland")?;
+        RegisterBox::new(Account::new(treasury_id.clone(), []))
+            .execute(account_id.clone(), &wsv)?;
+        RegisterBox::new(AssetDefinition::quantity(fee_definition_id.clone()))
+            .execute(account_id.clone(), &wsv)?;
+        let payer_asset_id = AssetId::new(fee_definition_id.clone(), account_id.clone());
+        MintBox::new(10_u32, IdBox::AssetId(payer_asset_id.clone()))
+            .execute(account_id.clone(), &wsv)?;
+
+        let fee_schedule = FeeSchedule::new(fee_definition_id.clone(), treasury_id.clone(), 1)
+            .with_instruction_fee(InstructionKind::Fail, 2);
+        SetParameterBox::new(Parameter::TransactionFees(fee_schedule))
+            .execute(account_id.clone(), &wsv)?;
+
+        // Flat fee plus a fee for each of the two nested instructions
+        let instructions: Vec<Instruction> = vec![SequenceBox::new(vec![
+            FailBox::new("fail").into(),
+            FailBox::new("fail").into(),
+        ])
+        .into()];
+        wsv.pay_transaction_fee(&account_id, &instructions.into(), 0)?;
+
+        assert_eq!(
+            *wsv.asset(&payer_asset_id)?.value(),
+            AssetValue::Quantity(5)
+        );
+        assert_eq!(
+            *wsv.asset(&AssetId::new(fee_definition_id, treasury_id))?
+                .value(),
+            AssetValue::Quantity(5)
+        );
+
+        // Not enough funds left to pay for the same transaction again
+        let instructions: Vec<Instruction> = vec![SequenceBox::new(vec![
+            FailBox::new("fail").into(),
+            FailBox::new("fail").into(),
+            FailBox::new("fail").into(),
+        ])
+        .into()];
+        assert!(matches!(
+            wsv.pay_transaction_fee(&account_id, &instructions.into(), 0)
+                .expect_err("Error expected"),
+            Error::Math(MathError::NotEnoughQuantity)
+        ));
+
+        Ok(())
+    }
 }
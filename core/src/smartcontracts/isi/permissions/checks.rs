@@ -28,7 +28,7 @@ pub fn check_instruction_permissions(
     check_permissions_directly(account_id, revoked_instructions, instruction_judge, wsv)?;
 
     check_query_in_instruction(account_id, instruction, wsv, query_judge)
-        .map_err(|reason| NotPermittedFail { reason })
+        .map_err(NotPermittedFail::new)
         .map_err(TransactionRejectionReason::NotPermitted)?;
 
     Ok(())
@@ -42,13 +42,43 @@ fn check_permissions_directly(
 ) -> std::result::Result<(), TransactionRejectionReason> {
     for isi in instructions {
         is_instruction_allowed
-            .judge(account_id, isi, wsv)
-            .map_err(|reason| NotPermittedFail { reason })
+            .judge_with_validator(account_id, isi, wsv)
+            .map_err(|denial| NotPermittedFail {
+                validator: denial.validator,
+                ..NotPermittedFail::new(denial.reason)
+            })
             .map_err(TransactionRejectionReason::NotPermitted)?;
     }
     Ok(())
 }
 
+/// Verify that the given `query` is allowed to be executed, including
+/// the queries it evaluates: every query of a [`FindBatch`] is judged
+/// on its own, and a [`FindAggregate`] is judged along with the query
+/// whose output it aggregates.
+///
+/// # Errors
+/// If a user is not allowed to execute the query or one of the inner queries,
+/// given the current `judge`.
+pub fn check_query_permissions(
+    authority: &AccountId,
+    query: &QueryBox,
+    wsv: &WorldStateView,
+    query_judge: &dyn Judge<Operation = QueryBox>,
+) -> Result<()> {
+    match query {
+        QueryBox::FindBatch(batch) => batch
+            .queries
+            .iter()
+            .try_for_each(|query| check_query_permissions(authority, query, wsv, query_judge)),
+        QueryBox::FindAggregate(aggregate) => {
+            query_judge.judge(authority, query, wsv)?;
+            check_query_permissions(authority, &aggregate.query, wsv, query_judge)
+        }
+        query => query_judge.judge(authority, query, wsv),
+    }
+}
+
 /// Checks an expression recursively to evaluate if there is a query
 /// inside of it and if the user has permission to execute this query.
 ///
@@ -110,7 +140,7 @@ pub fn check_query_in_expression(
             wsv,
             query_judge,
         )),
-        Expression::Query(query) => query_judge.judge(authority, query, wsv),
+        Expression::Query(query) => check_query_permissions(authority, query, wsv, query_judge),
         Expression::Contains(expression) => check_query_in_expression(
             authority,
             &expression.collection.expression,
@@ -294,6 +324,14 @@ pub fn check_query_in_instruction(
                     check_query_in_instruction(authority, this_instruction, wsv, query_judge)
                 })
         }
-        Instruction::Fail(_) | Instruction::ExecuteTrigger(_) => Ok(()),
+        Instruction::SetParameter(instruction) => check_query_in_expression(
+            authority,
+            &instruction.parameter.expression,
+            wsv,
+            query_judge,
+        ),
+        Instruction::Fail(_) | Instruction::ExecuteTrigger(_) | Instruction::EnableTrigger(_) => {
+            Ok(())
+        }
     }
 }
@@ -116,7 +116,9 @@ impl<V: IsAllowed<Operation = Instruction>> IsAllowed for CheckNested<V> {
             | Instruction::Grant(_)
             | Instruction::Revoke(_)
             | Instruction::Fail(_)
-            | Instruction::ExecuteTrigger(_) => self.validator.check(authority, instruction, wsv),
+            | Instruction::ExecuteTrigger(_)
+            | Instruction::EnableTrigger(_)
+            | Instruction::SetParameter(_) => self.validator.check(authority, instruction, wsv),
             Instruction::If(if_box) => self
                 .check(authority, &if_box.then, wsv)
                 .least_permissive_with(|| match &if_box.otherwise {
@@ -49,6 +49,25 @@ pub trait Judge {
         wsv: &WorldStateView,
     ) -> Result<()>;
 
+    /// Check if `operation` is allowed for `authority` like [`Judge::judge`] does,
+    /// additionally reporting which validator denied the operation, if known
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `operation` is not permitted
+    fn judge_with_validator(
+        &self,
+        authority: &AccountId,
+        operation: &Self::Operation,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<(), Denial> {
+        self.judge(authority, operation, wsv)
+            .map_err(|reason| Denial {
+                validator: None,
+                reason,
+            })
+    }
+
     /// Disable showing the operation description in the validation error message
     ///
     /// Use this when you have one [`Judge`] nested inside another, as it will prevent
@@ -67,6 +86,15 @@ pub trait Judge {
     }
 }
 
+/// Denial of an operation by a [`Judge`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Denial {
+    /// Name of the validator which denied the operation, if known
+    pub validator: Option<String>,
+    /// The cause of denial
+    pub reason: DenialReason,
+}
+
 /// Wrapper for types implementing [`Judge`]
 ///
 /// Implements [`IsAllowed`] trait so that
@@ -219,12 +247,25 @@ impl<O: NeedsPermission + Display> Judge for NoDenies<O> {
         operation: &Self::Operation,
         wsv: &WorldStateView,
     ) -> std::result::Result<(), DenialReason> {
+        self.judge_with_validator(authority, operation, wsv)
+            .map_err(|denial| denial.reason)
+    }
+
+    fn judge_with_validator(
+        &self,
+        authority: &AccountId,
+        operation: &Self::Operation,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<(), Denial> {
         for validator in &self.validators {
             if let ValidatorVerdict::Deny(reason) = validator.check(authority, operation, wsv) {
-                return Err(format!(
-                    "Validator `{validator}` denied the operation{}: {reason}",
-                    construct_operation_string(&operation, self.display_operation, authority)
-                ));
+                return Err(Denial {
+                    validator: Some(validator.to_string()),
+                    reason: format!(
+                        "Validator `{validator}` denied the operation{}: {reason}",
+                        construct_operation_string(&operation, self.display_operation, authority)
+                    ),
+                });
             }
         }
 
@@ -274,6 +315,16 @@ impl<O: NeedsPermission + Display> Judge for NoDeniesAndAtLeastOneAllow<O> {
         operation: &Self::Operation,
         wsv: &WorldStateView,
     ) -> std::result::Result<(), DenialReason> {
+        self.judge_with_validator(authority, operation, wsv)
+            .map_err(|denial| denial.reason)
+    }
+
+    fn judge_with_validator(
+        &self,
+        authority: &AccountId,
+        operation: &Self::Operation,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<(), Denial> {
         let mut messages = Vec::new();
         let mut allowed = false;
 
@@ -281,10 +332,17 @@ impl<O: NeedsPermission + Display> Judge for NoDeniesAndAtLeastOneAllow<O> {
             match validator.check(authority, operation, wsv) {
                 ValidatorVerdict::Allow => allowed = true,
                 ValidatorVerdict::Deny(reason) => {
-                    return Err(format!(
-                        "Validator `{validator}` denied the operation{}: {reason}",
-                        construct_operation_string(&operation, self.display_operation, authority)
-                    ));
+                    return Err(Denial {
+                        validator: Some(validator.to_string()),
+                        reason: format!(
+                            "Validator `{validator}` denied the operation{}: {reason}",
+                            construct_operation_string(
+                                &operation,
+                                self.display_operation,
+                                authority
+                            )
+                        ),
+                    });
                 }
                 ValidatorVerdict::Skip => {
                     messages.push(format!("Validator `{validator}` skipped"));
@@ -295,10 +353,13 @@ impl<O: NeedsPermission + Display> Judge for NoDeniesAndAtLeastOneAllow<O> {
         if allowed {
             Ok(())
         } else {
-            Err(format!(
-                "None of the validators has allowed operation{}: {messages:#?}",
-                construct_operation_string(&operation, self.display_operation, authority)
-            ))
+            Err(Denial {
+                validator: None,
+                reason: format!(
+                    "None of the validators has allowed operation{}: {messages:#?}",
+                    construct_operation_string(&operation, self.display_operation, authority)
+                ),
+            })
         }
     }
 
@@ -557,4 +557,43 @@ mod tests {
             .build();
         assert!(check_query_in_instruction(&alice_id, &instruction, &wsv, &judge).is_err())
     }
+
+    #[derive(Debug, Clone, Serialize, Display)]
+    #[display(fmt = "Deny finding all accounts")]
+    struct DenyFindAllAccounts;
+
+    impl IsAllowed for DenyFindAllAccounts {
+        type Operation = QueryBox;
+
+        fn check(
+            &self,
+            _authority: &AccountId,
+            query: &QueryBox,
+            _wsv: &WorldStateView,
+        ) -> ValidatorVerdict {
+            match query {
+                QueryBox::FindAllAccounts(_) => {
+                    ValidatorVerdict::Deny("Finding all accounts is denied.".to_owned())
+                }
+                _ => ValidatorVerdict::Skip,
+            }
+        }
+    }
+
+    #[test]
+    pub fn check_query_permissions_of_inner_queries() {
+        let judge = JudgeBuilder::with_validator(DenyFindAllAccounts)
+            .no_denies()
+            .build();
+        let wsv = WorldStateView::new(World::new());
+        let alice_id = <Account as Identifiable>::Id::from_str("alice@test").expect("Valid");
+        let check = |query: QueryBox| check_query_permissions(&alice_id, &query, &wsv, &judge);
+
+        let count_accounts = FindAggregate::new(FindAllAccounts::new(), AggregateFunction::Count);
+        let count_domains = FindAggregate::new(FindAllDomains::new(), AggregateFunction::Count);
+        assert!(check(count_accounts.clone().into()).is_err());
+        assert!(check(count_domains.clone().into()).is_ok());
+        assert!(check(FindBatch::new([QueryBox::from(count_accounts)]).into()).is_err());
+        assert!(check(FindBatch::new([count_domains]).into()).is_ok());
+    }
 }
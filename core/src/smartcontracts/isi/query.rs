@@ -8,6 +8,7 @@
 use eyre::Result;
 use iroha_data_model::prelude::*;
 use iroha_schema::IntoSchema;
+use iroha_telemetry::metrics;
 use parity_scale_codec::{Decode, Encode};
 use thiserror::Error;
 
@@ -35,6 +36,12 @@ impl ValidQueryRequest {
     pub const fn new(query: QueryBox) -> Self {
         Self { query }
     }
+
+    /// Query to be executed.
+    #[inline]
+    pub const fn query(&self) -> &QueryBox {
+        &self.query
+    }
 }
 
 /// Query errors.
@@ -61,6 +68,18 @@ pub enum Error {
     /// Query without account.
     #[error("Unauthorized query: account not provided")]
     Unauthorized,
+    /// Query is meant for another chain.
+    #[error("Query is meant for another chain")]
+    ChainIdMismatch,
+    /// Query cursor is unknown or has expired.
+    #[error("Query cursor is unknown or has expired")]
+    UnknownCursor,
+    /// Query cursor was issued for the query with another filter or sorting.
+    #[error("Query cursor was issued for the query with another filter or sorting")]
+    CursorMismatch,
+    /// Query result is too large to be kept for the following pages.
+    #[error("Query result is too large to be kept for the following pages, narrow the query down")]
+    ResultTooLarge,
 }
 
 impl From<FindError> for Error {
@@ -79,6 +98,7 @@ impl ValidQuery for QueryBox {
             FindAccountsByName(query) => query.execute_into_value(wsv),
             FindAccountsByDomainId(query) => query.execute_into_value(wsv),
             FindAccountsWithAsset(query) => query.execute_into_value(wsv),
+            FindAccountNextSequenceNumber(query) => query.execute_into_value(wsv),
             FindAllAssets(query) => query.execute_into_value(wsv),
             FindAllAssetsDefinitions(query) => query.execute_into_value(wsv),
             FindAssetById(query) => query.execute_into_value(wsv),
@@ -93,6 +113,7 @@ impl ValidQuery for QueryBox {
             FindDomainById(query) => query.execute_into_value(wsv),
             FindDomainKeyValueByIdAndKey(query) => query.execute_into_value(wsv),
             FindAllPeers(query) => query.execute_into_value(wsv),
+            FindAllParameters(query) => query.execute_into_value(wsv),
             FindAssetKeyValueByIdAndKey(query) => query.execute_into_value(wsv),
             FindAccountKeyValueByIdAndKey(query) => query.execute_into_value(wsv),
             FindAllBlocks(query) => query.execute_into_value(wsv),
@@ -112,10 +133,35 @@ impl ValidQuery for QueryBox {
             FindAllRoleIds(query) => query.execute_into_value(wsv),
             FindRolesByAccountId(query) => query.execute_into_value(wsv),
             FindRoleByRoleId(query) => query.execute_into_value(wsv),
+            FindAggregate(query) => query.execute_into_value(wsv),
+            FindBatch(query) => query.execute_into_value(wsv),
+            FindAccountsByMetadataKeyValue(query) => query.execute_into_value(wsv),
+            FindAssetsByMetadataKeyValue(query) => query.execute_into_value(wsv),
+            FindAssetDefinitionsByMetadataKeyValue(query) => query.execute_into_value(wsv),
+            FindBlockByHeight(query) => query.execute_into_value(wsv),
+            FindBlocksInHeightRange(query) => query.execute_into_value(wsv),
+            FindTransactionsInBlock(query) => query.execute_into_value(wsv),
+            FindLatestBlockHeader(query) => query.execute_into_value(wsv),
+            FindAccountStatement(query) => query.execute_into_value(wsv),
+            FindTransactionProofByHash(query) => query.execute_into_value(wsv),
+            FindTransactionRejectionReasonByHash(query) => query.execute_into_value(wsv),
         }
     }
 }
 
+/// All queries of a batch see the same state of the world as long as the
+/// whole batch is evaluated within [`WorldStateView::consistent_read`], as
+/// Torii does, or while a block is being applied, as by smart contracts.
+impl ValidQuery for FindBatch {
+    #[metrics(+"find_batch")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+        self.queries
+            .iter()
+            .map(|query| query.execute(wsv))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::restriction)]
@@ -123,7 +169,10 @@ mod tests {
     use std::{str::FromStr, sync::Arc};
 
     use iroha_crypto::{Hash, HashOf, KeyPair};
-    use iroha_data_model::transaction::TransactionLimits;
+    use iroha_data_model::{
+        predicate::{string, value},
+        transaction::TransactionLimits,
+    };
     use once_cell::sync::Lazy;
 
     use super::*;
@@ -235,6 +284,7 @@ mod tests {
             .chain_first()
             .validate(&TransactionValidator::new(
                 limits,
+                ChainId::default(),
                 Arc::new(AllowAll::new()),
                 Arc::new(AllowAll::new()),
                 Arc::clone(&wsv),
@@ -257,6 +307,7 @@ mod tests {
                 )
                 .validate(&TransactionValidator::new(
                     limits,
+                    ChainId::default(),
                     Arc::new(AllowAll::new()),
                     Arc::new(AllowAll::new()),
                     Arc::clone(&wsv),
@@ -345,6 +396,105 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn find_blocks_by_height() -> Result<()> {
+        let num_blocks = 10;
+
+        let wsv = wsv_with_test_blocks_and_transactions(num_blocks, 1, 1).await?;
+        let blocks: Vec<_> = wsv
+            .blocks()
+            .map(|block| block.clone().into_value())
+            .collect();
+
+        assert_eq!(FindBlockByHeight::new(3_u128).execute(&wsv)?, blocks[2]);
+        assert!(FindBlockByHeight::new(0_u128).execute(&wsv).is_err());
+        assert!(FindBlockByHeight::new(u128::from(num_blocks) + 1)
+            .execute(&wsv)
+            .is_err());
+
+        assert_eq!(
+            FindBlocksInHeightRange::new(4_u128, 6_u128).execute(&wsv)?,
+            blocks[3..6].to_vec()
+        );
+        assert_eq!(
+            FindBlocksInHeightRange::new(9_u128, 100_u128)
+                .execute(&wsv)?
+                .len(),
+            2
+        );
+        assert!(FindBlocksInHeightRange::new(6_u128, 4_u128)
+            .execute(&wsv)?
+            .is_empty());
+
+        assert_eq!(
+            FindLatestBlockHeader::new().execute(&wsv)?,
+            blocks[blocks.len() - 1].header
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_transactions_in_block() -> Result<()> {
+        let wsv = wsv_with_test_blocks_and_transactions(3, 2, 1).await?;
+
+        let txs = FindTransactionsInBlock::new(2_u128).execute(&wsv)?;
+        let block_hash = Hash::from(wsv.blocks().nth(1).expect("WSV is empty").hash());
+
+        assert_eq!(txs.len(), 3);
+        assert!(txs.iter().all(|tx| tx.block_hash == block_hash));
+        assert!(FindTransactionsInBlock::new(4_u128).execute(&wsv).is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_transaction_proof_by_hash() -> Result<()> {
+        let wsv = wsv_with_test_blocks_and_transactions(1, 3, 1).await?;
+        let block = wsv.blocks().last().expect("WSV is empty");
+        let tx_hash = block.as_v1().transactions[0].hash();
+
+        let proof = FindTransactionProofByHash::new(Hash::from(tx_hash)).execute(&wsv)?;
+        assert_eq!(proof.block_header.hash(), Hash::from(block.hash()));
+        assert!(proof.verify([ALICE_KEYS.public_key()], 1).is_ok());
+        assert_eq!(
+            proof.verify([ALICE_KEYS.public_key()], 2),
+            Err(TransactionProofError::NotEnoughSignatures {
+                expected: 2,
+                actual: 1
+            })
+        );
+        let stranger = KeyPair::generate()?;
+        assert!(proof.verify([stranger.public_key()], 1).is_err());
+
+        let rejected_hash = block.as_v1().rejected_transactions[0].hash();
+        assert!(FindTransactionProofByHash::new(Hash::from(rejected_hash))
+            .execute(&wsv)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn find_transaction_rejection_reason_by_hash() -> Result<()> {
+        let wsv = wsv_with_test_blocks_and_transactions(1, 1, 1).await?;
+        let block = wsv.blocks().last().expect("WSV is empty");
+        let rejected_tx = &block.as_v1().rejected_transactions[0];
+
+        let reason = FindTransactionRejectionReasonByHash::new(Hash::from(rejected_tx.hash()))
+            .execute(&wsv)?;
+        assert_eq!(reason, rejected_tx.as_v1().rejection_reason);
+
+        let committed_hash = block.as_v1().transactions[0].hash();
+        assert!(
+            FindTransactionRejectionReasonByHash::new(Hash::from(committed_hash))
+                .execute(&wsv)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn find_all_transactions() -> Result<()> {
         let num_blocks = 100;
@@ -392,6 +542,7 @@ mod tests {
             .chain_first()
             .validate(&TransactionValidator::new(
                 tx_limits,
+                ChainId::default(),
                 Arc::new(AllowAll::new()),
                 Arc::new(AllowAll::new()),
                 Arc::clone(&wsv),
@@ -448,4 +599,209 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn aggregate_quantities_and_counts() -> Result<()> {
+        let rose_id = AssetDefinitionId::from_str("rose#wonderland")?;
+        let account_with_roses = |id: &str, quantity: u32| -> Result<Account> {
+            let id = AccountId::from_str(id)?;
+            let mut account = Account::new(id.clone(), []).build();
+            let asset_id = AssetId::new(rose_id.clone(), id);
+            assert!(account
+                .add_asset(Asset::new(asset_id, AssetValue::Quantity(quantity)))
+                .is_none());
+            Ok(account)
+        };
+        let mut wonderland = Domain::new(DomainId::from_str("wonderland")?).build();
+        assert!(wonderland
+            .add_asset_definition(
+                AssetDefinition::quantity(rose_id.clone()).build(),
+                ALICE_ID.clone(),
+            )
+            .is_none());
+        assert!(wonderland
+            .add_account(account_with_roses("alice@wonderland", 13)?)
+            .is_none());
+        assert!(wonderland
+            .add_account(account_with_roses("bob@wonderland", 7)?)
+            .is_none());
+        let mut garden = Domain::new(DomainId::from_str("garden")?).build();
+        assert!(garden
+            .add_account(account_with_roses("carol@garden", 5)?)
+            .is_none());
+        let wsv = WorldStateView::new(World::with([wonderland, garden], PeersIds::new()));
+
+        let per_domain =
+            |function| FindAggregate::new(FindAllAssets::new(), function).group_by(GroupBy::Domain);
+        let pair = |domain: &str, value: Value| -> Result<Value> {
+            Ok(Value::Vec(vec![
+                IdBox::DomainId(DomainId::from_str(domain)?).into(),
+                value,
+            ]))
+        };
+        assert_eq!(
+            per_domain(AggregateFunction::Sum).execute(&wsv)?,
+            Value::Vec(vec![
+                pair("garden", Value::U128(5))?,
+                pair("wonderland", Value::U128(20))?
+            ])
+        );
+        assert_eq!(
+            FindAggregate::new(FindAllAccounts::new(), AggregateFunction::Count)
+                .group_by(GroupBy::Domain)
+                .execute(&wsv)?,
+            Value::Vec(vec![
+                pair("garden", Value::U128(1))?,
+                pair("wonderland", Value::U128(2))?
+            ])
+        );
+        assert_eq!(
+            FindAggregate::new(FindAllAssets::new(), AggregateFunction::Max)
+                .with_filter(PredicateBox::new(value::Predicate::Identifiable(
+                    string::Predicate::ends_with("@garden")
+                )))
+                .execute(&wsv)?,
+            Value::U128(5)
+        );
+        assert!(
+            FindAggregate::new(FindAllDomains::new(), AggregateFunction::Sum)
+                .execute(&wsv)
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn find_accounts_by_indexed_and_unindexed_metadata() -> Result<()> {
+        use iroha_config::wsv::{Configuration, MetadataIndexes};
+
+        use crate::smartcontracts::Execute as _;
+
+        let customer = Name::from_str("customer")?;
+        let nickname = Name::from_str("nickname")?;
+        let config = Configuration {
+            metadata_indexes: MetadataIndexes {
+                account: vec![customer.clone()],
+                ..MetadataIndexes::default()
+            },
+            ..Configuration::default()
+        };
+        let (events_sender, _) = tokio::sync::broadcast::channel(1);
+        let wsv =
+            WorldStateView::from_configuration(config, world_with_test_domains(), events_sender);
+        for (key, value) in [(&customer, 42_u32), (&nickname, 7_u32)] {
+            SetKeyValueBox::new(IdBox::from(ALICE_ID.clone()), key.clone(), value)
+                .execute(ALICE_ID.clone(), &wsv)?;
+        }
+        assert_eq!(
+            wsv.account_ids_by_metadata(&customer, &42_u32.into()),
+            Some(vec![ALICE_ID.clone()])
+        );
+        assert_eq!(wsv.account_ids_by_metadata(&nickname, &7_u32.into()), None);
+
+        let find = |key: &Name, value: u32| {
+            FindAccountsByMetadataKeyValue::new(key.clone(), Value::from(value)).execute(&wsv)
+        };
+        assert_eq!(find(&customer, 42)?.len(), 1);
+        assert_eq!(find(&nickname, 7)?.len(), 1);
+        assert!(find(&customer, 7)?.is_empty());
+
+        let copy = wsv.clone();
+        SetKeyValueBox::new(IdBox::from(ALICE_ID.clone()), customer.clone(), 43_u32)
+            .execute(ALICE_ID.clone(), &copy)?;
+        assert_eq!(
+            copy.account_ids_by_metadata(&customer, &43_u32.into()),
+            Some(vec![ALICE_ID.clone()])
+        );
+        assert!(find(&customer, 43)?.is_empty());
+
+        RemoveKeyValueBox::new(IdBox::from(ALICE_ID.clone()), customer.clone())
+            .execute(ALICE_ID.clone(), &wsv)?;
+        assert!(find(&customer, 42)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn account_statement_includes_received_transfers() -> Result<()> {
+        use crate::smartcontracts::Execute as _;
+
+        let wsv = WorldStateView::new(world_with_test_domains());
+        let bob_id = AccountId::from_str("bob@wonderland")?;
+        let rose_id = AssetDefinitionId::from_str("rose#wonderland")?;
+        let alice_rose = AssetId::new(rose_id.clone(), ALICE_ID.clone());
+        let bob_rose = AssetId::new(rose_id.clone(), bob_id.clone());
+        RegisterBox::new(Account::new(bob_id.clone(), [])).execute(ALICE_ID.clone(), &wsv)?;
+
+        let mint_hash = Hash::new(b"mint");
+        wsv.with_transaction_context(ALICE_ID.clone(), mint_hash, || {
+            MintBox::new(10_u32, IdBox::AssetId(alice_rose.clone())).execute(ALICE_ID.clone(), &wsv)
+        })?;
+        let transfer_hash = Hash::new(b"transfer");
+        wsv.with_transaction_context(ALICE_ID.clone(), transfer_hash, || {
+            TransferBox::new(
+                IdBox::AssetId(alice_rose.clone()),
+                4_u32,
+                IdBox::AssetId(bob_rose.clone()),
+            )
+            .execute(ALICE_ID.clone(), &wsv)
+        })?;
+        BurnBox::new(4_u32, IdBox::AssetId(bob_rose.clone())).execute(bob_id.clone(), &wsv)?;
+
+        let alice_statement = FindAccountStatement::new(ALICE_ID.clone()).execute(&wsv)?;
+        assert_eq!(
+            alice_statement,
+            vec![
+                AccountStatementEntry {
+                    block_height: 1,
+                    transaction_hash: Some(mint_hash),
+                    asset_id: alice_rose.clone(),
+                    counterparty: None,
+                    delta: AssetDelta::Increase(Value::U128(10)),
+                    balance: Value::U128(10),
+                },
+                AccountStatementEntry {
+                    block_height: 1,
+                    transaction_hash: Some(transfer_hash),
+                    asset_id: alice_rose.clone(),
+                    counterparty: Some(bob_id.clone()),
+                    delta: AssetDelta::Decrease(Value::U128(4)),
+                    balance: Value::U128(6),
+                },
+            ]
+        );
+
+        let bob_statement = FindAccountStatement::new(bob_id.clone()).execute(&wsv)?;
+        assert_eq!(bob_statement.len(), 2);
+        assert_eq!(bob_statement[0].counterparty, Some(ALICE_ID.clone()));
+        assert_eq!(bob_statement[0].delta, AssetDelta::Increase(Value::U128(4)));
+        assert_eq!(bob_statement[1].transaction_hash, None);
+        assert_eq!(bob_statement[1].counterparty, None);
+        assert_eq!(bob_statement[1].balance, Value::U128(0));
+
+        let other_definition = AssetDefinitionId::from_str("tulip#wonderland")?;
+        assert!(FindAccountStatement::new(bob_id.clone())
+            .with_asset_definition(other_definition)
+            .execute(&wsv)?
+            .is_empty());
+        assert!(FindAccountStatement::new(bob_id)
+            .with_height_range(2_u128, 3_u128)
+            .execute(&wsv)?
+            .is_empty());
+
+        let copy = wsv.clone();
+        MintBox::new(1_u32, IdBox::AssetId(alice_rose)).execute(ALICE_ID.clone(), &copy)?;
+        assert_eq!(
+            FindAccountStatement::new(ALICE_ID.clone())
+                .execute(&wsv)?
+                .len(),
+            2
+        );
+        assert_eq!(
+            FindAccountStatement::new(ALICE_ID.clone())
+                .execute(&copy)?
+                .len(),
+            2
+        );
+        Ok(())
+    }
 }
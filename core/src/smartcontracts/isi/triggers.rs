@@ -27,7 +27,17 @@ pub mod isi {
             _authority: <Account as Identifiable>::Id,
             wsv: &WorldStateView,
         ) -> Result<(), Self::Error> {
-            let new_trigger = self.object;
+            let mut new_trigger = self.object;
+            // Execution state is maintained by the peer
+            new_trigger.action.stats = ExecutionStats::default();
+            new_trigger.action.disabled = false;
+
+            if let FilterBox::Time(TimeEventFilter(ExecutionTime::BlockHeight(schedule))) =
+                &mut new_trigger.action.filter
+            {
+                // The trigger is registered in the block which is being applied
+                *schedule = schedule.resolve(wsv.height() + 1);
+            }
 
             if !new_trigger.action.mintable() {
                 match &new_trigger.action.repeats {
@@ -181,6 +191,35 @@ pub mod isi {
                 .ok_or_else(|| Error::Find(Box::new(FindError::Trigger(id))))?
         }
     }
+
+    impl Execute for EnableTriggerBox {
+        type Error = Error;
+
+        #[metrics(+"enable_trigger")]
+        fn execute(
+            self,
+            authority: <Account as Identifiable>::Id,
+            wsv: &WorldStateView,
+        ) -> Result<(), Self::Error> {
+            let id = self.trigger_id;
+
+            let is_owner = wsv
+                .triggers()
+                .inspect_by_id(&id, |action| action.technical_account() == &authority)
+                .ok_or_else(|| Error::Find(Box::new(FindError::Trigger(id.clone()))))?;
+            if !is_owner {
+                return Err(ValidationError::new(
+                    "Only the technical account of the trigger can enable it",
+                )
+                .into());
+            }
+
+            wsv.modify_triggers(|triggers| {
+                triggers.enable(&id);
+                Ok(TriggerEvent::Enabled(id))
+            })
+        }
+    }
 }
 
 pub mod query {
@@ -45,3 +45,37 @@ impl ValidQuery for FindTransactionByHash {
             .ok_or_else(|| FindError::Transaction(hash).into())
     }
 }
+
+impl ValidQuery for FindTransactionProofByHash {
+    #[metrics(+"find_transaction_proof_by_hash")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let hash = self
+            .hash
+            .evaluate(wsv, &Context::default())
+            .wrap_err("Failed to get hash")
+            .map_err(|e| query::Error::Evaluate(e.to_string()))?;
+        iroha_logger::trace!(%hash);
+        let hash = hash.typed();
+        wsv.transaction_proof_by_hash(&hash)
+            .ok_or_else(|| FindError::Transaction(hash).into())
+    }
+}
+
+impl ValidQuery for FindTransactionRejectionReasonByHash {
+    #[metrics(+"find_transaction_rejection_reason_by_hash")]
+    fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, query::Error> {
+        let hash = self
+            .hash
+            .evaluate(wsv, &Context::default())
+            .wrap_err("Failed to get hash")
+            .map_err(|e| query::Error::Evaluate(e.to_string()))?;
+        iroha_logger::trace!(%hash);
+        let hash = hash.typed();
+        match wsv.transaction_value_by_hash(&hash) {
+            Some(TransactionValue::RejectedTransaction(tx)) => {
+                Ok(tx.as_v1().rejection_reason.clone())
+            }
+            _ => Err(FindError::Transaction(hash).into()),
+        }
+    }
+}
@@ -254,6 +254,23 @@ pub mod isi {
         }
     }
 
+    impl Execute for SetParameterBox {
+        type Error = Error;
+
+        #[metrics(+"set_parameter")]
+        fn execute(
+            self,
+            _authority: <Account as Identifiable>::Id,
+            wsv: &WorldStateView,
+        ) -> Result<(), Self::Error> {
+            let parameter = self.parameter.evaluate(wsv, &Context::new())?;
+            iroha_logger::trace!(%parameter);
+
+            wsv.set_parameter(parameter);
+            Ok(())
+        }
+    }
+
     /// Remove all tokens with specified definition id from all registered roles
     fn remove_token_from_roles(
         wsv: &WorldStateView,
@@ -382,6 +399,13 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAllParameters {
+        #[metrics("find_all_parameters")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            Ok(wsv.parameters())
+        }
+    }
+
     impl ValidQuery for FindAllPermissionTokenDefinitions {
         #[metrics("find_all_token_ids")]
         fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
@@ -19,7 +19,7 @@ use wasmtime::{
 use super::permissions::judge::InstructionJudgeArc;
 use crate::{
     smartcontracts::{
-        permissions::{check_instruction_permissions, prelude::*},
+        permissions::{check_instruction_permissions, check_query_permissions, prelude::*},
         Execute, ValidQuery,
     },
     wsv::WorldStateView,
@@ -79,6 +79,31 @@ pub enum Error {
     Other(eyre::Error),
 }
 
+impl Error {
+    /// Details of the trap which aborted execution, if any
+    pub fn trap(&self) -> Option<WasmTrap> {
+        let trap = match self {
+            Self::ExportFnCall(trap) => trap,
+            _ => return None,
+        };
+
+        let backtrace = trap
+            .trace()
+            .unwrap_or_default()
+            .iter()
+            .map(|frame| match frame.func_name() {
+                Some(name) => name.to_owned(),
+                None => format!("<wasm function {}>", frame.func_index()),
+            })
+            .collect();
+
+        Some(WasmTrap {
+            code: trap.trap_code().map(|code| code.to_string()),
+            backtrace,
+        })
+    }
+}
+
 impl From<ParseError> for Error {
     fn from(err: ParseError) -> Self {
         Self::Parse(err)
@@ -136,8 +161,7 @@ impl Validator<'_> {
     }
 
     fn validate_query(&self, account_id: &AccountId, query: &QueryBox) -> Result<(), Trap> {
-        self.query_judge
-            .judge(account_id, query, self.wsv)
+        check_query_permissions(account_id, query, self.wsv, self.query_judge.as_ref())
             .map_err(Trap::new)
     }
 }
@@ -454,6 +478,8 @@ impl<'wrld> Runtime<'wrld> {
     /// - if instructions failed to validate, but queries are permitted
     /// - if instruction limits are not obeyed
     /// - if execution of the smartcontract fails (check ['execute'])
+    ///
+    /// Returns the amount of fuel consumed.
     pub fn validate(
         &mut self,
         wsv: &WorldStateView,
@@ -462,7 +488,7 @@ impl<'wrld> Runtime<'wrld> {
         max_instruction_count: u64,
         instruction_judge: InstructionJudgeArc,
         query_judge: QueryJudgeArc,
-    ) -> Result<(), Error> {
+    ) -> Result<u64, Error> {
         let state = State::new(wsv, account_id.clone(), self.config).with_validator(
             max_instruction_count,
             instruction_judge,
@@ -489,7 +515,7 @@ impl<'wrld> Runtime<'wrld> {
         event: Event,
     ) -> Result<(), Error> {
         let state = State::new(wsv, account_id.clone(), self.config).with_triggering_event(event);
-        self.execute_with_state(bytes, state)
+        self.execute_with_state(bytes, state).map(|_fuel| ())
     }
 
     /// Executes the given wasm smartcontract
@@ -501,26 +527,31 @@ impl<'wrld> Runtime<'wrld> {
     /// - if unable to find expected exports(main, memory, allocator)
     /// - if unable to write data to the smart contract memory
     /// - if the execution of the smartcontract fails
+    ///
+    /// Returns the amount of fuel consumed.
     pub fn execute(
         &mut self,
         wsv: &WorldStateView,
         account_id: AccountId,
         bytes: impl AsRef<[u8]>,
-    ) -> Result<(), Error> {
+    ) -> Result<u64, Error> {
         let state = State::new(wsv, account_id, self.config);
         self.execute_with_state(bytes, state)
     }
 
-    fn execute_with_state(&mut self, bytes: impl AsRef<[u8]>, state: State) -> Result<(), Error> {
+    fn execute_with_state(&mut self, bytes: impl AsRef<[u8]>, state: State) -> Result<u64, Error> {
         let mut store = self.create_store(state)?;
         let smart_contract = self.create_smart_contract(&mut store, bytes)?;
 
         let main_fn = smart_contract
-            .get_typed_func(&mut store, export::WASM_MAIN_FN_NAME)
+            .get_typed_func::<(), (), _>(&mut store, export::WASM_MAIN_FN_NAME)
             .map_err(Error::ExportNotFound)?;
 
         // NOTE: This function takes ownership of the pointer
-        main_fn.call(&mut store, ()).map_err(Error::ExportFnCall)
+        main_fn.call(&mut store, ()).map_err(Error::ExportFnCall)?;
+
+        // Fuel consumption is always enabled in `create_config`
+        Ok(store.fuel_consumed().unwrap_or(0))
     }
 
     /// Decode object from the given `memory` at the given `offset` with the given `len`
@@ -13,7 +13,7 @@
     clippy::std_instead_of_alloc,
     clippy::arithmetic
 )]
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use eyre::{Result, WrapErr};
 use iroha_crypto::SignaturesOf;
@@ -21,36 +21,66 @@ pub use iroha_data_model::prelude::*;
 use iroha_primitives::must_use::MustUse;
 use iroha_version::{declare_versioned_with_scale, version_with_scale};
 use parity_scale_codec::{Decode, Encode};
+use tokio::sync::broadcast;
 
 use crate::{
     prelude::*,
     smartcontracts::{
+        execute_locating_failure,
         permissions::{check_instruction_permissions, judge::InstructionJudgeArc, prelude::*},
-        wasm, Evaluate, Execute,
+        wasm, Evaluate,
     },
 };
 
+/// Maximum number of events reported by [`TransactionValidator::simulate`]
+pub const SIMULATION_EVENTS_CAPACITY: usize = 2_usize.pow(14);
+
+/// State shared by the transactions validated as parts of the same block.
+///
+/// Created by [`TransactionValidator::new_block_state`].
+pub struct BlockValidationState {
+    /// Copy of the `WorldStateView` with the previous valid transactions of the block applied,
+    /// made once per block
+    wsv: WorldStateView,
+    /// Sequence numbers expected after the previous valid transactions of the block
+    next_sequence_numbers: BTreeMap<AccountId, u32>,
+    /// The block being validated
+    block: BlockContext,
+}
+
+/// Height and timestamp of the block which a transaction is validated for,
+/// exposed to its expressions, see [`WorldStateView::with_block_context`].
+#[derive(Debug, Clone, Copy)]
+struct BlockContext {
+    height: u64,
+    timestamp: u128,
+}
+
 /// Used to validate transaction and thus move transaction lifecycle forward
 ///
 /// Permission validation is skipped for genesis.
 #[derive(Clone)]
 pub struct TransactionValidator {
     transaction_limits: TransactionLimits,
+    chain_id: ChainId,
     instruction_judge: InstructionJudgeArc,
     query_judge: QueryJudgeArc,
     wsv: Arc<WorldStateView>,
 }
 
 impl TransactionValidator {
-    /// Construct [`TransactionValidator`]
+    /// Construct [`TransactionValidator`] which accepts only the
+    /// transactions meant for the chain with `chain_id`
     pub fn new(
         transaction_limits: TransactionLimits,
+        chain_id: ChainId,
         instruction_judge: InstructionJudgeArc,
         query_judge: QueryJudgeArc,
         wsv: Arc<WorldStateView>,
     ) -> Self {
         Self {
             transaction_limits,
+            chain_id,
             instruction_judge,
             query_judge,
             wsv,
@@ -69,7 +99,86 @@ impl TransactionValidator {
         tx: AcceptedTransaction,
         is_genesis: bool,
     ) -> Result<VersionedValidTransaction, VersionedRejectedTransaction> {
-        if let Err(rejection_reason) = self.validate_internal(&tx, is_genesis) {
+        // WSV is cloned here so that instructions don't get applied to the blockchain
+        let wsv = WorldStateView::clone(&self.wsv);
+        self.validate_on_wsv(tx, is_genesis, &BTreeMap::new(), self.next_block(), &wsv)
+    }
+
+    /// Start validating the transactions of a new block at `height` with
+    /// `timestamp` with [`Self::validate_in_block`]
+    pub fn new_block_state(&self, height: u64, timestamp: u128) -> BlockValidationState {
+        BlockValidationState {
+            wsv: WorldStateView::clone(&self.wsv),
+            next_sequence_numbers: BTreeMap::new(),
+            block: BlockContext { height, timestamp },
+        }
+    }
+
+    /// The block which a transaction validated outside of a block is expected to get into
+    fn next_block(&self) -> BlockContext {
+        BlockContext {
+            height: self.wsv.height().saturating_add(1),
+            timestamp: current_time().as_millis(),
+        }
+    }
+
+    /// Same as [`Self::validate`], but `tx` is validated on top of the
+    /// previous valid transactions of the same block, as it will be applied.
+    /// E.g. the sequence number of `tx` is expected to follow theirs and
+    /// fees are paid from the balance left after them.
+    ///
+    /// The changes of `tx` are undone if it's rejected, so it's
+    /// not charged a fee, even if its execution consumed fuel.
+    ///
+    /// # Errors
+    /// Fails if validation of instruction fails or if the sequence number is out of order.
+    pub fn validate_in_block(
+        &self,
+        tx: AcceptedTransaction,
+        is_genesis: bool,
+        state: &mut BlockValidationState,
+    ) -> Result<VersionedValidTransaction, VersionedRejectedTransaction> {
+        // Recorded so that a rejected transaction doesn't affect the block state
+        state.wsv.start_transaction();
+        let tx = self
+            .validate_on_wsv(
+                tx,
+                is_genesis,
+                &state.next_sequence_numbers,
+                state.block,
+                &state.wsv,
+            )
+            .map_err(|tx| {
+                state.wsv.rollback_transaction();
+                tx
+            })?;
+        state.wsv.commit_transaction();
+
+        // Can't overflow, as the maximum sequence number is rejected above
+        if let Some(next) = tx
+            .payload()
+            .sequence_number
+            .and_then(|sequence_number| sequence_number.checked_add(1))
+        {
+            state
+                .next_sequence_numbers
+                .insert(tx.payload().account_id.clone(), next);
+        }
+        Ok(tx)
+    }
+
+    fn validate_on_wsv(
+        &self,
+        tx: AcceptedTransaction,
+        is_genesis: bool,
+        next_sequence_numbers: &BTreeMap<AccountId, u32>,
+        block: BlockContext,
+        wsv: &WorldStateView,
+    ) -> Result<VersionedValidTransaction, VersionedRejectedTransaction> {
+        if let Err(rejection_reason) = self
+            .validate_sequence_number(&tx, next_sequence_numbers)
+            .and_then(|()| self.validate_with_wsv(&tx, is_genesis, block, wsv))
+        {
             return Err(RejectedTransaction {
                 payload: tx.payload,
                 signatures: tx.signatures,
@@ -99,67 +208,164 @@ impl TransactionValidator {
         Ok(())
     }
 
+    /// Execute `tx` against a copy of the `WorldStateView` without
+    /// queuing or committing it, collecting the data events it would
+    /// emit, the fuel it would consume and the changes it would make.
+    ///
+    /// Only the last [`SIMULATION_EVENTS_CAPACITY`] events are reported.
+    ///
+    pub fn simulate(&self, tx: &AcceptedTransaction) -> TransactionSimulation {
+        let (events_sender, mut events_receiver) = broadcast::channel(SIMULATION_EVENTS_CAPACITY);
+        let wsv = self.wsv.clone_with_events_sender(events_sender);
+
+        let fuel_consumed = match self
+            .validate_sequence_number(tx, &BTreeMap::new())
+            .and_then(|()| self.validate_with_wsv(tx, false, self.next_block(), &wsv))
+        {
+            Ok(fuel_consumed) => fuel_consumed,
+            Err(rejection_reason) => return TransactionSimulation::rejected(rejection_reason),
+        };
+
+        let mut events = Vec::new();
+        let mut lagged = false;
+        loop {
+            match events_receiver.try_recv() {
+                Ok(Event::Data(event)) => events.push(event),
+                Ok(_) => {}
+                Err(broadcast::error::TryRecvError::Lagged(_)) => lagged = true,
+                Err(_) => break,
+            }
+        }
+        let state_diff = if lagged {
+            self.wsv.full_state_diff(&wsv)
+        } else {
+            self.wsv.state_diff(&wsv, &events)
+        };
+
+        TransactionSimulation {
+            rejection_reason: None,
+            events,
+            fuel_consumed,
+            state_diff,
+        }
+    }
+
     fn validate_internal(
         &self,
         tx: &AcceptedTransaction,
         is_genesis: bool,
     ) -> Result<(), TransactionRejectionReason> {
+        // WSV is cloned here so that instructions don't get applied to the blockchain
+        // Therefore, this instruction execution validates before actually executing
+        let wsv = WorldStateView::clone(&self.wsv);
+
+        self.validate_with_wsv(tx, is_genesis, self.next_block(), &wsv)
+            .map(|_fuel| ())
+    }
+
+    /// Validate `tx` by executing it on `wsv` as a part of `block`,
+    /// returning the consumed WASM fuel
+    fn validate_with_wsv(
+        &self,
+        tx: &AcceptedTransaction,
+        is_genesis: bool,
+        block: BlockContext,
+        wsv: &WorldStateView,
+    ) -> Result<u64, TransactionRejectionReason> {
         let account_id = &tx.payload.account_id;
+        // Checked here as well as in the queue, as the transactions of a block
+        // proposed by another peer don't go through the queue of this one
+        if tx.payload.chain_id != self.chain_id {
+            return Err(TransactionRejectionReason::ChainIdMismatch);
+        }
         self.validate_signatures(tx, is_genesis)?;
 
         // Sanity check - should have been checked by now
         tx.check_limits(&self.transaction_limits)?;
 
-        // WSV is cloned here so that instructions don't get applied to the blockchain
-        // Therefore, this instruction execution validates before actually executing
-        let wsv = WorldStateView::clone(&self.wsv);
-
         if !wsv
             .domain(&account_id.domain_id)
             .map_err(|_e| {
-                TransactionRejectionReason::NotPermitted(NotPermittedFail {
-                    reason: "Domain not found in Iroha".to_owned(),
-                })
+                TransactionRejectionReason::NotPermitted(NotPermittedFail::new(
+                    "Domain not found in Iroha",
+                ))
             })?
             .contains_account(account_id)
         {
-            return Err(TransactionRejectionReason::NotPermitted(NotPermittedFail {
-                reason: "Account not found in Iroha".to_owned(),
-            }));
+            return Err(TransactionRejectionReason::NotPermitted(
+                NotPermittedFail::new("Account not found in Iroha"),
+            ));
         }
 
+        let fuel = wsv.with_block_context(block.height, block.timestamp, || {
+            wsv.with_transaction_context(account_id.clone(), tx.hash().into(), || {
+                self.execute_with_wsv(tx, is_genesis, wsv)
+            })
+        })?;
+
+        wsv.pay_transaction_fee(account_id, &tx.payload.instructions, fuel)
+            .map_err(|reason| FeePaymentFail {
+                reason: reason.to_string(),
+            })
+            .map_err(TransactionRejectionReason::FeePayment)?;
+
+        Ok(fuel)
+    }
+
+    /// Execute the instructions or the smart contract of `tx` on `wsv`,
+    /// returning the consumed WASM fuel
+    fn execute_with_wsv(
+        &self,
+        tx: &AcceptedTransaction,
+        is_genesis: bool,
+        wsv: &WorldStateView,
+    ) -> Result<u64, TransactionRejectionReason> {
+        let account_id = &tx.payload.account_id;
         match &tx.payload.instructions {
             Executable::Instructions(instructions) => {
-                for instruction in instructions {
+                for (index, instruction) in (0_u32..).zip(instructions) {
                     if !is_genesis {
                         check_instruction_permissions(
                             account_id,
                             instruction,
                             self.instruction_judge.as_ref(),
                             self.query_judge.as_ref(),
-                            &wsv,
-                        )?
+                            wsv,
+                        )
+                        .map_err(|reason| match reason {
+                            TransactionRejectionReason::NotPermitted(fail) => {
+                                TransactionRejectionReason::NotPermitted(NotPermittedFail {
+                                    instruction_index: Some(index),
+                                    ..fail
+                                })
+                            }
+                            other => other,
+                        })?
                     }
 
-                    instruction
-                        .clone()
-                        .execute(account_id.clone(), &wsv)
-                        .map_err(|reason| InstructionExecutionFail {
-                            instruction: instruction.clone(),
-                            reason: reason.to_string(),
+                    execute_locating_failure(instruction, account_id, wsv)
+                        .map_err(|failure| InstructionExecutionFail {
+                            instruction: failure.instruction,
+                            location: InstructionLocation {
+                                index,
+                                nested_path: failure.nested_path,
+                            },
+                            reason: failure.error.to_string(),
                         })
                         .map_err(TransactionRejectionReason::InstructionExecution)?;
                 }
+                Ok(0)
             }
             Executable::Wasm(bytes) => {
                 let mut wasm_runtime = wasm::Runtime::new()
                     .map_err(|reason| WasmExecutionFail {
                         reason: reason.to_string(),
+                        trap: None,
                     })
                     .map_err(TransactionRejectionReason::WasmExecution)?;
                 wasm_runtime
                     .validate(
-                        &wsv,
+                        wsv,
                         account_id,
                         bytes,
                         self.transaction_limits.max_instruction_number,
@@ -167,12 +373,35 @@ impl TransactionValidator {
                         Arc::clone(&self.query_judge),
                     )
                     .map_err(|reason| WasmExecutionFail {
+                        trap: reason.trap(),
                         reason: reason.to_string(),
                     })
-                    .map_err(TransactionRejectionReason::WasmExecution)?;
+                    .map_err(TransactionRejectionReason::WasmExecution)
             }
         }
+    }
 
+    fn validate_sequence_number(
+        &self,
+        tx: &AcceptedTransaction,
+        next_sequence_numbers: &BTreeMap<AccountId, u32>,
+    ) -> Result<(), TransactionRejectionReason> {
+        let actual = match tx.payload.sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => return Ok(()),
+        };
+        let account_id = &tx.payload.account_id;
+        let expected = next_sequence_numbers
+            .get(account_id)
+            .copied()
+            .unwrap_or_else(|| self.wsv.next_sequence_number(account_id));
+
+        // The maximum is reserved, as there would be no sequence number after it
+        if actual != expected || actual == u32::MAX {
+            return Err(TransactionRejectionReason::SequenceNumber(
+                SequenceNumberFail { expected, actual },
+            ));
+        }
         Ok(())
     }
 
@@ -417,9 +646,11 @@ mod tests {
     use std::str::FromStr as _;
 
     use eyre::Result;
+    use iroha_crypto::KeyPair;
     use iroha_data_model::transaction::DEFAULT_MAX_INSTRUCTION_NUMBER;
 
     use super::*;
+    use crate::{smartcontracts::Execute, wsv::World, PeersIds};
 
     #[test]
     fn transaction_not_accepted_max_instruction_number() {
@@ -455,4 +686,177 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn fees_are_validated_cumulatively_within_block() -> Result<()> {
+        let alice_keys = KeyPair::generate()?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let treasury_id = AccountId::from_str("treasury@wonderland")?;
+        let fee_definition_id = AssetDefinitionId::from_str("xor#wonderland")?;
+        let mut domain = Domain::new(DomainId::from_str("wonderland")?).build();
+        let alice = Account::new(alice_id.clone(), [alice_keys.public_key().clone()]).build();
+        assert!(domain.add_account(alice).is_none());
+        assert!(domain
+            .add_account(Account::new(treasury_id.clone(), []).build())
+            .is_none());
+        let wsv = Arc::new(WorldStateView::new(World::with([domain], PeersIds::new())));
+
+        RegisterBox::new(AssetDefinition::quantity(fee_definition_id.clone()))
+            .execute(alice_id.clone(), &wsv)?;
+        let alice_fee_asset_id = AssetId::new(fee_definition_id.clone(), alice_id.clone());
+        MintBox::new(3_u32, IdBox::AssetId(alice_fee_asset_id.clone()))
+            .execute(alice_id.clone(), &wsv)?;
+        let fee_schedule = FeeSchedule::new(fee_definition_id, treasury_id, 2);
+        SetParameterBox::new(Parameter::TransactionFees(fee_schedule))
+            .execute(alice_id.clone(), &wsv)?;
+
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let validator = TransactionValidator::new(
+            limits,
+            ChainId::default(),
+            Arc::new(AllowAll::new()),
+            Arc::new(AllowAll::new()),
+            Arc::clone(&wsv),
+        );
+        let accept = |ttl_ms| -> Result<AcceptedTransaction> {
+            let tx = Transaction::new(alice_id.clone(), Vec::<Instruction>::new().into(), ttl_ms)
+                .sign(alice_keys.clone())?;
+            AcceptedTransaction::from_transaction(tx, &limits)
+        };
+        let first = accept(4000)?;
+        let second = accept(4001)?;
+
+        // Each transaction alone can pay its fee
+        assert!(validator.validate(second.clone(), false).is_ok());
+
+        // But not both of them in the same block
+        let mut block_state = validator.new_block_state(1, 0);
+        assert!(validator
+            .validate_in_block(first, false, &mut block_state)
+            .is_ok());
+        let rejected = validator
+            .validate_in_block(second, false, &mut block_state)
+            .expect_err("Not enough funds to pay the fee of the second transaction");
+        assert!(matches!(
+            rejected.as_v1().rejection_reason,
+            TransactionRejectionReason::FeePayment(_)
+        ));
+
+        // Validation doesn't touch the actual state
+        assert_eq!(
+            *wsv.asset(&alice_fee_asset_id)?.value(),
+            AssetValue::Quantity(3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_transaction_is_rolled_back_within_block() -> Result<()> {
+        let alice_keys = KeyPair::generate()?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let rose_definition_id = AssetDefinitionId::from_str("rose#wonderland")?;
+        let mut domain = Domain::new(DomainId::from_str("wonderland")?).build();
+        let alice = Account::new(alice_id.clone(), [alice_keys.public_key().clone()]).build();
+        assert!(domain.add_account(alice).is_none());
+        let wsv = Arc::new(WorldStateView::new(World::with([domain], PeersIds::new())));
+        RegisterBox::new(AssetDefinition::quantity(rose_definition_id.clone()))
+            .execute(alice_id.clone(), &wsv)?;
+        let alice_rose_id = AssetId::new(rose_definition_id, alice_id.clone());
+
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let validator = TransactionValidator::new(
+            limits,
+            ChainId::default(),
+            Arc::new(AllowAll::new()),
+            Arc::new(AllowAll::new()),
+            Arc::clone(&wsv),
+        );
+        let accept = |instructions: Vec<Instruction>| -> Result<AcceptedTransaction> {
+            let tx = Transaction::new(alice_id.clone(), instructions.into(), 4000)
+                .sign(alice_keys.clone())?;
+            AcceptedTransaction::from_transaction(tx, &limits)
+        };
+        let register_domain: Instruction =
+            RegisterBox::new(Domain::new(DomainId::from_str("looking_glass")?)).into();
+        let mint_rose: Instruction =
+            MintBox::new(1_u32, IdBox::AssetId(alice_rose_id.clone())).into();
+        let fail: Instruction = FailBox {
+            message: "Will fail".to_owned(),
+        }
+        .into();
+
+        let mut block_state = validator.new_block_state(1, 0);
+        assert!(validator
+            .validate_in_block(
+                accept(vec![mint_rose.clone(), register_domain.clone(), fail])?,
+                false,
+                &mut block_state,
+            )
+            .is_err());
+        // Would be a repeated registration if the rejected transaction had been kept
+        assert!(validator
+            .validate_in_block(
+                accept(vec![register_domain, mint_rose])?,
+                false,
+                &mut block_state,
+            )
+            .is_ok());
+        assert_eq!(
+            *block_state.wsv.asset(&alice_rose_id)?.value(),
+            AssetValue::Quantity(1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn transaction_for_another_chain_is_rejected() -> Result<()> {
+        let alice_keys = KeyPair::generate()?;
+        let alice_id = AccountId::from_str("alice@wonderland")?;
+        let mut domain = Domain::new(DomainId::from_str("wonderland")?).build();
+        let account = Account::new(alice_id.clone(), [alice_keys.public_key().clone()]).build();
+        assert!(domain.add_account(account).is_none());
+        let wsv = Arc::new(WorldStateView::new(World::with([domain], PeersIds::new())));
+
+        let limits = TransactionLimits {
+            max_instruction_number: 4096,
+            max_wasm_size_bytes: 0,
+        };
+        let validator = TransactionValidator::new(
+            limits,
+            ChainId::new("wonderland"),
+            Arc::new(AllowAll::new()),
+            Arc::new(AllowAll::new()),
+            wsv,
+        );
+        let accept = |chain_id| -> Result<AcceptedTransaction> {
+            let tx = Transaction::new(alice_id.clone(), Vec::<Instruction>::new().into(), 4000)
+                .with_chain_id(chain_id)
+                .sign(alice_keys.clone())?;
+            AcceptedTransaction::from_transaction(tx, &limits)
+        };
+
+        assert!(validator
+            .validate(accept(ChainId::new("wonderland"))?, false)
+            .is_ok());
+        // Also in a block, e.g. one proposed by a leader which skipped the check
+        let mut block_state = validator.new_block_state(1, 0);
+        let rejected = validator
+            .validate_in_block(
+                accept(ChainId::new("looking_glass"))?,
+                false,
+                &mut block_state,
+            )
+            .expect_err("Transaction is meant for another chain");
+        assert_eq!(
+            rejected.as_v1().rejection_reason,
+            TransactionRejectionReason::ChainIdMismatch
+        );
+        Ok(())
+    }
 }
@@ -7,33 +7,72 @@
     clippy::arithmetic
 )]
 
-use std::{convert::Infallible, fmt::Debug, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::Infallible,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::Duration,
+};
 
 use dashmap::{
     mapref::one::{Ref as DashMapRef, RefMut as DashMapRefMut},
-    DashSet,
+    DashMap, DashSet,
 };
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use getset::Getters;
 use iroha_config::wsv::Configuration;
 use iroha_crypto::HashOf;
-use iroha_data_model::prelude::*;
+use iroha_data_model::{
+    expression::{
+        BLOCK_HEIGHT_VALUE, BLOCK_TIMESTAMP_VALUE, TRANSACTION_CREATOR_VALUE,
+        TRANSACTION_HASH_VALUE, TRIGGERING_EVENT_ORIGIN_VALUE, TRIGGER_AUTHORITY_VALUE,
+        TRIGGER_CALLER_VALUE,
+    },
+    prelude::*,
+};
 use iroha_logger::prelude::*;
 use iroha_primitives::small::SmallVec;
 use iroha_telemetry::metrics::Metrics;
 use tokio::{sync::broadcast, task};
 
 use crate::{
+    account_statement::AccountStatements,
     block::Chain,
+    metadata_index::MetadataIndex,
     prelude::*,
     send_event,
     smartcontracts::{
-        isi::{query::Error as QueryError, Error},
-        wasm, Execute, FindError,
+        isi::{query::Error as QueryError, Error, MathError},
+        wasm, Evaluate, Execute, FindError,
     },
     DomainsMap, EventsSender, PeersIds,
 };
 
+thread_local! {
+    /// Creator and hash of the transaction being executed by the current thread,
+    /// see [`WorldStateView::with_transaction_context`].
+    static CURRENT_TRANSACTION: core::cell::RefCell<Option<(AccountId, Hash)>> =
+        core::cell::RefCell::new(None);
+}
+
+thread_local! {
+    /// Height and timestamp of the block being validated or applied by the current
+    /// thread, see [`WorldStateView::with_block_context`].
+    static CURRENT_BLOCK: core::cell::Cell<Option<(u64, u128)>> = core::cell::Cell::new(None);
+}
+
+fn current_transaction() -> Option<(AccountId, Hash)> {
+    CURRENT_TRANSACTION.with(|current| current.borrow().clone())
+}
+
+fn current_block() -> Option<(u64, u128)> {
+    CURRENT_BLOCK.with(core::cell::Cell::get)
+}
+
 /// Sender type of the new block notification channel
 pub type NewBlockNotificationSender = tokio::sync::watch::Sender<()>;
 /// Receiver type of the new block notification channel
@@ -43,9 +82,8 @@ pub type NewBlockNotificationReceiver = tokio::sync::watch::Receiver<()>;
 /// For example registration of domain, will have this as an ISI target.
 #[derive(Debug, Default, Clone, Getters)]
 pub struct World {
-    /// Iroha parameters.
-    /// TODO: Use this field
-    _parameters: Vec<Parameter>,
+    /// Iroha on-chain parameters. At most one parameter of each kind is stored.
+    pub(crate) parameters: DashSet<Parameter>,
     /// Identifications of discovered trusted peers.
     pub(crate) trusted_peers_ids: PeersIds,
     /// Registered domains.
@@ -58,6 +96,8 @@ pub struct World {
     pub(crate) permission_token_definitions: crate::PermissionTokenDefinitionsMap,
     /// Triggers
     pub(crate) triggers: TriggerSet,
+    /// Sequence numbers expected in the next transactions of accounts.
+    pub(crate) account_sequence_numbers: DashMap<AccountId, u32>,
 }
 
 impl World {
@@ -102,6 +142,59 @@ pub struct WorldStateView {
     new_block_notifier: Arc<NewBlockNotificationSender>,
     /// Transmitter to broadcast [`WorldStateView`]-related events.
     events_sender: EventsSender,
+    /// Indexes of the metadata keys listed in the configuration.
+    ///
+    /// Not copied along with the world, but rebuilt from it on the first
+    /// lookup, as most copies made for validation never look anything up.
+    metadata_index: Mutex<Option<MetadataIndex>>,
+    /// History of the changes of account asset quantities.
+    ///
+    /// Shared with the copies of [`Self`] made for validation, which
+    /// only read the history of the committed blocks and don't record to it.
+    account_statements: Arc<Mutex<AccountStatements>>,
+    /// Whether the changes are recorded to [`Self::account_statements`],
+    /// i.e. whether this isn't a copy.
+    records_account_statements: bool,
+    /// Data events produced by the committed blocks, by block height.
+    block_data_events: Arc<DashMap<u64, Vec<DataEvent>>>,
+    /// Data events produced by the block being applied.
+    applied_block_data_events: Mutex<Option<Vec<DataEvent>>>,
+    /// Incremented when a block starts and finishes being applied,
+    /// so it's odd while a block is being applied.
+    apply_epoch: AtomicU64,
+    /// Notifies [`Self::consistent_read`] when a block is applied.
+    apply_finished: tokio::sync::watch::Sender<()>,
+    /// Changes of the transaction being validated, see [`Self::start_transaction`].
+    ///
+    /// Not copied along with the world.
+    journal: Mutex<Option<Journal>>,
+}
+
+/// Parts of the [`World`] as they were before the transaction being
+/// validated changed them, restored by [`WorldStateView::rollback_transaction`].
+///
+/// Only the first change of each part is recorded.
+#[derive(Debug, Default)]
+struct Journal {
+    /// The whole world, taken on a change which isn't recorded more precisely,
+    /// e.g. registration of a domain. Makes recording the later changes unnecessary.
+    world: Option<World>,
+    /// Changed domains, `None` if there was no such domain
+    domains: BTreeMap<DomainId, Option<Domain>>,
+    /// Changed permission tokens of accounts, `None` if an account had none
+    account_permission_tokens: BTreeMap<AccountId, Option<Permissions>>,
+    /// On-chain parameters
+    parameters: Option<Vec<Parameter>>,
+}
+
+/// Marks a block as being applied to the [`WorldStateView`] until dropped.
+struct ApplyEpochGuard<'wsv>(&'wsv WorldStateView);
+
+impl Drop for ApplyEpochGuard<'_> {
+    fn drop(&mut self) {
+        self.0.apply_epoch.fetch_add(1, Ordering::SeqCst);
+        self.0.apply_finished.send_replace(());
+    }
 }
 
 impl Default for WorldStateView {
@@ -116,12 +209,20 @@ impl Clone for WorldStateView {
     fn clone(&self) -> Self {
         Self {
             world: Clone::clone(&self.world),
-            config: self.config,
+            config: self.config.clone(),
             blocks: Arc::clone(&self.blocks),
             transactions: self.transactions.clone(),
             metrics: Arc::clone(&self.metrics),
             new_block_notifier: Arc::clone(&self.new_block_notifier),
             events_sender: self.events_sender.clone(),
+            metadata_index: Mutex::new(None),
+            account_statements: Arc::clone(&self.account_statements),
+            records_account_statements: false,
+            block_data_events: Arc::clone(&self.block_data_events),
+            applied_block_data_events: Mutex::new(None),
+            apply_epoch: AtomicU64::new(self.apply_epoch.load(Ordering::SeqCst)),
+            apply_finished: tokio::sync::watch::channel(()).0,
+            journal: Mutex::new(None),
         }
     }
 }
@@ -194,6 +295,7 @@ impl WorldStateView {
         account: &<Account as Identifiable>::Id,
         token: PermissionToken,
     ) -> bool {
+        self.record_account_permission_tokens(account);
         // `match` here instead of `map_or_else` to avoid cloning token into each closure
         match self.world.account_permission_tokens.get_mut(account) {
             None => {
@@ -215,6 +317,7 @@ impl WorldStateView {
         account: &<Account as Identifiable>::Id,
         token: &PermissionToken,
     ) -> bool {
+        self.record_account_permission_tokens(account);
         self.world
             .account_permission_tokens
             .get_mut(account)
@@ -238,11 +341,49 @@ impl WorldStateView {
         }
     }
 
-    fn process_executable(&self, executable: &Executable, authority: AccountId) -> Result<()> {
-        match executable {
-            Executable::Instructions(instructions) => {
-                self.process_instructions(instructions.iter().cloned(), &authority)
+    /// Evaluate trigger `condition` with [`TRIGGER_AUTHORITY_VALUE`] and, depending on
+    /// the triggering `event`, [`TRIGGERING_EVENT_ORIGIN_VALUE`] or [`TRIGGER_CALLER_VALUE`]
+    /// bound in the context
+    fn evaluate_trigger_condition(
+        &self,
+        condition: &EvaluatesTo<bool>,
+        action: &dyn ActionTrait,
+        event: &Event,
+    ) -> Result<bool> {
+        let mut context = Context::new();
+        context.insert(
+            TRIGGER_AUTHORITY_VALUE.to_owned(),
+            IdBox::from(action.technical_account().clone()).into(),
+        );
+        match event {
+            Event::Data(event) => {
+                context.insert(
+                    TRIGGERING_EVENT_ORIGIN_VALUE.to_owned(),
+                    event.origin_id().into(),
+                );
+            }
+            Event::ExecuteTrigger(event) => {
+                context.insert(
+                    TRIGGER_CALLER_VALUE.to_owned(),
+                    IdBox::from(event.authority.clone()).into(),
+                );
             }
+            Event::Pipeline(_) | Event::Time(_) => {}
+        }
+
+        condition
+            .evaluate(self, &context)
+            .wrap_err("Failed to evaluate trigger condition")
+    }
+
+    /// Execute `executable` on behalf of `authority`.
+    ///
+    /// Returns the amount of fuel consumed by `WebAssembly` smartcontract (zero for instructions).
+    fn process_executable(&self, executable: &Executable, authority: AccountId) -> Result<u64> {
+        match executable {
+            Executable::Instructions(instructions) => self
+                .process_instructions(instructions.iter().cloned(), &authority)
+                .map(|()| 0),
             Executable::Wasm(bytes) => {
                 let mut wasm_runtime =
                     wasm::Runtime::from_configuration(self.config.wasm_runtime_config)?;
@@ -283,6 +424,9 @@ impl WorldStateView {
     #[log(skip(self, block))]
     #[allow(clippy::expect_used)]
     pub async fn apply(&self, block: VersionedCommittedBlock) -> Result<()> {
+        let _apply_epoch_guard = self.begin_apply();
+        *self.applied_block_data_events() = Some(Vec::new());
+
         let time_event = self.create_time_event(block.as_v1())?;
         self.produce_event(Event::Time(time_event));
 
@@ -290,10 +434,23 @@ impl WorldStateView {
 
         self.world.triggers.handle_time_event(time_event);
 
-        let res = self
+        let (height, timestamp) = (block.header().height, block.header().timestamp);
+        let (trigger_events, res) = self
             .world
             .triggers
-            .inspect_matched(|action, event| -> Result<()> { self.process_trigger(action, event) })
+            .inspect_matched(
+                height,
+                |condition, action, event| -> Result<bool> {
+                    self.with_block_context(height, timestamp, || {
+                        self.evaluate_trigger_condition(condition, action, event)
+                    })
+                },
+                |action, event| -> Result<()> {
+                    self.with_block_context(height, timestamp, || {
+                        self.process_trigger(action, event)
+                    })
+                },
+            )
             .await;
 
         if let Err(errors) = res {
@@ -302,7 +459,18 @@ impl WorldStateView {
                 "The following errors have occurred during trigger execution"
             );
         }
+        for event in trigger_events {
+            let event = DataEvent::Trigger(event);
+            self.world.triggers.handle_data_event(event.clone());
+            self.produce_event(event);
+        }
 
+        let data_events = self.applied_block_data_events().take().unwrap_or_default();
+        let height = block.header().height;
+        self.block_data_events.insert(height, data_events);
+        if let Some(expired_height) = height.checked_sub(self.config.data_events_retention_blocks) {
+            self.block_data_events.remove(&expired_height);
+        }
         self.blocks.push(block);
         self.block_commit_metrics_update_callback();
         self.new_block_notifier.send_replace(());
@@ -313,6 +481,43 @@ impl WorldStateView {
         Ok(())
     }
 
+    fn begin_apply(&self) -> ApplyEpochGuard<'_> {
+        self.apply_epoch.fetch_add(1, Ordering::SeqCst);
+        ApplyEpochGuard(self)
+    }
+
+    /// Wait until no block is being applied and return the epoch it left.
+    async fn applied_epoch(&self) -> u64 {
+        // Subscribed before checking, so that the end of the apply isn't missed
+        let mut apply_finished = self.apply_finished.subscribe();
+        loop {
+            let epoch = self.apply_epoch.load(Ordering::SeqCst);
+            if epoch % 2 == 0 {
+                return epoch;
+            }
+            #[allow(clippy::expect_used)]
+            apply_finished
+                .changed()
+                .await
+                .expect("The sender is kept in `self`");
+        }
+    }
+
+    /// Evaluate `f` on a consistent state of the world, i.e. with no
+    /// block being applied during the evaluation.
+    ///
+    /// If the evaluation overlaps with applying a block, it's repeated
+    /// once the block is applied, so it never fails because of it.
+    pub async fn consistent_read<T>(&self, f: impl Fn(&Self) -> T) -> T {
+        loop {
+            let epoch = self.applied_epoch().await;
+            let output = f(self);
+            if self.apply_epoch.load(Ordering::SeqCst) == epoch {
+                return output;
+            }
+        }
+    }
+
     /// Create time event using previous and current blocks
     fn create_time_event(&self, block: &CommittedBlock) -> Result<TimeEvent> {
         let prev_interval = self
@@ -334,7 +539,7 @@ impl WorldStateView {
             Duration::from_millis(block.header.consensus_estimation),
         );
 
-        Ok(TimeEvent::new(prev_interval, interval))
+        Ok(TimeEvent::new(block.header.height, prev_interval, interval))
     }
 
     /// Execute `block` transactions and store their hashes as well as
@@ -345,10 +550,34 @@ impl WorldStateView {
     async fn execute_transactions(&self, block: &CommittedBlock) -> Result<()> {
         // TODO: Should this block panic instead?
         for tx in &block.transactions {
-            self.process_executable(
+            let fuel =
+                self.with_block_context(block.header.height, block.header.timestamp, || {
+                    self.with_transaction_context(
+                        tx.payload().account_id.clone(),
+                        tx.hash().into(),
+                        || {
+                            self.process_executable(
+                                &tx.as_v1().payload.instructions,
+                                tx.payload().account_id.clone(),
+                            )
+                        },
+                    )
+                })?;
+            self.pay_transaction_fee(
+                &tx.payload().account_id,
                 &tx.as_v1().payload.instructions,
-                tx.payload().account_id.clone(),
+                fuel,
             )?;
+            // Can't overflow, as the maximum sequence number is rejected during validation
+            if let Some(next) = tx
+                .payload()
+                .sequence_number
+                .and_then(|sequence_number| sequence_number.checked_add(1))
+            {
+                self.world
+                    .account_sequence_numbers
+                    .insert(tx.payload().account_id.clone(), next);
+            }
             self.transactions.insert(tx.hash());
             task::yield_now().await;
         }
@@ -375,8 +604,41 @@ impl WorldStateView {
     }
 
     /// Send [`Event`]s to known subscribers.
+    ///
+    /// Data events of the block being applied are also recorded, so
+    /// that they can be replayed with [`Self::block_data_events`].
     fn produce_event(&self, event: impl Into<Event>) {
-        send_event(&self.events_sender, event.into());
+        let event = event.into();
+        if let Event::Data(data_event) = &event {
+            if let Some(data_events) = self.applied_block_data_events().as_mut() {
+                data_events.push(data_event.clone());
+            }
+        }
+        send_event(&self.events_sender, event);
+    }
+
+    fn applied_block_data_events(&self) -> MutexGuard<'_, Option<Vec<DataEvent>>> {
+        self.applied_block_data_events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Height of the earliest committed block whose data events are
+    /// still kept, see [`Configuration::data_events_retention_blocks`].
+    pub fn earliest_data_events_height(&self) -> u64 {
+        (self.height() + 1)
+            .saturating_sub(self.config.data_events_retention_blocks)
+            .max(1)
+    }
+
+    /// Data events produced by applying the committed block of the given `height`,
+    /// in the order they were produced.
+    ///
+    /// Returns `None` if there is no such block or its data events are no longer kept.
+    pub fn block_data_events(&self, height: u64) -> Option<Vec<DataEvent>> {
+        self.block_data_events
+            .get(&height)
+            .map(|data_events| data_events.value().clone())
     }
 
     /// Tries to get asset or inserts new with `default_asset_value`.
@@ -478,8 +740,30 @@ impl WorldStateView {
     pub fn modify_world(
         &self,
         f: impl FnOnce(&World) -> Result<WorldEvent, Error>,
+    ) -> Result<(), Error> {
+        self.record_change(|journal, world| journal.world = Some(world.clone()));
+        self.change_world(f)
+    }
+
+    /// Same as [`Self::modify_world`], but the change isn't recorded
+    /// for [`Self::rollback_transaction`], which is up to the caller.
+    fn change_world(
+        &self,
+        f: impl FnOnce(&World) -> Result<WorldEvent, Error>,
     ) -> Result<(), Error> {
         let world_event = f(&self.world)?;
+        if let Some(metadata_index) = self.metadata_index().as_mut() {
+            metadata_index.handle_world_event(&world_event, &self.world);
+        }
+        if self.records_account_statements {
+            let transaction_hash = current_transaction().map(|(_, hash)| hash);
+            self.account_statements().handle_world_event(
+                &world_event,
+                &self.world,
+                self.height().saturating_add(1),
+                transaction_hash,
+            );
+        }
         let data_events: SmallVec<[DataEvent; 3]> = world_event.into();
 
         for event in data_events {
@@ -572,7 +856,13 @@ impl WorldStateView {
         id: &<Domain as Identifiable>::Id,
         f: impl FnOnce(&mut Domain) -> Result<DomainEvent, Error>,
     ) -> Result<(), Error> {
-        self.modify_world(|world| {
+        self.record_change(|journal, world| {
+            journal
+                .domains
+                .entry(id.clone())
+                .or_insert_with(|| world.domains.get(id).map(|domain| domain.clone()));
+        });
+        self.change_world(|world| {
             let mut domain = world
                 .domains
                 .get_mut(id)
@@ -601,6 +891,8 @@ impl WorldStateView {
         events_sender: EventsSender,
     ) -> Self {
         let (new_block_notifier, _) = tokio::sync::watch::channel(());
+        let metadata_index = MetadataIndex::new(&config.metadata_indexes, &world);
+        let account_statements = AccountStatements::new(&world);
 
         Self {
             world,
@@ -610,6 +902,393 @@ impl WorldStateView {
             metrics: Arc::new(Metrics::default()),
             new_block_notifier: Arc::new(new_block_notifier),
             events_sender,
+            metadata_index: Mutex::new(Some(metadata_index)),
+            account_statements: Arc::new(Mutex::new(account_statements)),
+            records_account_statements: true,
+            block_data_events: Arc::new(DashMap::new()),
+            applied_block_data_events: Mutex::new(None),
+            apply_epoch: AtomicU64::new(0),
+            apply_finished: tokio::sync::watch::channel(()).0,
+            journal: Mutex::new(None),
+        }
+    }
+
+    fn metadata_index(&self) -> MutexGuard<'_, Option<MetadataIndex>> {
+        self.metadata_index
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `f` on the metadata index, building it from the world first if it isn't yet.
+    fn with_metadata_index<T>(&self, f: impl FnOnce(&MetadataIndex) -> T) -> T {
+        let mut metadata_index = self.metadata_index();
+        f(metadata_index
+            .get_or_insert_with(|| MetadataIndex::new(&self.config.metadata_indexes, &self.world)))
+    }
+
+    /// Ids of the accounts with `value` under `key` in their metadata.
+    ///
+    /// Returns `None` if `key` isn't indexed, see [`Configuration::metadata_indexes`].
+    pub fn account_ids_by_metadata(&self, key: &Name, value: &Value) -> Option<Vec<AccountId>> {
+        self.with_metadata_index(|index| index.accounts.get(key, value))
+    }
+
+    /// Ids of the asset definitions with `value` under `key` in their metadata.
+    ///
+    /// Returns `None` if `key` isn't indexed, see [`Configuration::metadata_indexes`].
+    pub fn asset_definition_ids_by_metadata(
+        &self,
+        key: &Name,
+        value: &Value,
+    ) -> Option<Vec<AssetDefinitionId>> {
+        self.with_metadata_index(|index| index.asset_definitions.get(key, value))
+    }
+
+    /// Ids of the store assets with `value` under `key`.
+    ///
+    /// Returns `None` if `key` isn't indexed, see [`Configuration::metadata_indexes`].
+    pub fn asset_ids_by_metadata(&self, key: &Name, value: &Value) -> Option<Vec<AssetId>> {
+        self.with_metadata_index(|index| index.assets.get(key, value))
+    }
+
+    fn account_statements(&self) -> MutexGuard<'_, AccountStatements> {
+        self.account_statements
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Changes of the quantities of the assets of the account with `id`
+    /// in chronological order.
+    ///
+    /// Copies of [`Self`] only see the changes made by the committed blocks.
+    pub fn account_statement(&self, id: &AccountId) -> Vec<AccountStatementEntry> {
+        self.account_statements().entries(id)
+    }
+
+    /// Run `f` with `creator` and `hash` exposed to the expressions evaluated
+    /// by it as [`TRANSACTION_CREATOR_VALUE`] and [`TRANSACTION_HASH_VALUE`].
+    ///
+    /// The context is only visible to the current thread for the duration of `f`,
+    /// so queries evaluated concurrently, e.g. by Torii, don't see it.
+    pub fn with_transaction_context<T>(
+        &self,
+        creator: AccountId,
+        hash: Hash,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        let previous = CURRENT_TRANSACTION.with(|current| current.replace(Some((creator, hash))));
+        let result = f();
+        CURRENT_TRANSACTION.with(|current| current.replace(previous));
+        result
+    }
+
+    /// Run `f` with `height` and `timestamp` of the block being validated or
+    /// applied exposed to the expressions evaluated by it as
+    /// [`BLOCK_HEIGHT_VALUE`] and [`BLOCK_TIMESTAMP_VALUE`].
+    ///
+    /// Like [`Self::with_transaction_context`], the context is only visible
+    /// to the current thread for the duration of `f`.
+    pub fn with_block_context<T>(&self, height: u64, timestamp: u128, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_BLOCK.with(|current| current.replace(Some((height, timestamp))));
+        let result = f();
+        CURRENT_BLOCK.with(|current| current.set(previous));
+        result
+    }
+
+    /// Built-in context value with the given `name`, which is available to
+    /// expressions without being injected by the caller.
+    ///
+    /// [`BLOCK_HEIGHT_VALUE`] and [`BLOCK_TIMESTAMP_VALUE`] are the ones of the
+    /// block being validated or applied, see [`Self::with_block_context`], so
+    /// a transaction sees the same values in both cases. Outside of a block,
+    /// e.g. in queries, they are the ones of the latest committed block.
+    pub fn builtin_context_value(&self, name: &str) -> Option<Value> {
+        match name {
+            BLOCK_HEIGHT_VALUE => Some(Value::U128(
+                current_block()
+                    .map_or_else(|| self.height(), |(height, _)| height)
+                    .into(),
+            )),
+            BLOCK_TIMESTAMP_VALUE => match current_block() {
+                Some((_, timestamp)) => Some(Value::U128(timestamp)),
+                None => self
+                    .blocks
+                    .latest_block()
+                    .map(|block| Value::U128(block.header().timestamp)),
+            },
+            TRANSACTION_CREATOR_VALUE => {
+                current_transaction().map(|(creator, _)| Value::Id(IdBox::AccountId(creator)))
+            }
+            TRANSACTION_HASH_VALUE => current_transaction().map(|(_, hash)| Value::Hash(hash)),
+            _ => None,
+        }
+    }
+
+    /// Clone [`WorldStateView`] so that the events produced by the
+    /// clone are sent to `events_sender` instead of the subscribers
+    /// of `self`.
+    #[must_use]
+    pub fn clone_with_events_sender(&self, events_sender: EventsSender) -> Self {
+        Self {
+            events_sender,
+            ..self.clone()
+        }
+    }
+
+    /// Start recording the changes of a transaction being validated,
+    /// so that they can be undone with [`Self::rollback_transaction`]
+    /// if it's rejected.
+    ///
+    /// Triggers matched by the transaction aren't undone, as they are
+    /// only executed when a block is applied, not on validation copies.
+    pub(crate) fn start_transaction(&self) {
+        *self.journal() = Some(Journal::default());
+    }
+
+    /// Keep the changes made since [`Self::start_transaction`].
+    pub(crate) fn commit_transaction(&self) {
+        *self.journal() = None;
+    }
+
+    /// Undo the changes made since [`Self::start_transaction`].
+    pub(crate) fn rollback_transaction(&mut self) {
+        let journal = match self.journal().take() {
+            Some(journal) => journal,
+            None => return,
+        };
+
+        // Restored first, as the parts changed before it was taken are recorded separately
+        if let Some(world) = journal.world {
+            self.world = world;
+        }
+        for (id, domain) in journal.domains {
+            match domain {
+                Some(domain) => {
+                    self.world.domains.insert(id, domain);
+                }
+                None => {
+                    self.world.domains.remove(&id);
+                }
+            }
+        }
+        for (id, permissions) in journal.account_permission_tokens {
+            match permissions {
+                Some(permissions) => {
+                    self.world.account_permission_tokens.insert(id, permissions);
+                }
+                None => {
+                    self.world.account_permission_tokens.remove(&id);
+                }
+            }
+        }
+        if let Some(parameters) = journal.parameters {
+            self.world.parameters = parameters.into_iter().collect();
+        }
+        // Rebuilt from the restored world on the next lookup
+        *self.metadata_index() = None;
+    }
+
+    fn journal(&self) -> MutexGuard<'_, Option<Journal>> {
+        self.journal.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Let `record` save the parts of the world about to be changed,
+    /// if the changes are recorded, see [`Self::start_transaction`].
+    fn record_change(&self, record: impl FnOnce(&mut Journal, &World)) {
+        if let Some(journal) = self.journal().as_mut() {
+            if journal.world.is_none() {
+                record(journal, &self.world);
+            }
+        }
+    }
+
+    fn record_account_permission_tokens(&self, account_id: &AccountId) {
+        self.record_change(|journal, world| {
+            journal
+                .account_permission_tokens
+                .entry(account_id.clone())
+                .or_insert_with(|| {
+                    world
+                        .account_permission_tokens
+                        .get(account_id)
+                        .map(|permissions| permissions.clone())
+                });
+        });
+    }
+
+    /// Changes of peers, domains, accounts, asset definitions,
+    /// assets and roles in `other` relative to `self`, where `other`
+    /// is a copy of `self` which produced the data `events`.
+    ///
+    /// Only the entities the `events` originate from and their children
+    /// are compared, see [`Self::full_state_diff`] if some events were lost.
+    pub fn state_diff(&self, other: &Self, events: &[DataEvent]) -> Vec<StateChange> {
+        let ids: BTreeSet<_> = events.iter().filter_map(changed_entity_id).collect();
+        Self::diff(self.entities(&ids), other.entities(&ids))
+    }
+
+    /// Changes of peers, domains, accounts, asset definitions,
+    /// assets and roles in `other` relative to `self`.
+    ///
+    /// Walks both worlds, unlike [`Self::state_diff`].
+    pub fn full_state_diff(&self, other: &Self) -> Vec<StateChange> {
+        let ids: BTreeSet<_> = self.top_level_ids().chain(other.top_level_ids()).collect();
+        Self::diff(self.entities(&ids), other.entities(&ids))
+    }
+
+    /// Domains and accounts are compared without their children,
+    /// which are reported as separate changes.
+    fn diff(
+        mut before: BTreeMap<IdBox, IdentifiableBox>,
+        after: BTreeMap<IdBox, IdentifiableBox>,
+    ) -> Vec<StateChange> {
+        let mut changes = Vec::new();
+
+        for (id, after) in after {
+            match before.remove(&id) {
+                Some(before) if Self::is_same_entity(&before, &after) => {}
+                before => changes.push(StateChange {
+                    id,
+                    before,
+                    after: Some(after),
+                }),
+            }
+        }
+        changes.extend(before.into_iter().map(|(id, before)| StateChange {
+            id,
+            before: Some(before),
+            after: None,
+        }));
+
+        changes.sort();
+        changes
+    }
+
+    fn top_level_ids(&self) -> impl Iterator<Item = IdBox> + '_ {
+        let peer_ids = self
+            .world
+            .trusted_peers_ids
+            .iter()
+            .map(|peer_id| IdBox::PeerId(peer_id.clone()));
+        let domain_ids = self
+            .world
+            .domains
+            .iter()
+            .map(|domain| IdBox::DomainId(domain.id().clone()));
+        let role_ids = self
+            .world
+            .roles
+            .iter()
+            .map(|role| IdBox::RoleId(role.id().clone()));
+        peer_ids.chain(domain_ids).chain(role_ids)
+    }
+
+    /// Existing entities with the given `ids` along with their children.
+    fn entities(&self, ids: &BTreeSet<IdBox>) -> BTreeMap<IdBox, IdentifiableBox> {
+        let mut entities = BTreeMap::new();
+        let insert_account = |entities: &mut BTreeMap<_, _>, account: &Account| {
+            for asset in account.assets() {
+                entities.insert(
+                    IdBox::AssetId(asset.id().clone()),
+                    IdentifiableBox::Asset(Box::new(asset.clone())),
+                );
+            }
+            entities.insert(
+                IdBox::AccountId(account.id().clone()),
+                IdentifiableBox::Account(Box::new(account.clone())),
+            );
+        };
+
+        for id in ids {
+            match id {
+                IdBox::PeerId(peer_id) => {
+                    if self.world.trusted_peers_ids.contains(peer_id) {
+                        entities.insert(
+                            id.clone(),
+                            IdentifiableBox::Peer(Box::new(Peer::new(peer_id.clone()))),
+                        );
+                    }
+                }
+                IdBox::DomainId(domain_id) => {
+                    if let Some(domain) = self.world.domains.get(domain_id) {
+                        for account in domain.accounts() {
+                            insert_account(&mut entities, account);
+                        }
+                        for entry in domain.asset_definitions() {
+                            entities.insert(
+                                IdBox::AssetDefinitionId(entry.definition().id().clone()),
+                                IdentifiableBox::AssetDefinition(Box::new(
+                                    entry.definition().clone(),
+                                )),
+                            );
+                        }
+                        entities.insert(
+                            id.clone(),
+                            IdentifiableBox::Domain(Box::new(domain.clone())),
+                        );
+                    }
+                }
+                IdBox::AccountId(account_id) => {
+                    if let Some(domain) = self.world.domains.get(&account_id.domain_id) {
+                        if let Some(account) = domain.account(account_id) {
+                            insert_account(&mut entities, account);
+                        }
+                    }
+                }
+                IdBox::AssetDefinitionId(definition_id) => {
+                    if let Some(domain) = self.world.domains.get(&definition_id.domain_id) {
+                        if let Some(entry) = domain.asset_definition(definition_id) {
+                            entities.insert(
+                                id.clone(),
+                                IdentifiableBox::AssetDefinition(Box::new(
+                                    entry.definition().clone(),
+                                )),
+                            );
+                        }
+                    }
+                }
+                IdBox::AssetId(asset_id) => {
+                    let asset = self
+                        .world
+                        .domains
+                        .get(&asset_id.account_id.domain_id)
+                        .and_then(|domain| {
+                            domain
+                                .account(&asset_id.account_id)?
+                                .asset(asset_id)
+                                .cloned()
+                        });
+                    if let Some(asset) = asset {
+                        entities.insert(id.clone(), IdentifiableBox::Asset(Box::new(asset)));
+                    }
+                }
+                IdBox::RoleId(role_id) => {
+                    if let Some(role) = self.world.roles.get(role_id) {
+                        entities.insert(
+                            id.clone(),
+                            IdentifiableBox::Role(Box::new(role.value().clone())),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        entities
+    }
+
+    fn is_same_entity(before: &IdentifiableBox, after: &IdentifiableBox) -> bool {
+        match (before, after) {
+            (IdentifiableBox::Domain(before), IdentifiableBox::Domain(after)) => {
+                before.logo() == after.logo() && before.metadata() == after.metadata()
+            }
+            (IdentifiableBox::Account(before), IdentifiableBox::Account(after)) => {
+                before.signatories().eq(after.signatories())
+                    && before.roles().eq(after.roles())
+                    && before.signature_check_condition() == after.signature_check_condition()
+                    && before.metadata() == after.metadata()
+            }
+            _ => before == after,
         }
     }
 
@@ -764,31 +1443,7 @@ impl WorldStateView {
     pub fn transaction_values(&self) -> Vec<TransactionQueryResult> {
         let mut txs = self
             .blocks()
-            .flat_map(|block| {
-                let block = block.as_v1();
-                block
-                    .rejected_transactions
-                    .iter()
-                    .cloned()
-                    .map(Box::new)
-                    .map(|versioned_rejected_tx| TransactionQueryResult {
-                        tx_value: TransactionValue::RejectedTransaction(versioned_rejected_tx),
-                        block_hash: Hash::from(block.hash()),
-                    })
-                    .chain(
-                        block
-                            .transactions
-                            .iter()
-                            .cloned()
-                            .map(VersionedTransaction::from)
-                            .map(Box::new)
-                            .map(|versioned_tx| TransactionQueryResult {
-                                tx_value: TransactionValue::Transaction(versioned_tx),
-                                block_hash: Hash::from(block.hash()),
-                            }),
-                    )
-                    .collect::<Vec<_>>()
-            })
+            .flat_map(|block| block_transaction_values(block.as_v1()))
             .collect::<Vec<_>>();
         txs.sort();
         txs
@@ -820,6 +1475,17 @@ impl WorldStateView {
         })
     }
 
+    /// Find a committed [`VersionedTransaction`] by hash together with
+    /// the proof of its inclusion in a block.
+    pub fn transaction_proof_by_hash(
+        &self,
+        hash: &HashOf<VersionedTransaction>,
+    ) -> Option<TransactionProof> {
+        self.blocks
+            .iter()
+            .find_map(|b| b.as_v1().transaction_proof(hash))
+    }
+
     #[cfg(test)]
     pub fn transactions_number(&self) -> u64 {
         self.blocks.iter().fold(0_u64, |acc, block| {
@@ -869,6 +1535,91 @@ impl WorldStateView {
         &self.world
     }
 
+    /// Get sequence number expected in the next transaction of the account with `account_id`.
+    pub fn next_sequence_number(&self, account_id: &AccountId) -> u32 {
+        self.world
+            .account_sequence_numbers
+            .get(account_id)
+            .map_or(0, |sequence_number| *sequence_number)
+    }
+
+    /// Get on-chain [`Parameter`]s.
+    pub fn parameters(&self) -> Vec<Parameter> {
+        self.world
+            .parameters
+            .iter()
+            .map(|parameter| parameter.clone())
+            .collect()
+    }
+
+    /// Set on-chain [`Parameter`], replacing the previous parameter of the same kind.
+    pub fn set_parameter(&self, parameter: Parameter) {
+        self.record_change(|journal, world| {
+            journal.parameters.get_or_insert_with(|| {
+                world
+                    .parameters
+                    .iter()
+                    .map(|parameter| parameter.clone())
+                    .collect()
+            });
+        });
+        let kind = core::mem::discriminant(&parameter);
+        self.world
+            .parameters
+            .retain(|existing| core::mem::discriminant(existing) != kind);
+        self.world.parameters.insert(parameter);
+    }
+
+    /// Get [`FeeSchedule`] from the on-chain parameters if fees are enabled.
+    pub fn fee_schedule(&self) -> Option<FeeSchedule> {
+        self.world
+            .parameters
+            .iter()
+            .find_map(|parameter| match parameter.key() {
+                Parameter::TransactionFees(fee_schedule) => Some(fee_schedule.clone()),
+                _ => None,
+            })
+    }
+
+    /// Debit fee for executing `executable` which consumed `fuel` from `payer` to the treasury.
+    ///
+    /// Does nothing if no [`FeeSchedule`] is set or if `payer` is the genesis account.
+    /// Rejected transactions aren't charged, even for the fuel their execution
+    /// consumed, as none of their changes are applied.
+    ///
+    /// # Errors
+    /// - Fee overflows
+    /// - `payer` doesn't hold enough of the fee asset
+    pub fn pay_transaction_fee(
+        &self,
+        payer: &AccountId,
+        executable: &Executable,
+        fuel: u64,
+    ) -> Result<(), Error> {
+        let fee_schedule = match self.fee_schedule() {
+            Some(fee_schedule) if *payer != AccountId::genesis() => fee_schedule,
+            _ => return Ok(()),
+        };
+        let fee = match executable {
+            Executable::Instructions(instructions) => fee_schedule.instructions_fee(instructions),
+            Executable::Wasm(_) => fee_schedule.wasm_fee(fuel),
+        }
+        .ok_or(MathError::Overflow)?;
+        if fee == 0 {
+            return Ok(());
+        }
+
+        Transfer::<Asset, u32, Asset>::new(
+            AssetId::new(fee_schedule.asset_definition_id.clone(), payer.clone()),
+            fee,
+            AssetId::new(
+                fee_schedule.asset_definition_id,
+                fee_schedule.treasury_account_id,
+            ),
+        )
+        .execute(payer.clone(), self)
+    }
+
     /// Returns reference for triggers
     #[inline]
     pub fn triggers(&self) -> &TriggerSet {
@@ -910,11 +1661,105 @@ impl WorldStateView {
     }
 }
 
+/// Id of the entity changed by `event`, if it's covered by [`WorldStateView::state_diff`].
+///
+/// Events of the children carried by the events of their parents are skipped,
+/// as every such event is also produced on its own.
+fn changed_entity_id(event: &DataEvent) -> Option<IdBox> {
+    match event {
+        DataEvent::Peer(event) => Some(IdBox::PeerId(event.origin_id().clone())),
+        DataEvent::Domain(DomainEvent::Account(_) | DomainEvent::AssetDefinition(_))
+        | DataEvent::Account(AccountEvent::Asset(_))
+        | DataEvent::Trigger(_)
+        | DataEvent::PermissionToken(_) => None,
+        DataEvent::Domain(event) => Some(IdBox::DomainId(event.origin_id().clone())),
+        DataEvent::Account(event) => Some(IdBox::AccountId(event.origin_id().clone())),
+        DataEvent::AssetDefinition(event) => {
+            Some(IdBox::AssetDefinitionId(event.origin_id().clone()))
+        }
+        DataEvent::Asset(event) => Some(IdBox::AssetId(event.origin_id().clone())),
+        DataEvent::Role(event) => Some(IdBox::RoleId(event.origin_id().clone())),
+    }
+}
+
+/// Committed and rejected transactions of `block`, sorted.
+pub fn block_transaction_values(block: &CommittedBlock) -> Vec<TransactionQueryResult> {
+    let block_hash = Hash::from(block.hash());
+    let mut txs = block
+        .rejected_transactions
+        .iter()
+        .cloned()
+        .map(Box::new)
+        .map(|versioned_rejected_tx| TransactionQueryResult {
+            tx_value: TransactionValue::RejectedTransaction(versioned_rejected_tx),
+            block_hash,
+        })
+        .chain(
+            block
+                .transactions
+                .iter()
+                .cloned()
+                .map(VersionedTransaction::from)
+                .map(Box::new)
+                .map(|versioned_tx| TransactionQueryResult {
+                    tx_value: TransactionValue::Transaction(versioned_tx),
+                    block_hash,
+                }),
+        )
+        .collect::<Vec<_>>();
+    txs.sort();
+    txs
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::restriction)]
 
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::transaction::TransactionLimits;
+
     use super::*;
+    use crate::{block::PendingBlock, prelude::AllowAll, tx::TransactionValidator};
+
+    /// [`WorldStateView`] with the `alice@wonderland` account, along with its id and keys.
+    fn wsv_with_alice() -> Result<(Arc<WorldStateView>, AccountId, KeyPair)> {
+        let alice_keys = KeyPair::generate()?;
+        let alice_id: AccountId = "alice@wonderland".parse()?;
+        let mut domain = Domain::new("wonderland".parse()?).build();
+        let account = Account::new(alice_id.clone(), [alice_keys.public_key().clone()]).build();
+        assert!(domain.add_account(account).is_none());
+        let wsv = Arc::new(WorldStateView::new(World::with([domain], PeersIds::new())));
+        Ok((wsv, alice_id, alice_keys))
+    }
+
+    /// Apply the first block to `wsv`, made of a transaction of `authority`
+    /// registering the `looking_glass` domain.
+    async fn apply_first_block(
+        wsv: &Arc<WorldStateView>,
+        authority: AccountId,
+        keys: KeyPair,
+    ) -> Result<()> {
+        let limits = TransactionLimits {
+            max_instruction_number: 1,
+            max_wasm_size_bytes: 0,
+        };
+        let register_domain = RegisterBox::new(Domain::new("looking_glass".parse()?));
+        let tx = Transaction::new(authority, vec![register_domain.into()].into(), 4000)
+            .sign(keys.clone())?;
+        let tx = crate::VersionedAcceptedTransaction::from_transaction(tx, &limits)?;
+        let block = PendingBlock::new(vec![tx], vec![])
+            .chain_first()
+            .validate(&TransactionValidator::new(
+                limits,
+                ChainId::default(),
+                Arc::new(AllowAll::new()),
+                Arc::new(AllowAll::new()),
+                Arc::clone(wsv),
+            ))
+            .sign(keys)?
+            .commit();
+        wsv.apply(block).await
+    }
 
     #[tokio::test]
     async fn get_blocks_after_hash() {
@@ -960,4 +1805,258 @@ mod tests {
             &[8, 9, 10]
         );
     }
+
+    #[tokio::test]
+    async fn consistent_read_retries_while_block_is_applied() {
+        use std::cell::Cell;
+
+        let wsv = WorldStateView::default();
+
+        let attempts = Cell::new(0);
+        let retried = wsv
+            .consistent_read(|wsv| {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    drop(wsv.begin_apply());
+                }
+                attempts.get()
+            })
+            .await;
+        assert_eq!(retried, 3);
+    }
+
+    #[tokio::test]
+    async fn consistent_read_waits_for_block_to_be_applied() {
+        let wsv = Arc::new(WorldStateView::default());
+        let apply_epoch_guard = wsv.begin_apply();
+
+        let mut read = tokio::spawn({
+            let wsv = Arc::clone(&wsv);
+            async move { wsv.consistent_read(|wsv| wsv.height()).await }
+        });
+        assert!(tokio::time::timeout(Duration::from_millis(50), &mut read)
+            .await
+            .is_err());
+
+        drop(apply_epoch_guard);
+        assert_eq!(
+            tokio::time::timeout(Duration::from_secs(5), read)
+                .await
+                .unwrap()
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn data_events_are_recorded_per_block() -> Result<()> {
+        let (wsv, alice_id, alice_keys) = wsv_with_alice()?;
+
+        assert!(wsv.block_data_events(1).is_none());
+        apply_first_block(&wsv, alice_id, alice_keys).await?;
+
+        let data_events = wsv.block_data_events(1).expect("Block is committed");
+        assert!(matches!(
+            data_events.as_slice(),
+            [DataEvent::Domain(DomainEvent::Created(id))] if id.name.as_ref() == "looking_glass"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn block_context_values_are_of_block_being_applied() -> Result<()> {
+        use iroha_data_model::expression::{BLOCK_HEIGHT_VALUE, BLOCK_TIMESTAMP_VALUE};
+
+        let (wsv, alice_id, alice_keys) = wsv_with_alice()?;
+        let limits = TransactionLimits {
+            max_instruction_number: 16,
+            max_wasm_size_bytes: 0,
+        };
+        let set_context_value = |name: &str| -> Result<Instruction> {
+            Ok(SetKeyValueBox::new(
+                IdBox::AccountId(alice_id.clone()),
+                name.parse::<Name>()?,
+                EvaluatesTo::new_unchecked(ContextValue::new(name).into()),
+            )
+            .into())
+        };
+        let instructions = vec![
+            set_context_value(BLOCK_HEIGHT_VALUE)?,
+            set_context_value(BLOCK_TIMESTAMP_VALUE)?,
+        ];
+        let tx = Transaction::new(alice_id.clone(), instructions.into(), 4000)
+            .sign(alice_keys.clone())?;
+        let tx = crate::VersionedAcceptedTransaction::from_transaction(tx, &limits)?;
+        let block = PendingBlock::new(vec![tx], vec![])
+            .chain_first()
+            .validate(&TransactionValidator::new(
+                limits,
+                ChainId::default(),
+                Arc::new(AllowAll::new()),
+                Arc::new(AllowAll::new()),
+                Arc::clone(&wsv),
+            ))
+            .sign(alice_keys)?
+            .commit();
+        assert!(block.as_v1().rejected_transactions.is_empty());
+        let timestamp = block.header().timestamp;
+        wsv.apply(block).await?;
+
+        let context_value = |name: &str| {
+            wsv.map_account(&alice_id, |account| {
+                account.metadata().get(&name.parse().unwrap()).cloned()
+            })
+        };
+        assert_eq!(context_value(BLOCK_HEIGHT_VALUE)?, Some(Value::U128(1)));
+        assert_eq!(
+            context_value(BLOCK_TIMESTAMP_VALUE)?,
+            Some(Value::U128(timestamp))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn data_events_are_kept_for_retention_window() {
+        const BLOCK_CNT: usize = 5;
+
+        let mut block = ValidBlock::new_dummy().commit();
+        let config = Configuration {
+            data_events_retention_blocks: 2,
+            ..Configuration::default()
+        };
+        let (events_sender, _) = broadcast::channel(1);
+        let wsv = WorldStateView::from_configuration(config, World::default(), events_sender);
+
+        for i in 1..=BLOCK_CNT {
+            block.header.height = i as u64;
+            let block: VersionedCommittedBlock = block.clone().into();
+            wsv.apply(block).await.unwrap();
+        }
+
+        assert_eq!(wsv.earliest_data_events_height(), 4);
+        assert!(wsv.block_data_events(3).is_none());
+        assert!(wsv.block_data_events(4).is_some());
+        assert!(wsv.block_data_events(5).is_some());
+    }
+
+    #[tokio::test]
+    async fn trigger_is_executed_only_if_condition_holds() -> Result<()> {
+        use iroha_data_model::events::data::prelude::FilterOpt;
+
+        let (wsv, alice_id, alice_keys) = wsv_with_alice()?;
+
+        let register_conditional_trigger = |name: &str, origin_domain: &str| -> Result<()> {
+            let set_key = SetKeyValueBox::new(alice_id.clone(), name.parse::<Name>()?, 1_u32);
+            let is_created_domain = Equal::new(
+                EvaluatesTo::new_unchecked(ContextValue::new(TRIGGERING_EVENT_ORIGIN_VALUE).into()),
+                IdBox::DomainId(origin_domain.parse()?),
+            );
+            let trigger = Trigger::new(
+                name.parse()?,
+                Action::new(
+                    Executable::from(vec![set_key.into()]),
+                    Repeats::from(1_u32),
+                    alice_id.clone(),
+                    FilterBox::Data(FilterOpt::AcceptAll),
+                )
+                .with_condition(EvaluatesTo::new_unchecked(is_created_domain.into())),
+            );
+            RegisterBox::new(trigger).execute(alice_id.clone(), &wsv)?;
+            Ok(())
+        };
+        register_conditional_trigger("matching", "looking_glass")?;
+        register_conditional_trigger("not_matching", "wonderland")?;
+
+        apply_first_block(&wsv, alice_id.clone(), alice_keys).await?;
+
+        let alice_metadata = wsv.map_account(&alice_id, |account| account.metadata().clone())?;
+        assert!(alice_metadata.get(&"matching".parse::<Name>()?).is_some());
+        assert!(alice_metadata
+            .get(&"not_matching".parse::<Name>()?)
+            .is_none());
+
+        // Repeats of the trigger which condition didn't hold are not consumed
+        assert!(!wsv.triggers().contains(&"matching".parse()?));
+        assert!(wsv.triggers().contains(&"not_matching".parse()?));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failing_trigger_is_disabled_by_failure_policy() -> Result<()> {
+        use iroha_data_model::events::data::prelude::FilterOpt;
+
+        let (wsv, alice_id, alice_keys) = wsv_with_alice()?;
+
+        let register_failing_trigger = |name: &str, failure_policy| -> Result<TriggerId> {
+            let trigger_id: TriggerId = name.parse()?;
+            let trigger = Trigger::new(
+                trigger_id.clone(),
+                Action::new(
+                    Executable::from(vec![FailBox::new("Always fails").into()]),
+                    Repeats::Indefinitely,
+                    alice_id.clone(),
+                    FilterBox::Data(FilterOpt::AcceptAll),
+                )
+                .with_failure_policy(failure_policy),
+            );
+            RegisterBox::new(trigger).execute(alice_id.clone(), &wsv)?;
+            Ok(trigger_id)
+        };
+        let disabled_id = register_failing_trigger("disabled", FailurePolicy::DisableAfter(1))?;
+        let kept_id = register_failing_trigger("kept", FailurePolicy::Ignore)?;
+
+        apply_first_block(&wsv, alice_id.clone(), alice_keys).await?;
+
+        let (is_disabled, stats) = wsv
+            .triggers()
+            .inspect_by_id(&disabled_id, |action| {
+                (action.is_disabled(), action.stats().clone())
+            })
+            .expect("Trigger is registered");
+        assert!(is_disabled);
+        assert_eq!(stats.successes, 0);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.consecutive_failures, 1);
+        assert_eq!(stats.last_execution_height, Some(1));
+        assert!(stats.last_error.is_some());
+
+        let (is_kept_disabled, kept_stats) = wsv
+            .triggers()
+            .inspect_by_id(&kept_id, |action| {
+                (action.is_disabled(), action.stats().clone())
+            })
+            .expect("Trigger is registered");
+        assert!(!is_kept_disabled);
+        assert!(kept_stats.failures > 0);
+
+        let data_events = wsv.block_data_events(1).expect("Block is committed");
+        let has_trigger_event =
+            |expected: TriggerEvent| data_events.contains(&DataEvent::Trigger(expected));
+        assert!(has_trigger_event(TriggerEvent::ExecutionFailed(
+            disabled_id.clone()
+        )));
+        assert!(has_trigger_event(TriggerEvent::Disabled(
+            disabled_id.clone()
+        )));
+        assert!(has_trigger_event(TriggerEvent::ExecutionFailed(
+            kept_id.clone()
+        )));
+        assert!(!has_trigger_event(TriggerEvent::Disabled(kept_id)));
+
+        let bob_id: AccountId = "bob@wonderland".parse()?;
+        assert!(EnableTriggerBox::new(disabled_id.clone())
+            .execute(bob_id, &wsv)
+            .is_err());
+        EnableTriggerBox::new(disabled_id.clone()).execute(alice_id, &wsv)?;
+        let (is_disabled, stats) = wsv
+            .triggers()
+            .inspect_by_id(&disabled_id, |action| {
+                (action.is_disabled(), action.stats().clone())
+            })
+            .expect("Trigger is registered");
+        assert!(!is_disabled);
+        assert_eq!(stats.consecutive_failures, 0);
+        assert_eq!(stats.failures, 1);
+        Ok(())
+    }
 }
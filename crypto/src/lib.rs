@@ -25,7 +25,7 @@ pub use hash::*;
 use iroha_ffi::{IntoFfi, TryFromReprC};
 use iroha_primitives::conststr::ConstString;
 use iroha_schema::IntoSchema;
-pub use merkle::MerkleTree;
+pub use merkle::{MerkleProof, MerkleTree};
 use multihash::{DigestFunction as MultihashDigestFunction, Multihash};
 use parity_scale_codec::{Decode, Encode, Error as ScaleError};
 use serde::{Deserialize, Serialize};
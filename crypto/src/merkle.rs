@@ -2,11 +2,13 @@
 #![allow(clippy::std_instead_of_alloc, clippy::arithmetic)]
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{borrow::ToOwned as _, format, string::String, vec, vec::Vec};
 #[cfg(feature = "std")]
 use std::collections::VecDeque;
 
 use iroha_schema::prelude::*;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
 
 use crate::HashOf;
 
@@ -14,6 +16,16 @@ use crate::HashOf;
 #[derive(Debug)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof that a leaf hash is included in a [`MerkleTree`] with a known root hash.
+#[derive(Debug, Decode, Encode, Deserialize, Serialize)]
+pub struct MerkleProof<T> {
+    /// Index of the leaf among the leaves of the tree.
+    leaf_index: u32,
+    /// Hashes of the siblings of the nodes on the path from the leaf to
+    /// the root, `None` for the missing right siblings.
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -135,6 +147,78 @@ impl<T: IntoSchema> IntoSchema for MerkleTree<T> {
     }
 }
 
+impl<T: IntoSchema> IntoSchema for MerkleProof<T> {
+    fn type_name() -> String {
+        format!("{}::MerkleProof<{}>", module_path!(), T::type_name())
+    }
+    fn schema(map: &mut MetaMap) {
+        map.entry(Self::type_name()).or_insert_with(|| {
+            Metadata::Struct(NamedFieldsMeta {
+                declarations: vec![
+                    Declaration {
+                        name: "leaf_index".to_owned(),
+                        ty: u32::type_name(),
+                    },
+                    Declaration {
+                        name: "audit_path".to_owned(),
+                        ty: Vec::<Option<HashOf<T>>>::type_name(),
+                    },
+                ],
+            })
+        });
+
+        u32::schema(map);
+        Vec::<Option<HashOf<T>>>::schema(map);
+    }
+}
+
+impl<T> Clone for MerkleProof<T> {
+    fn clone(&self) -> Self {
+        Self {
+            leaf_index: self.leaf_index,
+            audit_path: self.audit_path.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for MerkleProof<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.leaf_index == other.leaf_index && self.audit_path == other.audit_path
+    }
+}
+
+impl<T> Eq for MerkleProof<T> {}
+
+impl<T> MerkleProof<T> {
+    /// Index of the proven leaf among the leaves of the tree.
+    pub const fn leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+
+    /// Check that `leaf` is the leaf at [`Self::leaf_index`] of the tree with `root` hash.
+    #[cfg(feature = "std")]
+    pub fn verify(&self, leaf: &HashOf<T>, root: &HashOf<MerkleTree<T>>) -> bool {
+        let mut idx = self.leaf_index;
+        let mut node = *leaf;
+        for sibling in &self.audit_path {
+            let parent = if idx % 2 == 0 {
+                MerkleTree::<T>::nodes_pair_hash(Some(&node), sibling.as_ref())
+            } else {
+                match sibling {
+                    Some(sibling) => MerkleTree::<T>::nodes_pair_hash(Some(sibling), Some(&node)),
+                    None => return false,
+                }
+            };
+            node = match parent {
+                Some(parent) => parent,
+                None => return false,
+            };
+            idx /= 2;
+        }
+        idx == 0 && node.transmute() == *root
+    }
+}
+
 impl<T> Default for MerkleTree<T> {
     fn default() -> Self {
         Self::new()
@@ -160,6 +244,28 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof of inclusion of the `idx`-th leaf hash.
+    pub fn get_proof(&self, idx: usize) -> Option<MerkleProof<T>> {
+        let offset = 2_usize.pow(self.height()) - 1;
+        let mut node_idx = offset.checked_add(idx)?;
+        self.get(node_idx)?.as_ref()?;
+
+        let mut audit_path = Vec::new();
+        while let Some(parent_idx) = self.parent(node_idx) {
+            let sibling_idx = if node_idx % 2 == 0 {
+                node_idx - 1
+            } else {
+                node_idx + 1
+            };
+            audit_path.push(self.get(sibling_idx).copied().flatten());
+            node_idx = parent_idx;
+        }
+        Some(MerkleProof {
+            leaf_index: u32::try_from(idx).ok()?,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     #[cfg(feature = "std")]
     pub fn add(&mut self, hash: HashOf<T>) {
@@ -317,4 +423,45 @@ mod tests {
             assert_eq!(testee_leaf, tester_leaf);
         }
     }
+
+    #[test]
+    fn inclusion_proofs() {
+        for n_leaves in 1..=9 {
+            let hashes = test_hashes(n_leaves);
+            let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+            let root = tree.hash().expect("Tree isn't empty");
+
+            for (idx, hash) in hashes.iter().enumerate() {
+                let proof = tree.get_proof(idx).expect("Leaf exists");
+                assert!(proof.verify(hash, &root));
+
+                let other = hashes[(idx + 1) % hashes.len()];
+                if other != *hash {
+                    assert!(!proof.verify(&other, &root));
+                }
+            }
+            assert!(tree.get_proof(hashes.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn tampered_proof_is_rejected() {
+        let hashes = test_hashes(5);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+        let root = tree.hash().expect("Tree isn't empty");
+
+        let mut proof = tree.get_proof(2).expect("Leaf exists");
+        proof.audit_path[0] = Some(hashes[4]);
+        assert!(!proof.verify(&hashes[2], &root));
+
+        // The last leaf has no right sibling, so it can't be a right child.
+        let mut proof = tree.get_proof(4).expect("Leaf exists");
+        proof.leaf_index = 5;
+        assert!(!proof.verify(&hashes[4], &root));
+
+        let mut proof = tree.get_proof(2).expect("Leaf exists");
+        proof.audit_path.push(None);
+        proof.leaf_index += 8;
+        assert!(!proof.verify(&hashes[2], &root));
+    }
 }
@@ -13,7 +13,7 @@ fn schedule_from_zero_with_little_period(criterion: &mut Criterion) {
     const TIMESTAMP: u64 = 1_647_443_386;
 
     let interval = TimeInterval::new(Duration::from_secs(TIMESTAMP), Duration::from_secs(1));
-    let event = TimeEvent::new(None, interval);
+    let event = TimeEvent::new(1, None, interval);
     let schedule = TimeSchedule::starting_at(Duration::ZERO).with_period(Duration::from_millis(1));
     let filter = TimeEventFilter(ExecutionTime::Schedule(schedule));
 
@@ -19,6 +19,7 @@ use std::{
 
 use derive_more::Display;
 use getset::{Getters, MutGetters, Setters};
+use iroha_crypto::Hash;
 use iroha_data_model_derive::IdOrdEqHash;
 use iroha_ffi::{IntoFfi, TryFromReprC};
 use iroha_schema::IntoSchema;
@@ -35,7 +36,7 @@ use crate::{
     metadata::Metadata,
     prelude::Asset,
     role::{prelude::RoleId, RoleIds},
-    HasMetadata, Identifiable, Name, ParseError, PublicKey, Registered,
+    HasMetadata, Identifiable, Name, ParseError, PublicKey, Registered, Value,
 };
 
 /// `AccountsMap` provides an API to work with collection of key (`Id`) - value
@@ -454,7 +455,66 @@ impl FromStr for Id {
     }
 }
 
+/// Change of an asset quantity.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+pub enum AssetDelta {
+    /// Quantity increased by the given amount.
+    #[display(fmt = "+{_0}")]
+    Increase(Value),
+    /// Quantity decreased by the given amount.
+    #[display(fmt = "-{_0}")]
+    Decrease(Value),
+}
+
+/// Entry of an account statement: a single change of the quantity
+/// of one of the account's assets.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+#[display(fmt = "{asset_id}: {delta}, balance {balance} at block {block_height}")]
+pub struct AccountStatementEntry {
+    /// Height of the block in which the quantity changed.
+    pub block_height: u64,
+    /// Hash of the transaction which changed the quantity, `None`
+    /// if it was changed outside of a transaction, e.g. by a time trigger.
+    pub transaction_hash: Option<Hash>,
+    /// Asset whose quantity changed.
+    pub asset_id: AssetId,
+    /// Account on the other side of a transfer, `None` for mints and burns.
+    pub counterparty: Option<Id>,
+    /// Change of the quantity.
+    pub delta: AssetDelta,
+    /// Quantity after the change.
+    pub balance: Value,
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, Id as AccountId, SignatureCheckCondition};
+    pub use super::{
+        Account, AccountStatementEntry, AssetDelta, Id as AccountId, SignatureCheckCondition,
+    };
 }
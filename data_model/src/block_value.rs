@@ -50,6 +50,45 @@ impl Ord for BlockHeaderValue {
     }
 }
 
+/// Block header in the form which lets clients check that it hashes
+/// into the block hash without knowing the types of all header fields.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct EncodedBlockHeader {
+    /// Unix time (in milliseconds) of block forming by a peer.
+    pub timestamp: u128,
+    /// Estimation of consensus duration in milliseconds
+    pub consensus_estimation: u64,
+    /// a number of blocks in the chain up to the block.
+    pub height: u64,
+    /// Hash of a previous block in the chain.
+    pub previous_block_hash: Hash,
+    /// Hash of merkle tree root of the tree of valid transactions' hashes.
+    pub transactions_hash: HashOf<MerkleTree<VersionedTransaction>>,
+    /// Hash of merkle tree root of the tree of rejected transactions' hashes.
+    pub rejected_transactions_hash: HashOf<MerkleTree<VersionedTransaction>>,
+    /// SCALE encoding of the rest of the header fields, which follow
+    /// the fields above in the encoding of the header.
+    pub rest: Vec<u8>,
+}
+
+impl EncodedBlockHeader {
+    /// Hash of the block with this header.
+    #[cfg(feature = "std")]
+    pub fn hash(&self) -> Hash {
+        let mut bytes = (
+            self.timestamp,
+            self.consensus_estimation,
+            self.height,
+            self.previous_block_hash,
+            self.transactions_hash,
+            self.rejected_transactions_hash,
+        )
+            .encode();
+        bytes.extend_from_slice(&self.rest);
+        Hash::new(bytes)
+    }
+}
+
 /// Representation of block on blockchain
 #[derive(
     Debug, Display, Clone, PartialEq, Eq, Decode, Encode, Serialize, Deserialize, IntoSchema,
@@ -80,5 +119,5 @@ impl Ord for BlockValue {
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{BlockHeaderValue, BlockValue};
+    pub use super::{BlockHeaderValue, BlockValue, EncodedBlockHeader};
 }
@@ -370,6 +370,9 @@ mod trigger {
         Deleted(TriggerId),
         Extended(TriggerId),
         Shortened(TriggerId),
+        ExecutionFailed(TriggerId),
+        Disabled(TriggerId),
+        Enabled(TriggerId),
     }
 
     impl HasOrigin for TriggerEvent {
@@ -380,7 +383,10 @@ mod trigger {
                 Self::Created(id)
                 | Self::Deleted(id)
                 | Self::Extended(id)
-                | Self::Shortened(id) => id,
+                | Self::Shortened(id)
+                | Self::ExecutionFailed(id)
+                | Self::Disabled(id)
+                | Self::Enabled(id) => id,
             }
         }
     }
@@ -468,6 +474,20 @@ impl Event {
             Self::Peer(_) | Self::Role(_) | Self::PermissionToken(_) => None,
         }
     }
+
+    /// Return the id of the entity which emitted [`Event`]
+    pub fn origin_id(&self) -> IdBox {
+        match self {
+            Self::Peer(event) => event.origin_id().clone().into(),
+            Self::Domain(event) => event.origin_id().clone().into(),
+            Self::Account(event) => event.origin_id().clone().into(),
+            Self::AssetDefinition(event) => event.origin_id().clone().into(),
+            Self::Asset(event) => event.origin_id().clone().into(),
+            Self::Trigger(event) => event.origin_id().clone().into(),
+            Self::Role(event) => event.origin_id().clone().into(),
+            Self::PermissionToken(event) => event.origin_id().clone().into(),
+        }
+    }
 }
 
 impl From<WorldEvent> for SmallVec<[Event; 3]> {
@@ -48,7 +48,27 @@ pub enum EventPublisherMessage {
     /// events starting with the next message.
     SubscriptionAccepted,
     /// Event sent by the peer.
-    Event(Event),
+    Event(SequencedEvent),
+    /// Reply sent by the peer if the subscription can't be served,
+    /// e.g. if the data events of the requested block are no longer kept.
+    SubscriptionRejected(String),
+}
+
+/// Event along with its position in the subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct SequencedEvent {
+    /// Number of the event in the subscription. Starts from `0` and
+    /// is increased by one with every event sent, so it restarts with
+    /// every subscription.
+    pub sequence_number: u64,
+    /// Height of the committed block which produced the event.
+    /// Set only for the subscriptions started from a block height.
+    pub block_height: Option<u64>,
+    /// Position of the event among all events of its block. Along with
+    /// [`Self::block_height`], it identifies the event across subscriptions.
+    pub index_in_block: Option<u64>,
+    /// The event.
+    pub event: Event,
 }
 
 declare_versioned_with_scale!(VersionedEventSubscriberMessage 1..2, Debug, Clone, FromVariant, IntoSchema);
@@ -82,11 +102,52 @@ impl VersionedEventSubscriberMessage {
 pub enum EventSubscriberMessage {
     /// Request sent by the client to subscribe to events.
     //TODO: Sign request?
-    SubscriptionRequest(FilterBox),
+    SubscriptionRequest(EventSubscriptionRequest),
     /// Acknowledgment of receiving event sent from the peer.
     EventReceived,
 }
 
+/// Request to subscribe to events.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct EventSubscriptionRequest {
+    /// Filter the sent events have to match.
+    pub filter: FilterBox,
+    /// Height of the committed block to start the subscription from.
+    ///
+    /// If set, data and pipeline events of the committed blocks are
+    /// replayed in order from this height up to the latest block and
+    /// then the events of every newly committed block are sent. Only
+    /// the events of committed blocks are sent in that case.
+    /// Otherwise, events are sent as they happen.
+    ///
+    /// Peers only keep the data events of a limited number of the
+    /// latest blocks, older heights are rejected.
+    pub from_height: Option<u64>,
+}
+
+impl EventSubscriptionRequest {
+    /// Construct request to subscribe to events matching `filter` as they happen.
+    pub const fn new(filter: FilterBox) -> Self {
+        Self {
+            filter,
+            from_height: None,
+        }
+    }
+
+    /// Start the subscription from the committed block of the given `height`.
+    #[must_use]
+    pub fn from_height(mut self, height: u64) -> Self {
+        self.from_height = Some(height);
+        self
+    }
+}
+
+impl From<FilterBox> for EventSubscriptionRequest {
+    fn from(filter: FilterBox) -> Self {
+        Self::new(filter)
+    }
+}
+
 /// Event.
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, FromVariant, IntoSchema,
@@ -193,7 +254,8 @@ impl Filter for FilterBox {
 pub mod prelude {
     pub use super::{
         data::prelude::*, execute_trigger::prelude::*, pipeline::prelude::*, time::prelude::*,
-        Event, EventPublisherMessage, EventSubscriberMessage, EventType, Filter, FilterBox,
-        VersionedEventPublisherMessage, VersionedEventSubscriberMessage,
+        Event, EventPublisherMessage, EventSubscriberMessage, EventSubscriptionRequest, EventType,
+        Filter, FilterBox, SequencedEvent, VersionedEventPublisherMessage,
+        VersionedEventSubscriberMessage,
     };
 }
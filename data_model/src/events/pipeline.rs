@@ -215,9 +215,9 @@ mod tests {
             },
             Event {
                 entity_kind: EntityKind::Transaction,
-                status: Status::Rejected(Transaction(NotPermitted(NotPermittedFail {
-                    reason: "Some reason".to_string(),
-                }))),
+                status: Status::Rejected(Transaction(NotPermitted(NotPermittedFail::new(
+                    "Some reason",
+                )))),
                 hash: Hash::prehashed([0_u8; Hash::LENGTH]),
             },
             Event {
@@ -240,9 +240,9 @@ mod tests {
                 },
                 Event {
                     entity_kind: EntityKind::Transaction,
-                    status: Status::Rejected(Transaction(NotPermitted(NotPermittedFail {
-                        reason: "Some reason".to_string(),
-                    }))),
+                    status: Status::Rejected(Transaction(NotPermitted(NotPermittedFail::new(
+                        "Some reason"
+                    )))),
                     hash: Hash::prehashed([0_u8; Hash::LENGTH]),
                 },
             ],
@@ -1,15 +1,18 @@
 //! Time event and filter
 #![allow(clippy::std_instead_of_core, clippy::arithmetic)]
 
-use core::{ops::Range, time::Duration};
+use core::{cmp::max, fmt, ops::Range, str::FromStr, time::Duration};
 
 use super::*;
+use crate::ParseError;
 
 /// Special event that is emitted when `WSV` is ready for handling time-triggers
 ///
 /// Contains time interval which is used to identify time-triggers to be executed
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Serialize, Deserialize, IntoSchema)]
 pub struct Event {
+    /// Height of the current block
+    pub height: u64,
     /// Previous block timestamp and consensus durations estimation.
     /// `None` if it's first block commit
     pub prev_interval: Option<Interval>,
@@ -18,9 +21,10 @@ pub struct Event {
 }
 
 impl Event {
-    /// Construct `Event` with `prev_interval` and `interval`
-    pub const fn new(prev_interval: Option<Interval>, interval: Interval) -> Self {
+    /// Construct `Event` for the block at `height` with `prev_interval` and `interval`
+    pub const fn new(height: u64, prev_interval: Option<Interval>, interval: Interval) -> Self {
         Self {
+            height,
             prev_interval,
             interval,
         }
@@ -65,13 +69,18 @@ impl Filter for EventFilter {
 
                 count_matches_in_interval(schedule, &current_interval)
             }
+            ExecutionTime::BlockHeight(schedule) => u32::from(schedule.matches(event.height)),
         }
     }
 
     fn mintable(&self) -> bool {
         !matches!(
             self.0,
-            ExecutionTime::Schedule(Schedule { period: None, .. })
+            ExecutionTime::Schedule(Schedule {
+                period: None,
+                cron: None,
+                ..
+            }) | ExecutionTime::BlockHeight(BlockSchedule { period: None, .. })
         )
     }
 }
@@ -79,6 +88,10 @@ impl Filter for EventFilter {
 /// Count something with the `schedule` within the `interval`
 #[allow(clippy::expect_used)]
 fn count_matches_in_interval(schedule: &Schedule, interval: &Interval) -> u32 {
+    if let Some(cron) = &schedule.cron {
+        return cron.count_matches_in_interval(schedule.start, interval);
+    }
+
     schedule.period.map_or_else(
         || u32::from(Range::from(*interval).contains(&schedule.start)),
         |period| {
@@ -141,6 +154,8 @@ pub enum ExecutionTime {
     PreCommit,
     /// Execute with some schedule
     Schedule(Schedule),
+    /// Execute with some block height schedule
+    BlockHeight(BlockSchedule),
 }
 
 /// Schedule of the trigger
@@ -164,6 +179,9 @@ pub struct Schedule {
     pub start: Duration,
     /// If some, the period between cyclic executions
     pub period: Option<Duration>,
+    /// If some, executions happen at every time matching the expression since `start`,
+    /// and `period` is ignored
+    pub cron: Option<Cron>,
 }
 
 impl Schedule {
@@ -174,6 +192,7 @@ impl Schedule {
         Self {
             start,
             period: None,
+            cron: None,
         }
     }
 
@@ -184,6 +203,382 @@ impl Schedule {
         self.period = Some(period);
         self
     }
+
+    /// Add calendar-based recurrence to `self`
+    #[must_use]
+    #[inline]
+    pub const fn with_cron(mut self, cron: Cron) -> Self {
+        self.cron = Some(cron);
+        self
+    }
+}
+
+/// Calendar-based recurrence of [`Schedule`] in cron syntax
+///
+/// Consists of five space-separated fields matched against UTC time:
+/// minute (`0-59`), hour (`0-23`), day of month (`1-31`), month (`1-12`)
+/// and day of week (`0-7`, both `0` and `7` are Sunday).
+/// Each field is either `*` or a comma-separated list of values and `a-b` ranges,
+/// any of which can be followed by `/step`. As in cron, if both day of month
+/// and day of week are restricted, a day matches if either of them matches.
+///
+/// E.g. `0 0 1 * *` matches midnight of the first day of every month
+/// and `59 23 * * 1-5` matches the end of every business day.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    Serialize,
+    Deserialize,
+    IntoSchema,
+    Hash,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cron {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+}
+
+const MS_PER_MINUTE: u128 = 60_000;
+const MINUTES_PER_HOUR: u64 = 60;
+const MINUTES_PER_DAY: u64 = 24 * MINUTES_PER_HOUR;
+const ALL_DAYS_OF_MONTH: u32 = 0xFFFF_FFFE;
+const ALL_DAYS_OF_WEEK: u8 = 0x7F;
+
+impl Cron {
+    /// Count times matching `self` in `interval`, which aren't earlier than `start`
+    #[allow(clippy::integer_division, clippy::expect_used)]
+    fn count_matches_in_interval(&self, start: Duration, interval: &Interval) -> u32 {
+        let to_minutes = |time: Duration| {
+            u64::try_from((time.as_millis() + MS_PER_MINUTE - 1) / MS_PER_MINUTE)
+                .unwrap_or(u64::MAX)
+        };
+        let end = to_minutes(interval.since + interval.length);
+        let mut minute = to_minutes(max(interval.since, start));
+
+        let mut count = 0_u32;
+        while minute < end {
+            let day = minute / MINUTES_PER_DAY;
+            if !self.matches_day(day) {
+                minute = (day + 1) * MINUTES_PER_DAY;
+                continue;
+            }
+            let hour = minute % MINUTES_PER_DAY / MINUTES_PER_HOUR;
+            if self.hours & (1 << hour) == 0 {
+                minute = (minute / MINUTES_PER_HOUR + 1) * MINUTES_PER_HOUR;
+                continue;
+            }
+            if self.minutes & (1 << (minute % MINUTES_PER_HOUR)) != 0 {
+                count = count.checked_add(1).expect(
+                    "Overflow. The schedule is too frequent relative to the interval length",
+                );
+            }
+            minute += 1;
+        }
+        count
+    }
+
+    /// Check if the day `days` after UNIX epoch matches `self`
+    fn matches_day(&self, days: u64) -> bool {
+        let (month, day_of_month) = month_and_day_from_days(days);
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+
+        // 1970-01-01 was Thursday
+        let day_of_week = (days + 4) % 7;
+        let day_of_month_matches = self.days_of_month & (1 << day_of_month) != 0;
+        let day_of_week_matches = self.days_of_week & (1 << day_of_week) != 0;
+        if self.days_of_month == ALL_DAYS_OF_MONTH {
+            day_of_week_matches
+        } else if self.days_of_week == ALL_DAYS_OF_WEEK {
+            day_of_month_matches
+        } else {
+            day_of_month_matches || day_of_week_matches
+        }
+    }
+}
+
+/// Convert the number of days since UNIX epoch into month (`1-12`)
+/// and day of month (`1-31`) of the proleptic Gregorian calendar
+#[allow(clippy::integer_division)]
+const fn month_and_day_from_days(days: u64) -> (u64, u64) {
+    // Shift epoch to 0000-03-01, so that leap day is the last day of the year
+    let days = days + 719_468;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    (month, day)
+}
+
+/// Parse cron `field` with values in `min..=max` into a bit mask
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<u64, ParseError> {
+    let parse_value = |value: &str| {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|parsed| (min..=max).contains(parsed))
+            .ok_or(ParseError {
+                reason: "Cron field value is invalid or out of range",
+            })
+    };
+
+    let mut mask = 0_u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+        // Bounding the step by `max` also keeps `value + step` below from overflowing
+        let step = step.map_or(Ok(1), |step| {
+            step.parse::<u32>()
+                .ok()
+                .filter(|step| (1..=max).contains(step))
+                .ok_or(ParseError {
+                    reason:
+                        "Cron step must be a positive number not greater than the field maximum",
+                })
+        })?;
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else if let Some((first, last)) = range.split_once('-') {
+            (parse_value(first)?, parse_value(last)?)
+        } else {
+            let first = parse_value(range)?;
+            (first, if step > 1 { max } else { first })
+        };
+        if first > last {
+            return Err(ParseError {
+                reason: "Cron range start is greater than its end",
+            });
+        }
+
+        let mut value = first;
+        while value <= last {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(mask)
+}
+
+/// Write cron field represented by `mask` with values in `min..=max`
+fn fmt_cron_field(f: &mut fmt::Formatter<'_>, mask: u64, min: u32, max: u32) -> fmt::Result {
+    let contains = |value: u32| mask & (1 << value) != 0;
+    if (min..=max).all(contains) {
+        return f.write_str("*");
+    }
+
+    let mut separator = "";
+    let mut value = min;
+    while value <= max {
+        if !contains(value) {
+            value += 1;
+            continue;
+        }
+        let first = value;
+        while value < max && contains(value + 1) {
+            value += 1;
+        }
+        if first == value {
+            write!(f, "{separator}{first}")?;
+        } else {
+            write!(f, "{separator}{first}-{value}")?;
+        }
+        separator = ",";
+        value += 1;
+    }
+    Ok(())
+}
+
+impl FromStr for Cron {
+    type Err = ParseError;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week]: [&str; 5] =
+            fields.try_into().map_err(|_| ParseError {
+                reason: "Cron expression must consist of 5 fields",
+            })?;
+
+        let mut days_of_week = parse_cron_field(days_of_week, 0, 7)?;
+        // Both `0` and `7` stand for Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        // Masks fit into the fields as they're limited by the maximum values
+        Ok(Self {
+            minutes: parse_cron_field(minutes, 0, 59)?,
+            hours: parse_cron_field(hours, 0, 23)? as u32,
+            days_of_month: parse_cron_field(days_of_month, 1, 31)? as u32,
+            months: parse_cron_field(months, 1, 12)? as u16,
+            days_of_week: days_of_week as u8,
+        })
+    }
+}
+
+impl TryFrom<String> for Cron {
+    type Error = ParseError;
+
+    fn try_from(expression: String) -> Result<Self, Self::Error> {
+        expression.parse()
+    }
+}
+
+impl fmt::Display for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_cron_field(f, self.minutes, 0, 59)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.hours.into(), 0, 23)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.days_of_month.into(), 1, 31)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.months.into(), 1, 12)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.days_of_week.into(), 0, 6)
+    }
+}
+
+impl From<Cron> for String {
+    fn from(cron: Cron) -> Self {
+        format!("{cron}")
+    }
+}
+
+/// Block height schedule of the trigger
+///
+/// Unlike [`Schedule`] it doesn't depend on block timestamps,
+/// so the trigger is executed exactly once in every matching block
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    Serialize,
+    Deserialize,
+    IntoSchema,
+    Hash,
+)]
+pub struct BlockSchedule {
+    /// The first execution height
+    pub start: BlockScheduleStart,
+    /// If some, the number of blocks between cyclic executions
+    pub period: Option<u64>,
+}
+
+/// The first execution height of [`BlockSchedule`]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    Serialize,
+    Deserialize,
+    IntoSchema,
+    Hash,
+)]
+pub enum BlockScheduleStart {
+    /// Execute in the block with the given height
+    Height(u64),
+    /// Execute the given number of blocks after the block in which the trigger is registered
+    ///
+    /// Replaced with [`BlockScheduleStart::Height`] on trigger registration.
+    /// Schedule with this start never matches if it's not resolved.
+    AfterRegistration(u64),
+}
+
+impl BlockSchedule {
+    /// Create new `BlockSchedule` starting at block `height` and without period
+    #[must_use]
+    #[inline]
+    pub const fn at_height(height: u64) -> Self {
+        Self {
+            start: BlockScheduleStart::Height(height),
+            period: None,
+        }
+    }
+
+    /// Create new `BlockSchedule` starting `blocks` after the registration block and without period
+    #[must_use]
+    #[inline]
+    pub const fn after_registration(blocks: u64) -> Self {
+        Self {
+            start: BlockScheduleStart::AfterRegistration(blocks),
+            period: None,
+        }
+    }
+
+    /// Create new `BlockSchedule` matching every block which height is a multiple of `period`
+    #[must_use]
+    #[inline]
+    pub const fn every(period: u64) -> Self {
+        Self::at_height(period).with_period(period)
+    }
+
+    /// Add `period` in blocks to `self`
+    #[must_use]
+    #[inline]
+    pub const fn with_period(mut self, period: u64) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Replace relative start of `self` with the absolute one,
+    /// given that the trigger is registered in the block at `registration_height`
+    #[must_use]
+    pub const fn resolve(mut self, registration_height: u64) -> Self {
+        if let BlockScheduleStart::AfterRegistration(blocks) = self.start {
+            self.start = BlockScheduleStart::Height(registration_height.saturating_add(blocks));
+        }
+        self
+    }
+
+    /// Check if the block at `height` matches `self`
+    pub fn matches(&self, height: u64) -> bool {
+        let start = match self.start {
+            BlockScheduleStart::Height(start) => start,
+            BlockScheduleStart::AfterRegistration(_) => return false,
+        };
+        if height < start {
+            return false;
+        }
+
+        let since_start = height - start;
+        since_start == 0
+            || self
+                .period
+                .and_then(|period| since_start.checked_rem(period))
+                == Some(0)
+    }
 }
 
 /// Time interval in which `TimeAction` should appear
@@ -227,8 +622,9 @@ impl From<Interval> for Range<Duration> {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     pub use super::{
-        Event as TimeEvent, EventFilter as TimeEventFilter, ExecutionTime,
-        Interval as TimeInterval, Schedule as TimeSchedule,
+        BlockSchedule, BlockScheduleStart, Cron, Event as TimeEvent,
+        EventFilter as TimeEventFilter, ExecutionTime, Interval as TimeInterval,
+        Schedule as TimeSchedule,
     };
 }
 
@@ -395,4 +791,133 @@ mod tests {
             assert_eq!(count_matches_in_interval(&schedule, &interval), 7);
         }
     }
+
+    mod cron {
+        use super::*;
+
+        /// 2022-01-01T00:00:00Z, Saturday
+        const NEW_YEAR: u64 = 1_640_995_200;
+        const DAY: u64 = 24 * 60 * 60;
+
+        fn count(expression: &str, since: u64, length: u64) -> u32 {
+            let schedule = Schedule::starting_at(Duration::ZERO)
+                .with_cron(expression.parse().expect("Valid cron expression"));
+            let interval = Interval::new(Duration::from_secs(since), Duration::from_secs(length));
+            count_matches_in_interval(&schedule, &interval)
+        }
+
+        #[test]
+        fn monthly() {
+            // January, February and March of 2022 are 90 days long
+            assert_eq!(count("0 0 1 * *", NEW_YEAR, 90 * DAY), 3);
+            assert_eq!(count("0 0 1 * *", NEW_YEAR + 1, 90 * DAY), 2);
+            assert_eq!(count("0 0 29 2 *", NEW_YEAR, 365 * DAY), 0);
+            assert_eq!(count("0 0 31 * *", NEW_YEAR, 365 * DAY), 7);
+        }
+
+        #[test]
+        fn business_days() {
+            assert_eq!(count("59 23 * * 1-5", NEW_YEAR, 14 * DAY), 10);
+            assert_eq!(count("0 12 * * 0,6", NEW_YEAR, 14 * DAY), 4);
+            assert_eq!(count("0 12 * * 7", NEW_YEAR, 14 * DAY), 2);
+        }
+
+        #[test]
+        fn day_of_month_or_day_of_week() {
+            // 13th of January and Fridays of January 2022
+            assert_eq!(count("0 0 13 1 5", NEW_YEAR, 31 * DAY), 5);
+        }
+
+        #[test]
+        fn steps_and_lists() {
+            assert_eq!(count("*/15 * * * *", NEW_YEAR, 60 * 60), 4);
+            assert_eq!(count("5/20,1 * * * *", NEW_YEAR, 60 * 60), 4);
+            assert_eq!(count("0 9-17/4 * * *", NEW_YEAR, DAY), 3);
+        }
+
+        #[test]
+        fn interval_borders() {
+            assert_eq!(count("0 0 * * *", NEW_YEAR, 60), 1);
+            assert_eq!(count("0 0 * * *", NEW_YEAR - 60, 60), 0);
+        }
+
+        #[test]
+        fn start_is_respected() {
+            let schedule = Schedule::starting_at(Duration::from_secs(NEW_YEAR + 1))
+                .with_cron("0 0 * * *".parse().expect("Valid cron expression"));
+            let interval = Interval::new(Duration::from_secs(NEW_YEAR), Duration::from_secs(DAY));
+            assert_eq!(count_matches_in_interval(&schedule, &interval), 0);
+        }
+
+        #[test]
+        fn display_roundtrip() {
+            let cron: Cron = "*/15 9-17 * 1,2,3 1-5"
+                .parse()
+                .expect("Valid cron expression");
+            let displayed = format!("{cron}");
+            assert_eq!(displayed, "0,15,30,45 9-17 * 1-3 1-5");
+            assert_eq!(
+                displayed.parse::<Cron>().expect("Valid cron expression"),
+                cron
+            );
+        }
+
+        #[test]
+        fn invalid_expressions() {
+            for expression in [
+                "",
+                "* * * *",
+                "* * * * * *",
+                "60 * * * *",
+                "* 24 * * *",
+                "* * 0 * *",
+                "* * * 13 *",
+                "* * * * 8",
+                "*/0 * * * *",
+                "*/60 * * * *",
+                "1/4294967295 * * * *",
+                "* * * * */4294967295",
+                "5-1 * * * *",
+                "a * * * *",
+            ] {
+                assert!(expression.parse::<Cron>().is_err(), "{expression}");
+            }
+        }
+    }
+
+    mod block_schedule {
+        use super::*;
+
+        #[test]
+        fn at_height_matches_once() {
+            let schedule = BlockSchedule::at_height(5);
+            let matched: Vec<_> = (1..20).filter(|h| schedule.matches(*h)).collect();
+            assert_eq!(matched, vec![5]);
+        }
+
+        #[test]
+        fn every_matches_multiples_of_period() {
+            let schedule = BlockSchedule::every(4);
+            let matched: Vec<_> = (1..20).filter(|h| schedule.matches(*h)).collect();
+            assert_eq!(matched, vec![4, 8, 12, 16]);
+        }
+
+        #[test]
+        fn after_registration_matches_only_when_resolved() {
+            let schedule = BlockSchedule::after_registration(3).with_period(2);
+            assert!(!(1..20).any(|h| schedule.matches(h)));
+
+            let schedule = schedule.resolve(10);
+            assert_eq!(schedule.start, BlockScheduleStart::Height(13));
+            let matched: Vec<_> = (1..20).filter(|h| schedule.matches(*h)).collect();
+            assert_eq!(matched, vec![13, 15, 17, 19]);
+        }
+
+        #[test]
+        fn zero_period_matches_only_start() {
+            let schedule = BlockSchedule::at_height(2).with_period(0);
+            let matched: Vec<_> = (1..20).filter(|h| schedule.matches(*h)).collect();
+            assert_eq!(matched, vec![2]);
+        }
+    }
 }
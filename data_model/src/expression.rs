@@ -165,6 +165,31 @@ pub type ValueName = String;
 /// Context, composed of (name, value) pairs.
 pub type Context = btree_map::BTreeMap<ValueName, Value>;
 
+/// The built-in context value name for the height of the block being validated
+/// or applied, or of the latest committed block outside of a block.
+pub const BLOCK_HEIGHT_VALUE: &str = "block_height";
+
+/// The built-in context value name for the timestamp in milliseconds of the block being
+/// validated or applied, or of the latest committed block outside of a block.
+pub const BLOCK_TIMESTAMP_VALUE: &str = "block_timestamp";
+
+/// The built-in context value name for the creator of the transaction being executed.
+pub const TRANSACTION_CREATOR_VALUE: &str = "transaction_creator";
+
+/// The built-in context value name for the hash of the transaction being executed.
+pub const TRANSACTION_HASH_VALUE: &str = "transaction_hash";
+
+/// The context value name for the technical account of the trigger which condition is evaluated.
+pub const TRIGGER_AUTHORITY_VALUE: &str = "trigger_authority";
+
+/// The context value name for the id of the entity which emitted the data event
+/// that triggered the trigger which condition is evaluated.
+pub const TRIGGERING_EVENT_ORIGIN_VALUE: &str = "triggering_event_origin";
+
+/// The context value name for the account which executed by call the trigger
+/// which condition is evaluated.
+pub const TRIGGER_CALLER_VALUE: &str = "trigger_caller";
+
 /// Boxed expression.
 pub type ExpressionBox = Box<Expression>;
 
@@ -58,6 +58,62 @@ pub enum Instruction {
     Revoke(RevokeBox),
     /// `ExecuteTrigger` variant.
     ExecuteTrigger(ExecuteTriggerBox),
+    /// `SetParameter` variant.
+    SetParameter(SetParameterBox),
+    /// `EnableTrigger` variant.
+    EnableTrigger(EnableTriggerBox),
+}
+
+/// Kind of [`Instruction`] without its contents.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+pub enum InstructionKind {
+    /// `Register` variant.
+    Register,
+    /// `Unregister` variant.
+    Unregister,
+    /// `Mint` variant.
+    Mint,
+    /// `Burn` variant.
+    Burn,
+    /// `Transfer` variant.
+    Transfer,
+    /// `If` variant.
+    If,
+    /// `Pair` variant.
+    Pair,
+    /// `Sequence` variant.
+    Sequence,
+    /// `Fail` variant.
+    Fail,
+    /// `SetKeyValue` variant.
+    SetKeyValue,
+    /// `RemoveKeyValue` variant.
+    RemoveKeyValue,
+    /// `Grant` variant.
+    Grant,
+    /// `Revoke` variant.
+    Revoke,
+    /// `ExecuteTrigger` variant.
+    ExecuteTrigger,
+    /// `SetParameter` variant.
+    SetParameter,
+    /// `EnableTrigger` variant.
+    EnableTrigger,
 }
 
 impl Instruction {
@@ -80,6 +136,30 @@ impl Instruction {
             Grant(grant_box) => grant_box.len(),
             Revoke(revoke_box) => revoke_box.len(),
             ExecuteTrigger(execute_trigger) => execute_trigger.len(),
+            SetParameter(set_parameter) => set_parameter.len(),
+            EnableTrigger(enable_trigger) => enable_trigger.len(),
+        }
+    }
+
+    /// Kind of this instruction.
+    pub const fn kind(&self) -> InstructionKind {
+        match self {
+            Instruction::Register(_) => InstructionKind::Register,
+            Instruction::Unregister(_) => InstructionKind::Unregister,
+            Instruction::Mint(_) => InstructionKind::Mint,
+            Instruction::Burn(_) => InstructionKind::Burn,
+            Instruction::Transfer(_) => InstructionKind::Transfer,
+            Instruction::If(_) => InstructionKind::If,
+            Instruction::Pair(_) => InstructionKind::Pair,
+            Instruction::Sequence(_) => InstructionKind::Sequence,
+            Instruction::Fail(_) => InstructionKind::Fail,
+            Instruction::SetKeyValue(_) => InstructionKind::SetKeyValue,
+            Instruction::RemoveKeyValue(_) => InstructionKind::RemoveKeyValue,
+            Instruction::Grant(_) => InstructionKind::Grant,
+            Instruction::Revoke(_) => InstructionKind::Revoke,
+            Instruction::ExecuteTrigger(_) => InstructionKind::ExecuteTrigger,
+            Instruction::SetParameter(_) => InstructionKind::SetParameter,
+            Instruction::EnableTrigger(_) => InstructionKind::EnableTrigger,
         }
     }
 }
@@ -394,6 +474,43 @@ pub struct ExecuteTriggerBox {
     pub trigger_id: TriggerId,
 }
 
+/// Instruction to enable the trigger disabled by its failure policy,
+/// resetting its consecutive failures. Can only be executed by the
+/// technical account of the trigger.
+#[derive(
+    Debug, Display, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, IntoSchema,
+)]
+#[display(fmt = "ENABLE `{trigger_id}`")]
+pub struct EnableTriggerBox {
+    /// Id of a trigger to enable
+    pub trigger_id: TriggerId,
+}
+
+/// Instruction to set an on-chain [`Parameter`], replacing the previous value of the same kind.
+#[derive(
+    Debug, Display, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, IntoSchema,
+)]
+#[display(fmt = "SET PARAMETER `{parameter}`")]
+pub struct SetParameterBox {
+    /// Parameter to set.
+    pub parameter: EvaluatesTo<Parameter>,
+}
+
+impl SetParameterBox {
+    /// Construct [`SetParameterBox`]
+    pub fn new<P: Into<EvaluatesTo<Parameter>>>(parameter: P) -> Self {
+        Self {
+            parameter: parameter.into(),
+        }
+    }
+
+    /// Length of contained instructions and queries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parameter.len() + 1
+    }
+}
+
 impl ExecuteTriggerBox {
     /// Construct [`ExecuteTriggerBox`]
     #[inline]
@@ -408,6 +525,20 @@ impl ExecuteTriggerBox {
     }
 }
 
+impl EnableTriggerBox {
+    /// Construct [`EnableTriggerBox`]
+    #[inline]
+    pub const fn new(trigger_id: TriggerId) -> Self {
+        Self { trigger_id }
+    }
+
+    /// Length of contained instructions and queries.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        1
+    }
+}
+
 impl<O, K, V> SetKeyValue<O, K, V>
 where
     O: Identifiable,
@@ -869,9 +1000,10 @@ mod tests {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Burn, BurnBox, ExecuteTriggerBox, FailBox, Grant, GrantBox, If as IfInstruction,
-        Instruction, Mint, MintBox, Pair, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox,
-        Revoke, RevokeBox, SequenceBox, SetKeyValue, SetKeyValueBox, Transfer, TransferBox,
-        Unregister, UnregisterBox,
+        Burn, BurnBox, EnableTriggerBox, ExecuteTriggerBox, FailBox, Grant, GrantBox,
+        If as IfInstruction, Instruction, InstructionKind, Mint, MintBox, Pair, Register,
+        RegisterBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox, SequenceBox,
+        SetKeyValue, SetKeyValueBox, SetParameterBox, Transfer, TransferBox, Unregister,
+        UnregisterBox,
     };
 }
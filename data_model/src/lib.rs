@@ -61,6 +61,7 @@ pub mod pagination;
 pub mod peer;
 pub mod permissions;
 pub mod predicate;
+pub mod projection;
 pub mod query;
 pub mod role;
 pub mod sorting;
@@ -257,16 +258,54 @@ impl<EXPECTED, GOT> EnumTryAsError<EXPECTED, GOT> {
 #[cfg(feature = "std")]
 impl<EXPECTED: Debug, GOT: Debug> std::error::Error for EnumTryAsError<EXPECTED, GOT> {}
 
+/// Chain identifier used by default.
+pub const DEFAULT_CHAIN_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+/// Identifier of a particular Iroha network.
+///
+/// It is signed as a part of transactions and queries, so that they can't be replayed on another network.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+#[serde(transparent)]
+#[repr(transparent)]
+pub struct ChainId(String);
+
+impl ChainId {
+    /// Construct [`ChainId`].
+    pub fn new(chain_id: impl Into<String>) -> Self {
+        Self(chain_id.into())
+    }
+}
+
+impl Default for ChainId {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHAIN_ID)
+    }
+}
+
 /// Represents Iroha Configuration parameters.
 #[derive(
     Debug,
     Display,
     Clone,
-    Copy,
     PartialEq,
     Eq,
     PartialOrd,
     Ord,
+    Hash,
     Decode,
     Encode,
     Deserialize,
@@ -286,6 +325,9 @@ pub enum Parameter {
     /// Time to wait for a transaction Receipt.
     #[display(fmt = "Transaction receipt time: {}ms", _0)]
     TransactionReceiptTime(u128),
+    /// Fees charged for submitting transactions.
+    #[display(fmt = "Transaction fees: {}", _0)]
+    TransactionFees(transaction::FeeSchedule),
 }
 
 /// Sized container for all possible identifications.
@@ -509,6 +551,12 @@ pub enum Value {
     Ipv4Addr(iroha_primitives::addr::Ipv4Addr),
     /// IP Version 6 address.
     Ipv6Addr(iroha_primitives::addr::Ipv6Addr),
+    /// Entry of an account statement.
+    AccountStatementEntry(Box<account::AccountStatementEntry>),
+    /// Committed transaction with the proof of its inclusion in a block.
+    TransactionProof(Box<transaction::TransactionProof>),
+    /// Reason why a transaction was rejected.
+    TransactionRejectionReason(Box<transaction::TransactionRejectionReason>),
 }
 
 /// Cross-platform wrapper for `BlockValue`.
@@ -605,6 +653,9 @@ impl fmt::Display for Value {
             Value::BlockHeader(v) => fmt::Display::fmt(&v, f),
             Value::Ipv4Addr(v) => fmt::Display::fmt(&v, f),
             Value::Ipv6Addr(v) => fmt::Display::fmt(&v, f),
+            Value::AccountStatementEntry(v) => fmt::Display::fmt(&v, f),
+            Value::TransactionProof(_) => write!(f, "TransactionProof"),
+            Value::TransactionRejectionReason(v) => fmt::Display::fmt(&v, f),
         }
     }
 }
@@ -633,6 +684,9 @@ impl Value {
             | Block(_)
             | Ipv4Addr(_)
             | Ipv6Addr(_)
+            | AccountStatementEntry(_)
+            | TransactionProof(_)
+            | TransactionRejectionReason(_)
             | BlockHeader(_) => 1_usize,
             Vec(v) => v.iter().map(Self::len).sum::<usize>() + 1_usize,
             LimitedMetadata(data) => data.nested_len() + 1_usize,
@@ -880,6 +934,42 @@ impl TryFrom<Value> for BlockValue {
     }
 }
 
+impl TryFrom<Value> for account::AccountStatementEntry {
+    type Error = ErrorTryFromEnum<Value, Self>;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::AccountStatementEntry(entry) = value {
+            return Ok(*entry);
+        }
+
+        Err(Self::Error::default())
+    }
+}
+
+impl TryFrom<Value> for transaction::TransactionProof {
+    type Error = ErrorTryFromEnum<Value, Self>;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::TransactionProof(proof) = value {
+            return Ok(*proof);
+        }
+
+        Err(Self::Error::default())
+    }
+}
+
+impl TryFrom<Value> for transaction::TransactionRejectionReason {
+    type Error = ErrorTryFromEnum<Value, Self>;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::TransactionRejectionReason(reason) = value {
+            return Ok(*reason);
+        }
+
+        Err(Self::Error::default())
+    }
+}
+
 impl<A: SmallArray> TryFrom<Value> for SmallVec<A>
 where
     Value: TryInto<A::Item>,
@@ -1060,10 +1150,10 @@ pub mod prelude {
     pub use super::Registrable;
     pub use super::{
         account::prelude::*, asset::prelude::*, block_value::prelude::*, domain::prelude::*,
-        name::prelude::*, pagination::prelude::*, peer::prelude::*, role::prelude::*,
-        sorting::prelude::*, trigger::prelude::*, EnumTryAsError, HasMetadata, IdBox, Identifiable,
-        IdentifiableBox, Parameter, PredicateTrait, RegistrableBox, TryAsMut, TryAsRef,
-        ValidationError, Value,
+        name::prelude::*, pagination::prelude::*, peer::prelude::*, projection::prelude::*,
+        role::prelude::*, sorting::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError,
+        HasMetadata, IdBox, Identifiable, IdentifiableBox, Parameter, PredicateTrait,
+        RegistrableBox, TryAsMut, TryAsRef, ValidationError, Value,
     };
     pub use crate::{
         events::prelude::*, expression::prelude::*, isi::prelude::*, metadata::prelude::*,
@@ -99,6 +99,12 @@ pub struct Metadata {
 /// A path slice, composed of [`Name`]s.
 pub type Path = [Name];
 
+impl From<UnlimitedMetadata> for Metadata {
+    fn from(map: UnlimitedMetadata) -> Self {
+        Self { map }
+    }
+}
+
 impl Metadata {
     /// Constructor.
     #[inline]
@@ -26,6 +26,7 @@ use warp::{
 
 const PAGINATION_START: &str = "start";
 const PAGINATION_LIMIT: &str = "limit";
+const PAGINATION_CURSOR: &str = "cursor";
 
 /// Describes a collection to which pagination can be applied.
 /// Implemented for the [`Iterator`] implementors.
@@ -107,6 +108,93 @@ impl Pagination {
     }
 }
 
+/// Opaque cursor pointing to the rest of a paginated query result.
+///
+/// The peer keeps the rest of the result, computed on the state of the first
+/// request, until the cursor expires. Pages requested with the cursor are
+/// therefore not affected by the blocks committed in the meantime.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Decode, Encode, IntoSchema,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cursor {
+    query_id: u64,
+    position: u64,
+}
+
+impl Cursor {
+    /// Constructs [`Cursor`].
+    pub const fn new(query_id: u64, position: u64) -> Self {
+        Self { query_id, position }
+    }
+
+    /// Identifier of the query result kept by the peer.
+    pub const fn query_id(&self) -> u64 {
+        self.query_id
+    }
+
+    /// Position of the next page in the query result.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl core::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}-{:x}", self.query_id, self.position)
+    }
+}
+
+impl core::str::FromStr for Cursor {
+    type Err = PaginateError;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        let (query_id, position) = cursor.split_once('-').unwrap_or((cursor, ""));
+        Ok(Self {
+            query_id: u64::from_str_radix(query_id, 16).map_err(PaginateError)?,
+            position: u64::from_str_radix(position, 16).map_err(PaginateError)?,
+        })
+    }
+}
+
+impl TryFrom<String> for Cursor {
+    type Error = PaginateError;
+
+    fn try_from(cursor: String) -> Result<Self, Self::Error> {
+        cursor.parse()
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(cursor: Cursor) -> Self {
+        cursor.to_string()
+    }
+}
+
+/// Structure for requests continuing a paginated query from a [`Cursor`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct CursorRequest {
+    /// cursor returned with the previous page
+    pub cursor: Option<Cursor>,
+}
+
+impl CursorRequest {
+    /// Constructs [`CursorRequest`].
+    pub const fn new(cursor: Option<Cursor>) -> Self {
+        Self { cursor }
+    }
+}
+
+impl From<CursorRequest> for Vec<(&'static str, String)> {
+    fn from(request: CursorRequest) -> Self {
+        request
+            .cursor
+            .map(|cursor| (PAGINATION_CURSOR, cursor.to_string()))
+            .into_iter()
+            .collect()
+    }
+}
+
 /// Error for pagination
 #[derive(Debug, Display, Clone, Eq, PartialEq)]
 #[display(fmt = "Failed to decode pagination. Error: {_0}")]
@@ -128,6 +216,12 @@ pub fn paginate() -> impl Filter<Extract = (Pagination,), Error = Rejection> + C
     warp::query()
 }
 
+#[cfg(feature = "warp")]
+/// Filter for warp which extracts the cursor to continue a query from
+pub fn cursor() -> impl Filter<Extract = (CursorRequest,), Error = Rejection> + Copy {
+    warp::query()
+}
+
 impl From<Pagination> for btree_map::BTreeMap<String, String> {
     fn from(pagination: Pagination) -> Self {
         let mut query_params = Self::new();
@@ -239,6 +333,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cursor_roundtrip() {
+        let cursor = Cursor::new(0xdead, 42);
+        assert_eq!(cursor.to_string(), "dead-2a");
+        assert_eq!(cursor.to_string().parse::<Cursor>(), Ok(cursor));
+        assert!("dead".parse::<Cursor>().is_err());
+    }
+
     #[test]
     fn start_and_limit() {
         assert_eq!(
@@ -7,7 +7,9 @@ use super::*;
 use crate::{IdBox, Name, Value};
 
 /// Predicate combinator enum.
-#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Encode, Decode, IntoSchema,
+)]
 pub enum PredicateBox {
     /// Logically `&&` the results of applying the two predicates.
     And(Vec<PredicateBox>),
@@ -146,7 +148,19 @@ pub mod string {
     use super::*;
 
     /// Predicate useful for processing [`String`]s and [`Name`]s.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub enum Predicate {
         /// Forward to [`str::contains()`]
         Contains(String),
@@ -420,7 +434,19 @@ pub mod numerical {
     use super::*;
 
     /// A lower-inclusive range predicate.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub struct SemiInterval<T: Copy + Ord> {
         /// The start of the range (inclusive)
         start: T,
@@ -444,7 +470,19 @@ pub mod numerical {
     impl Copy for SemiInterval<u64> {}
 
     /// A both-inclusive range predicate
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub struct Interval<T: Copy + Ord> {
         /// The start of the range (inclusive)
         start: T,
@@ -485,7 +523,19 @@ pub mod numerical {
     /// [`Self`] only applies to `Values` that are variants of
     /// compatible types. If the [`Range`] variant and the [`Value`]
     /// variant don't match defaults to `false`.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub enum SemiRange {
         /// 32-bit
         U32(SemiInterval<u32>),
@@ -503,7 +553,19 @@ pub mod numerical {
     /// [`Self`] only applies to `Values` that are variants of
     /// compatible types. If the [`Range`] variant and the [`Value`]
     /// variant don't match defaults to `false`.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub enum Range {
         /// 32-bit
         U32(Interval<u32>),
@@ -788,7 +850,19 @@ pub mod value {
     use super::*;
 
     /// A predicate designed for general processing of `Value`.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub enum Predicate {
         /// Apply predicate to the [`Identifiable::Id`] and/or [`IdBox`].
         Identifiable(string::Predicate),
@@ -806,6 +880,14 @@ pub mod value {
         Ipv6Addr(ip_addr::Ipv6Predicate),
         /// Always return true.
         Pass,
+        /// Apply predicate to the account.
+        Account(account::Predicate),
+        /// Apply predicate to the asset definition.
+        AssetDefinition(asset_definition::Predicate),
+        /// Apply predicate to the trigger.
+        Trigger(trigger::Predicate),
+        /// Apply predicate to the transaction.
+        Transaction(transaction::Predicate),
     }
 
     impl PredicateTrait<&Value> for Predicate {
@@ -874,6 +956,10 @@ pub mod value {
                     _ => false,
                 },
                 Predicate::Pass => true,
+                Predicate::Account(pred) => pred.applies(input),
+                Predicate::AssetDefinition(pred) => pred.applies(input),
+                Predicate::Trigger(pred) => pred.applies(input),
+                Predicate::Transaction(pred) => pred.applies(input),
             }
         }
     }
@@ -922,14 +1008,38 @@ pub mod value {
     }
 
     /// A predicate that targets the particular `index` of a collection.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub struct AtIndex {
         index: u32,
         predicate: Box<Predicate>,
     }
 
     /// A predicate that targets the particular `key` of a collection.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub struct ValueOfKey {
         key: Name,
         predicate: Box<Predicate>,
@@ -939,7 +1049,19 @@ pub mod value {
     /// working with containers. Currently only
     /// [`Metadata`](crate::metadata::Metadata) and [`Vec<Value>`] are
     /// supported.
-    #[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, IntoSchema)]
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
     pub enum Container {
         /// Forward to [`Iterator::any`]
         Any(Box<Predicate>),
@@ -1082,7 +1204,20 @@ pub mod ip_addr {
 
     /// A Predicate containing independent octuplet masks to be
     /// applied to all elements of an IP version 4 address.
-    #[derive(Debug, Clone, Copy, Encode, Decode, IntoSchema, Serialize, Deserialize)]
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Encode,
+        Decode,
+        IntoSchema,
+        Serialize,
+        Deserialize,
+    )]
     pub struct Ipv4Predicate([Mask<u8>; 4]);
 
     impl PredicateTrait<Ipv4Addr> for Ipv4Predicate {
@@ -1115,7 +1250,20 @@ pub mod ip_addr {
     /// A Predicate containing independent _hexadecuplets_ (u16
     /// groups) masks to be applied to all elements of an IP version 6
     /// address.
-    #[derive(Debug, Clone, Copy, Encode, Decode, IntoSchema, Serialize, Deserialize)]
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Encode,
+        Decode,
+        IntoSchema,
+        Serialize,
+        Deserialize,
+    )]
     pub struct Ipv6Predicate([Mask<u16>; 8]);
 
     impl PredicateTrait<Ipv6Addr> for Ipv6Predicate {
@@ -1211,3 +1359,464 @@ pub mod ip_addr {
         }
     }
 }
+
+pub mod account {
+    //! Predicates for [`Account`](crate::account::Account)s.
+    use super::*;
+    use crate::{
+        account::Account,
+        asset::{AssetValue, DefinitionId as AssetDefinitionId},
+        role::Id as RoleId,
+    };
+
+    /// Predicate applied to accounts. Doesn't apply to other values.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
+    pub enum Predicate {
+        /// The account has an asset of the definition, with a quantity in the range.
+        HasAsset(AssetQuantity),
+        /// The account has the role.
+        HasRole(RoleId),
+    }
+
+    /// Quantity range of the asset of a particular definition.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
+    pub struct AssetQuantity {
+        definition_id: AssetDefinitionId,
+        quantity: numerical::Range,
+    }
+
+    impl Predicate {
+        /// Construct [`Predicate::HasAsset`] variant.
+        #[inline]
+        #[must_use]
+        pub fn has_asset(definition_id: AssetDefinitionId, quantity: numerical::Range) -> Self {
+            Self::HasAsset(AssetQuantity {
+                definition_id,
+                quantity,
+            })
+        }
+    }
+
+    impl PredicateTrait<&Account> for Predicate {
+        fn applies(&self, input: &Account) -> bool {
+            match self {
+                Predicate::HasAsset(AssetQuantity {
+                    definition_id,
+                    quantity,
+                }) => input
+                    .assets()
+                    .filter(|asset| &asset.id().definition_id == definition_id)
+                    .any(|asset| {
+                        // Integer quantities are compared as `u128`, like by `SortField::Quantity`,
+                        // so that a range of either integer type applies to both kinds of assets
+                        let value = match asset.value() {
+                            AssetValue::Quantity(value) => u128::from(*value),
+                            AssetValue::BigQuantity(value) => *value,
+                            AssetValue::Fixed(value) => {
+                                return quantity.applies(&Value::Fixed(*value))
+                            }
+                            AssetValue::Store(_) => return false,
+                        };
+                        match quantity {
+                            numerical::Range::U32(interval) => {
+                                u32::try_from(value).map_or(false, |value| interval.applies(value))
+                            }
+                            numerical::Range::U128(interval) => interval.applies(value),
+                            numerical::Range::Fixed(_) => false,
+                        }
+                    }),
+                Predicate::HasRole(role_id) => input.contains_role(role_id),
+            }
+        }
+    }
+
+    impl PredicateTrait<&Value> for Predicate {
+        fn applies(&self, input: &Value) -> bool {
+            match input {
+                Value::Identifiable(IdentifiableBox::Account(account)) => self.applies(&**account),
+                _ => false,
+            }
+        }
+    }
+
+    impl From<Predicate> for value::Predicate {
+        fn from(pred: Predicate) -> Self {
+            value::Predicate::Account(pred)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "mutable_api")]
+        fn asset_quantity_and_role() {
+            use iroha_primitives::fixed::Fixed;
+
+            use crate::{
+                asset::{Asset, Id as AssetId},
+                Registrable as _,
+            };
+
+            let mut account = Account::new("alice@wonderland".parse().expect("Valid"), []).build();
+            let rose: AssetDefinitionId = "rose#wonderland".parse().expect("Valid");
+            account.add_asset(Asset::new(
+                AssetId::new(rose.clone(), account.id().clone()),
+                AssetValue::Quantity(13),
+            ));
+            account.add_role("admin".parse().expect("Valid"));
+            let account = Value::Identifiable(IdentifiableBox::Account(Box::new(account)));
+
+            let in_range = numerical::Range::U32((10, 20).into());
+            let out_of_range = numerical::Range::U32((14, 20).into());
+            let big_in_range = numerical::Range::U128((10, 20).into());
+            let wrong_type = numerical::Range::Fixed(
+                (
+                    Fixed::try_from(10_f64).expect("Valid"),
+                    Fixed::try_from(20_f64).expect("Valid"),
+                )
+                    .into(),
+            );
+            assert!(Predicate::has_asset(rose.clone(), in_range.clone()).applies(&account));
+            assert!(!Predicate::has_asset(rose.clone(), out_of_range).applies(&account));
+            assert!(Predicate::has_asset(rose.clone(), big_in_range).applies(&account));
+            assert!(!Predicate::has_asset(rose, wrong_type).applies(&account));
+            assert!(
+                !Predicate::has_asset("tulip#wonderland".parse().expect("Valid"), in_range)
+                    .applies(&account)
+            );
+
+            assert!(Predicate::HasRole("admin".parse().expect("Valid")).applies(&account));
+            assert!(!Predicate::HasRole("user".parse().expect("Valid")).applies(&account));
+            assert!(!Predicate::HasRole("admin".parse().expect("Valid")).applies(&Value::U32(1)));
+        }
+
+        #[test]
+        #[cfg(feature = "mutable_api")]
+        fn big_quantity_matches_either_integer_range() {
+            use crate::{
+                asset::{Asset, Id as AssetId},
+                Registrable as _,
+            };
+
+            let mut account = Account::new("alice@wonderland".parse().expect("Valid"), []).build();
+            let gold: AssetDefinitionId = "gold#wonderland".parse().expect("Valid");
+            account.add_asset(Asset::new(
+                AssetId::new(gold.clone(), account.id().clone()),
+                AssetValue::BigQuantity(13),
+            ));
+
+            assert!(
+                Predicate::has_asset(gold.clone(), numerical::Range::U32((10, 20).into()))
+                    .applies(&account)
+            );
+            assert!(
+                Predicate::has_asset(gold.clone(), numerical::Range::U128((10, 20).into()))
+                    .applies(&account)
+            );
+            assert!(
+                !Predicate::has_asset(gold, numerical::Range::U128((14, 20).into()))
+                    .applies(&account)
+            );
+        }
+    }
+}
+
+pub mod asset_definition {
+    //! Predicates for [`AssetDefinition`](crate::asset::AssetDefinition)s.
+    use super::*;
+    use crate::asset::{AssetDefinition, AssetValueType, Mintable};
+
+    /// Predicate applied to asset definitions. Doesn't apply to other values.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
+    pub enum Predicate {
+        /// The asset definition has the mintability.
+        Mintable(Mintable),
+        /// The asset definition has the value type.
+        ValueType(AssetValueType),
+    }
+
+    impl PredicateTrait<&AssetDefinition> for Predicate {
+        fn applies(&self, input: &AssetDefinition) -> bool {
+            match self {
+                Predicate::Mintable(mintable) => input.mintable() == mintable,
+                Predicate::ValueType(value_type) => input.value_type() == *value_type,
+            }
+        }
+    }
+
+    impl PredicateTrait<&Value> for Predicate {
+        fn applies(&self, input: &Value) -> bool {
+            match input {
+                Value::Identifiable(IdentifiableBox::AssetDefinition(definition)) => {
+                    self.applies(&**definition)
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl From<Predicate> for value::Predicate {
+        fn from(pred: Predicate) -> Self {
+            value::Predicate::AssetDefinition(pred)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "mutable_api")]
+        fn mintability() {
+            use crate::Registrable as _;
+
+            let id = "rose#wonderland".parse().expect("Valid");
+            let definitions: Vec<Value> = vec![
+                AssetDefinition::quantity(id).mintable_once().build().into(),
+                AssetDefinition::fixed("tulip#wonderland".parse().expect("Valid"))
+                    .build()
+                    .into(),
+            ];
+            let filter = PredicateBox::new(Predicate::Mintable(Mintable::Once));
+
+            assert_eq!(
+                filter.filter(Value::Vec(definitions.clone())),
+                Value::Vec(vec![definitions[0].clone()])
+            );
+            assert!(
+                PredicateBox::new(Predicate::ValueType(AssetValueType::Fixed))
+                    .applies(&definitions[1])
+            );
+        }
+    }
+}
+
+pub mod trigger {
+    //! Predicates for [`Trigger`](crate::trigger::Trigger)s.
+    use super::*;
+    use crate::{
+        events::FilterBox,
+        trigger::{action::Repeats, Trigger},
+    };
+
+    /// Predicate applied to triggers. Doesn't apply to other values.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
+    pub enum Predicate {
+        /// The trigger repeats until it's unregistered.
+        RepeatsIndefinitely,
+        /// The number of the remaining repetitions of the trigger is in the interval.
+        RepeatsRemaining(numerical::Interval<u32>),
+    }
+
+    impl PredicateTrait<&Trigger<FilterBox>> for Predicate {
+        fn applies(&self, input: &Trigger<FilterBox>) -> bool {
+            match (self, &input.action.repeats) {
+                (Predicate::RepeatsIndefinitely, Repeats::Indefinitely) => true,
+                (Predicate::RepeatsRemaining(interval), Repeats::Exactly(remaining)) => {
+                    interval.applies(remaining.get())
+                }
+                _ => false,
+            }
+        }
+    }
+
+    impl PredicateTrait<&Value> for Predicate {
+        fn applies(&self, input: &Value) -> bool {
+            match input {
+                Value::Identifiable(IdentifiableBox::Trigger(trigger)) => self.applies(&**trigger),
+                _ => false,
+            }
+        }
+    }
+
+    impl From<Predicate> for value::Predicate {
+        fn from(pred: Predicate) -> Self {
+            value::Predicate::Trigger(pred)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{events::pipeline::EventFilter as PipelineEventFilter, isi::Instruction};
+
+        fn trigger(repeats: Repeats) -> Value {
+            let action = crate::trigger::action::Action::new(
+                Vec::<Instruction>::new(),
+                repeats,
+                "alice@wonderland".parse().expect("Valid"),
+                FilterBox::Pipeline(PipelineEventFilter::new()),
+            );
+            let trigger = Trigger::new("mint_rose".parse().expect("Valid"), action);
+            Value::Identifiable(IdentifiableBox::Trigger(Box::new(trigger)))
+        }
+
+        #[test]
+        fn repetitions() {
+            let indefinite = trigger(Repeats::Indefinitely);
+            let three_times = trigger(Repeats::from(3));
+
+            assert!(Predicate::RepeatsIndefinitely.applies(&indefinite));
+            assert!(!Predicate::RepeatsIndefinitely.applies(&three_times));
+
+            let in_range = Predicate::RepeatsRemaining((2, 5).into());
+            let out_of_range = Predicate::RepeatsRemaining((4, 5).into());
+            assert!(in_range.applies(&three_times));
+            assert!(!out_of_range.applies(&three_times));
+            assert!(!in_range.applies(&indefinite));
+            assert!(!Predicate::RepeatsIndefinitely.applies(&Value::U32(1)));
+        }
+    }
+}
+
+pub mod transaction {
+    //! Predicates for transactions.
+    use super::*;
+    use crate::transaction::TransactionValue;
+
+    /// Predicate applied to [`TransactionValue`]s and
+    /// [`TransactionQueryResult`]s. Doesn't apply to other values.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Serialize,
+        Deserialize,
+        Encode,
+        Decode,
+        IntoSchema,
+    )]
+    pub enum Predicate {
+        /// The transaction was committed.
+        Committed,
+        /// The transaction was rejected.
+        Rejected,
+    }
+
+    impl PredicateTrait<&TransactionValue> for Predicate {
+        fn applies(&self, input: &TransactionValue) -> bool {
+            matches!(
+                (self, input),
+                (Predicate::Committed, TransactionValue::Transaction(_))
+                    | (
+                        Predicate::Rejected,
+                        TransactionValue::RejectedTransaction(_)
+                    )
+            )
+        }
+    }
+
+    impl PredicateTrait<&Value> for Predicate {
+        fn applies(&self, input: &Value) -> bool {
+            match input {
+                Value::TransactionValue(tx) => self.applies(tx),
+                Value::TransactionQueryResult(tx) => self.applies(&tx.tx_value),
+                _ => false,
+            }
+        }
+    }
+
+    impl From<Predicate> for value::Predicate {
+        fn from(pred: Predicate) -> Self {
+            value::Predicate::Transaction(pred)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use iroha_crypto::{KeyPair, SignaturesOf};
+
+        use super::*;
+        use crate::{
+            isi::Instruction,
+            transaction::{
+                NotPermittedFail, RejectedTransaction, Transaction, TransactionRejectionReason,
+            },
+        };
+
+        #[test]
+        fn committed_and_rejected() {
+            let tx = Transaction::new(
+                "alice@wonderland".parse().expect("Valid"),
+                Vec::<Instruction>::new().into(),
+                1000,
+            )
+            .sign(KeyPair::generate().expect("Valid"))
+            .expect("Valid");
+            let rejected = RejectedTransaction {
+                payload: tx.payload.clone(),
+                signatures: SignaturesOf::try_from(tx.signatures.clone()).expect("Signed"),
+                rejection_reason: TransactionRejectionReason::NotPermitted(NotPermittedFail::new(
+                    "Not allowed",
+                )),
+            };
+            let committed =
+                Value::TransactionValue(TransactionValue::Transaction(Box::new(tx.into())));
+            let rejected = Value::TransactionValue(TransactionValue::RejectedTransaction(
+                Box::new(rejected.into()),
+            ));
+
+            assert!(Predicate::Committed.applies(&committed));
+            assert!(!Predicate::Committed.applies(&rejected));
+            assert!(Predicate::Rejected.applies(&rejected));
+            assert!(!Predicate::Rejected.applies(&committed));
+            assert!(!Predicate::Committed.applies(&Value::U32(1)));
+        }
+    }
+}
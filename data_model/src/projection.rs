@@ -0,0 +1,179 @@
+//! Structures and traits related to projections of query results.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use iroha_schema::IntoSchema;
+use iroha_version::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "warp")]
+use warp::{Filter, Rejection};
+
+use crate::{metadata::UnlimitedMetadata, prelude::*};
+
+const SELECT: &str = "select";
+
+/// Fields of the query result entries which are sent back to the
+/// client instead of the whole entries. The fields are the same as
+/// the ones by which results can be sorted.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Decode, Encode, IntoSchema,
+)]
+pub struct Projection {
+    /// Fields to select. The entries are returned as is, if empty.
+    #[serde(default, with = "fields")]
+    pub select: Vec<SortField>,
+}
+
+impl Projection {
+    /// Creates a projection which selects `fields`.
+    pub fn fields(fields: impl IntoIterator<Item = SortField>) -> Self {
+        Self {
+            select: fields.into_iter().collect(),
+        }
+    }
+
+    /// Whether this projection returns the entries as is.
+    pub fn is_empty(&self) -> bool {
+        self.select.is_empty()
+    }
+
+    /// Project `value`, or each of its entries if it's a [`Value::Vec`].
+    ///
+    /// Every entry is replaced with [`Value::LimitedMetadata`] which
+    /// maps the names of the selected fields to their values. Fields
+    /// which the entry doesn't have are left out.
+    pub fn project(&self, value: Value) -> Value {
+        if self.is_empty() {
+            return value;
+        }
+        match value {
+            Value::Vec(entries) => Value::Vec(
+                entries
+                    .into_iter()
+                    .map(|entry| self.project_entry(&entry))
+                    .collect(),
+            ),
+            entry => self.project_entry(&entry),
+        }
+    }
+
+    fn project_entry(&self, entry: &Value) -> Value {
+        let fields: UnlimitedMetadata = self
+            .select
+            .iter()
+            .filter_map(|field| {
+                let name = Name::from_str(&field.to_string()).ok()?;
+                field.extract(entry).map(|value| (name, value))
+            })
+            .collect();
+        Value::LimitedMetadata(fields.into())
+    }
+}
+
+impl From<Projection> for Vec<(&'static str, String)> {
+    fn from(projection: Projection) -> Self {
+        if projection.is_empty() {
+            return Vec::new();
+        }
+        vec![(SELECT, fields::to_string(&projection.select))]
+    }
+}
+
+/// (De)serialization of selected fields as a comma-separated string,
+/// so that they fit into a single URL query parameter.
+mod fields {
+    #[cfg(not(feature = "std"))]
+    use alloc::{
+        string::{String, ToString as _},
+        vec::Vec,
+    };
+
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use super::SortField;
+
+    pub fn to_string(fields: &[SortField]) -> String {
+        fields
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn serialize<S: Serializer>(
+        fields: &[SortField],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_string(fields))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<SortField>, D::Error> {
+        let fields = String::deserialize(deserializer)?;
+        fields
+            .split(',')
+            .filter(|field| !field.is_empty())
+            .map(|field| field.parse().map_err(D::Error::custom))
+            .collect()
+    }
+}
+
+#[cfg(feature = "warp")]
+/// Filter for warp which extracts projection
+pub fn projection() -> impl Filter<Extract = (Projection,), Error = Rejection> + Copy {
+    warp::query()
+}
+
+pub mod prelude {
+    //! Prelude: re-export most commonly used traits, structs and macros from this module.
+    pub use super::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_id_and_quantity() {
+        let id = AssetId::new(
+            "rose#wonderland".parse().expect("Valid"),
+            "alice@wonderland".parse().expect("Valid"),
+        );
+        let assets = Value::Vec(vec![
+            Asset::new(id.clone(), AssetValue::Quantity(13)).into(),
+            Asset::new(id.clone(), AssetValue::Store(Metadata::new())).into(),
+        ]);
+
+        let projected = Projection::fields([SortField::Id, SortField::Quantity]).project(assets);
+
+        let expected = |quantity: Option<u128>| {
+            let mut fields = UnlimitedMetadata::new();
+            fields.insert(
+                "id".parse().expect("Valid"),
+                IdBox::AssetId(id.clone()).into(),
+            );
+            if let Some(quantity) = quantity {
+                fields.insert("quantity".parse().expect("Valid"), Value::U128(quantity));
+            }
+            Value::LimitedMetadata(fields.into())
+        };
+        assert_eq!(
+            projected,
+            Value::Vec(vec![expected(Some(13)), expected(None)])
+        );
+    }
+
+    #[test]
+    fn empty_projection_keeps_entries() {
+        let value = Value::Vec(vec![Value::U32(1), Value::U32(2)]);
+        assert_eq!(Projection::default().project(value.clone()), value);
+        assert!(Vec::<(&str, String)>::from(Projection::default()).is_empty());
+    }
+}
@@ -3,10 +3,16 @@
 #![allow(clippy::missing_inline_in_public_items, unused_imports)]
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
 
 use derive_more::Display;
-use iroha_crypto::SignatureOf;
+use iroha_crypto::{PublicKey, SignatureOf};
 use iroha_macro::FromVariant;
 use iroha_schema::prelude::*;
 use iroha_version::prelude::*;
@@ -14,11 +20,14 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use self::{
-    account::*, asset::*, block::*, domain::*, peer::*, permissions::*, role::*, transaction::*,
-    trigger::*,
+    account::*, aggregate::*, asset::*, batch::*, block::*, domain::*, peer::*, permissions::*,
+    role::*, transaction::*, trigger::*,
 };
 use crate::{
-    account::Account, pagination::Pagination, predicate::PredicateBox, Identifiable, Value,
+    account::Account,
+    pagination::{Cursor, Pagination},
+    predicate::PredicateBox,
+    ChainId, Identifiable, Value,
 };
 
 /// Sized container for all possible Queries.
@@ -51,6 +60,8 @@ pub enum QueryBox {
     FindAccountsByDomainId(FindAccountsByDomainId),
     /// [`FindAccountsWithAsset`] variant.
     FindAccountsWithAsset(FindAccountsWithAsset),
+    /// [`FindAccountNextSequenceNumber`] variant.
+    FindAccountNextSequenceNumber(FindAccountNextSequenceNumber),
     /// [`FindAllAssets`] variant.
     FindAllAssets(FindAllAssets),
     /// [`FindAllAssetsDefinitions`] variant.
@@ -83,6 +94,8 @@ pub enum QueryBox {
     FindDomainKeyValueByIdAndKey(FindDomainKeyValueByIdAndKey),
     /// [`FindAllPeers`] variant.
     FindAllPeers(FindAllPeers),
+    /// [`FindAllParameters`] variant.
+    FindAllParameters(FindAllParameters),
     /// [`FindAllBlocks`] variant.
     FindAllBlocks(FindAllBlocks),
     /// [`FindAllBlockHeaders`] variant.
@@ -115,6 +128,30 @@ pub enum QueryBox {
     FindRoleByRoleId(FindRoleByRoleId),
     /// [`FindRolesByAccountId`] variant.
     FindRolesByAccountId(FindRolesByAccountId),
+    /// [`FindAggregate`] variant.
+    FindAggregate(FindAggregate),
+    /// [`FindBatch`] variant.
+    FindBatch(FindBatch),
+    /// [`FindAccountsByMetadataKeyValue`] variant.
+    FindAccountsByMetadataKeyValue(FindAccountsByMetadataKeyValue),
+    /// [`FindAssetsByMetadataKeyValue`] variant.
+    FindAssetsByMetadataKeyValue(FindAssetsByMetadataKeyValue),
+    /// [`FindAssetDefinitionsByMetadataKeyValue`] variant.
+    FindAssetDefinitionsByMetadataKeyValue(FindAssetDefinitionsByMetadataKeyValue),
+    /// [`FindBlockByHeight`] variant.
+    FindBlockByHeight(FindBlockByHeight),
+    /// [`FindBlocksInHeightRange`] variant.
+    FindBlocksInHeightRange(FindBlocksInHeightRange),
+    /// [`FindTransactionsInBlock`] variant.
+    FindTransactionsInBlock(FindTransactionsInBlock),
+    /// [`FindLatestBlockHeader`] variant.
+    FindLatestBlockHeader(FindLatestBlockHeader),
+    /// [`FindAccountStatement`] variant.
+    FindAccountStatement(FindAccountStatement),
+    /// [`FindTransactionProofByHash`] variant.
+    FindTransactionProofByHash(FindTransactionProofByHash),
+    /// [`FindTransactionRejectionReasonByHash`] variant.
+    FindTransactionRejectionReasonByHash(FindTransactionRejectionReasonByHash),
 }
 
 /// Trait for typesafe query output
@@ -130,6 +167,8 @@ impl Query for QueryBox {
 /// Payload of a query.
 #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
 pub struct Payload {
+    /// Identifier of the network this query is meant for.
+    pub chain_id: ChainId,
     /// Timestamp of the query creation.
     #[codec(compact)]
     pub timestamp_ms: u128,
@@ -191,6 +230,109 @@ pub struct PaginatedQueryResult {
     pub pagination: Pagination,
     /// Total query amount (if applicable) else 0.
     pub total: u64,
+    /// Cursor to the next page, if the peer keeps the rest of the result.
+    pub cursor: Option<Cursor>,
+    /// Signature of the peer over the response, if it was requested.
+    pub signature: Option<QueryResponseSignature>,
+}
+
+impl PaginatedQueryResult {
+    /// Public key of the peer which signed this response for the query
+    /// with `query_hash`, if the signature is present and valid.
+    ///
+    /// `pagination`, `sorting` and `projection` are the ones the query was
+    /// sent with, so a response to the query with other parameters isn't
+    /// taken as signed.
+    #[cfg(feature = "std")]
+    pub fn signed_by(
+        &self,
+        query_hash: iroha_crypto::HashOf<Payload>,
+        pagination: Pagination,
+        sorting: &Sorting,
+        projection: &Projection,
+    ) -> Option<&PublicKey> {
+        let QueryResponseSignature {
+            block_height,
+            signature,
+        } = self.signature.as_ref()?;
+        let payload = QueryResponsePayload {
+            query_hash,
+            pagination,
+            sorting: sorting.clone(),
+            projection: projection.clone(),
+            result: self.result.clone(),
+            total: self.total,
+            cursor: self.cursor,
+            block_height: *block_height,
+        };
+        signature
+            .verify(&payload)
+            .ok()
+            .map(|()| signature.public_key())
+    }
+}
+
+/// Contents of a query response which the peer signs, so that the
+/// responses of several peers can be compared and kept as evidence.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct QueryResponsePayload {
+    /// Hash of the payload of the query.
+    pub query_hash: iroha_crypto::HashOf<Payload>,
+    /// Pagination the query was sent with.
+    pub pagination: Pagination,
+    /// Sorting the query was sent with.
+    pub sorting: Sorting,
+    /// Projection the query was sent with.
+    pub projection: Projection,
+    /// The result of the query execution, after filtering and pagination.
+    pub result: QueryResult,
+    /// Total number of results before pagination.
+    pub total: u64,
+    /// Cursor to the next page, if the peer keeps the rest of the result.
+    pub cursor: Option<Cursor>,
+    /// Height of the latest block committed when the query was executed.
+    pub block_height: u64,
+}
+
+/// Signature of the peer over a [`QueryResponsePayload`].
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct QueryResponseSignature {
+    /// Height of the latest block committed when the query was executed.
+    pub block_height: u64,
+    /// Signature of the peer.
+    pub signature: SignatureOf<QueryResponsePayload>,
+}
+
+/// Structure for requests asking the peer to sign the query response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct ResponseSigningRequest {
+    /// Whether the peer should sign the response with its key.
+    #[serde(default)]
+    pub sign_response: bool,
+}
+
+impl ResponseSigningRequest {
+    /// Constructs [`ResponseSigningRequest`].
+    pub const fn new(sign_response: bool) -> Self {
+        Self { sign_response }
+    }
+}
+
+impl From<ResponseSigningRequest> for Vec<(&'static str, String)> {
+    fn from(request: ResponseSigningRequest) -> Self {
+        if request.sign_response {
+            vec![("sign_response", true.to_string())]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(feature = "warp")]
+/// Filter for warp which extracts whether the response should be signed
+pub fn response_signing(
+) -> impl warp::Filter<Extract = (ResponseSigningRequest,), Error = warp::Rejection> + Copy {
+    warp::query()
 }
 
 #[cfg(all(feature = "std", feature = "warp"))]
@@ -204,6 +346,7 @@ impl QueryRequest {
         let timestamp_ms = crate::current_time().as_millis();
         Self {
             payload: Payload {
+                chain_id: ChainId::default(),
                 timestamp_ms,
                 query,
                 account_id,
@@ -212,6 +355,13 @@ impl QueryRequest {
         }
     }
 
+    /// Sets identifier of the network this query is meant for.
+    #[must_use]
+    pub fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.payload.chain_id = chain_id;
+        self
+    }
+
     /// Consumes self and returns a signed [`QueryRequest`].
     ///
     /// # Errors
@@ -613,6 +763,60 @@ pub mod account {
         type Output = Vec<Account>;
     }
 
+    /// [`FindAccountNextSequenceNumber`] Iroha Query finds the sequence number
+    /// expected in the next transaction of an [`Account`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find next sequence number of `{}` account", id)]
+    pub struct FindAccountNextSequenceNumber {
+        /// `Id` of an account to find.
+        pub id: EvaluatesTo<AccountId>,
+    }
+
+    impl Query for FindAccountNextSequenceNumber {
+        type Output = u32;
+    }
+
+    /// [`FindAccountsByMetadataKeyValue`] Iroha Query finds all [`Account`]s
+    /// with the given value under the given metadata key.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find accounts with `{}` under `{}` metadata key", value, key)]
+    pub struct FindAccountsByMetadataKeyValue {
+        /// Metadata key to look up.
+        pub key: EvaluatesTo<Name>,
+        /// Value under the metadata key.
+        pub value: EvaluatesTo<Value>,
+    }
+
+    impl Query for FindAccountsByMetadataKeyValue {
+        type Output = Vec<Account>;
+    }
+
     impl FindAllAccounts {
         /// Construct [`FindAllAccounts`].
         pub const fn new() -> Self {
@@ -666,10 +870,98 @@ pub mod account {
         }
     }
 
+    impl FindAccountNextSequenceNumber {
+        /// Construct [`FindAccountNextSequenceNumber`].
+        pub fn new(id: impl Into<EvaluatesTo<AccountId>>) -> Self {
+            let id = id.into();
+            FindAccountNextSequenceNumber { id }
+        }
+    }
+
+    impl FindAccountsByMetadataKeyValue {
+        /// Construct [`FindAccountsByMetadataKeyValue`].
+        pub fn new(
+            key: impl Into<EvaluatesTo<Name>>,
+            value: impl Into<EvaluatesTo<Value>>,
+        ) -> Self {
+            let key = key.into();
+            let value = value.into();
+            Self { key, value }
+        }
+    }
+
+    /// [`FindAccountStatement`] Iroha Query finds the chronological history of
+    /// changes of the quantities of the account's assets, including the ones
+    /// received from other accounts.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find statement of `{}` account", account_id)]
+    pub struct FindAccountStatement {
+        /// `Id` of the account.
+        pub account_id: EvaluatesTo<AccountId>,
+        /// Only include changes of the assets of this definition.
+        pub asset_definition_id: Option<EvaluatesTo<AssetDefinitionId>>,
+        /// Only include changes made in blocks at this height or later.
+        pub start_height: Option<EvaluatesTo<u128>>,
+        /// Only include changes made in blocks at this height or earlier.
+        pub end_height: Option<EvaluatesTo<u128>>,
+    }
+
+    impl Query for FindAccountStatement {
+        type Output = Vec<AccountStatementEntry>;
+    }
+
+    impl FindAccountStatement {
+        /// Construct [`FindAccountStatement`] covering all assets of the account.
+        pub fn new(account_id: impl Into<EvaluatesTo<AccountId>>) -> Self {
+            Self {
+                account_id: account_id.into(),
+                asset_definition_id: None,
+                start_height: None,
+                end_height: None,
+            }
+        }
+
+        /// Include only the changes of the assets of `asset_definition_id`.
+        #[must_use]
+        pub fn with_asset_definition(
+            mut self,
+            asset_definition_id: impl Into<EvaluatesTo<AssetDefinitionId>>,
+        ) -> Self {
+            self.asset_definition_id = Some(asset_definition_id.into());
+            self
+        }
+
+        /// Include only the changes made in blocks from `start` to `end` height inclusive.
+        #[must_use]
+        pub fn with_height_range(
+            mut self,
+            start: impl Into<EvaluatesTo<u128>>,
+            end: impl Into<EvaluatesTo<u128>>,
+        ) -> Self {
+            self.start_height = Some(start.into());
+            self.end_height = Some(end.into());
+            self
+        }
+    }
+
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
         pub use super::{
-            FindAccountById, FindAccountKeyValueByIdAndKey, FindAccountsByDomainId,
+            FindAccountById, FindAccountKeyValueByIdAndKey, FindAccountNextSequenceNumber,
+            FindAccountStatement, FindAccountsByDomainId, FindAccountsByMetadataKeyValue,
             FindAccountsByName, FindAccountsWithAsset, FindAllAccounts,
         };
     }
@@ -1091,6 +1383,66 @@ pub mod asset {
         }
     }
 
+    /// [`FindAssetsByMetadataKeyValue`] Iroha Query finds all store [`Asset`]s
+    /// with the given value under the given metadata key.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find assets with `{}` under `{}` metadata key", value, key)]
+    pub struct FindAssetsByMetadataKeyValue {
+        /// Metadata key to look up.
+        pub key: EvaluatesTo<Name>,
+        /// Value under the metadata key.
+        pub value: EvaluatesTo<Value>,
+    }
+
+    impl Query for FindAssetsByMetadataKeyValue {
+        type Output = Vec<Asset>;
+    }
+
+    /// [`FindAssetDefinitionsByMetadataKeyValue`] Iroha Query finds all [`AssetDefinition`]s
+    /// with the given value under the given metadata key.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(
+        fmt = "Find asset definitions with `{}` under `{}` metadata key",
+        value,
+        key
+    )]
+    pub struct FindAssetDefinitionsByMetadataKeyValue {
+        /// Metadata key to look up.
+        pub key: EvaluatesTo<Name>,
+        /// Value under the metadata key.
+        pub value: EvaluatesTo<Value>,
+    }
+
+    impl Query for FindAssetDefinitionsByMetadataKeyValue {
+        type Output = Vec<AssetDefinition>;
+    }
+
     impl FindAssetQuantityById {
         /// Construct [`FindAssetQuantityById`].
         pub fn new(id: impl Into<EvaluatesTo<AssetId>>) -> Self {
@@ -1120,13 +1472,39 @@ pub mod asset {
         }
     }
 
+    impl FindAssetsByMetadataKeyValue {
+        /// Construct [`FindAssetsByMetadataKeyValue`].
+        pub fn new(
+            key: impl Into<EvaluatesTo<Name>>,
+            value: impl Into<EvaluatesTo<Value>>,
+        ) -> Self {
+            let key = key.into();
+            let value = value.into();
+            Self { key, value }
+        }
+    }
+
+    impl FindAssetDefinitionsByMetadataKeyValue {
+        /// Construct [`FindAssetDefinitionsByMetadataKeyValue`].
+        pub fn new(
+            key: impl Into<EvaluatesTo<Name>>,
+            value: impl Into<EvaluatesTo<Value>>,
+        ) -> Self {
+            let key = key.into();
+            let value = value.into();
+            Self { key, value }
+        }
+    }
+
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
         pub use super::{
             FindAllAssets, FindAllAssetsDefinitions, FindAssetById, FindAssetDefinitionById,
-            FindAssetDefinitionKeyValueByIdAndKey, FindAssetKeyValueByIdAndKey,
-            FindAssetQuantityById, FindAssetsByAccountId, FindAssetsByAssetDefinitionId,
-            FindAssetsByDomainId, FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByName,
+            FindAssetDefinitionKeyValueByIdAndKey, FindAssetDefinitionsByMetadataKeyValue,
+            FindAssetKeyValueByIdAndKey, FindAssetQuantityById, FindAssetsByAccountId,
+            FindAssetsByAssetDefinitionId, FindAssetsByDomainId,
+            FindAssetsByDomainIdAndAssetDefinitionId, FindAssetsByMetadataKeyValue,
+            FindAssetsByName,
         };
     }
 }
@@ -1294,8 +1672,7 @@ pub mod peer {
         type Output = Vec<Peer>;
     }
 
-    /// [`FindAllParameters`] Iroha Query finds all [`Peer`]s parameters.
-    // TODO: Unused query. Remove?
+    /// [`FindAllParameters`] Iroha Query finds all on-chain [`Parameter`]s.
     #[derive(
         Debug,
         Display,
@@ -1492,7 +1869,9 @@ pub mod transaction {
     use crate::{
         account::prelude::AccountId,
         expression::EvaluatesTo,
-        transaction::{TransactionQueryResult, TransactionValue},
+        transaction::{
+            TransactionProof, TransactionQueryResult, TransactionRejectionReason, TransactionValue,
+        },
     };
 
     /// [`FindAllTransactions`] Iroha Query lists all transactions included in a blockchain
@@ -1594,9 +1973,81 @@ pub mod transaction {
         }
     }
 
+    /// [`FindTransactionProofByHash`] Iroha Query finds a committed transaction
+    /// with corresponding hash value together with the proof of its inclusion
+    /// in a block, which clients can verify without trusting the peer
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find proof of transaction with `{}` hash", hash)]
+    pub struct FindTransactionProofByHash {
+        /// Transaction hash.
+        pub hash: EvaluatesTo<Hash>,
+    }
+
+    impl Query for FindTransactionProofByHash {
+        type Output = TransactionProof;
+    }
+
+    impl FindTransactionProofByHash {
+        /// Construct [`FindTransactionProofByHash`].
+        pub fn new(hash: impl Into<EvaluatesTo<Hash>>) -> Self {
+            let hash = hash.into();
+            FindTransactionProofByHash { hash }
+        }
+    }
+
+    /// [`FindTransactionRejectionReasonByHash`] Iroha Query finds the reason
+    /// why the transaction with corresponding hash value was rejected
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find rejection reason of transaction with `{}` hash", hash)]
+    pub struct FindTransactionRejectionReasonByHash {
+        /// Transaction hash.
+        pub hash: EvaluatesTo<Hash>,
+    }
+
+    impl Query for FindTransactionRejectionReasonByHash {
+        type Output = TransactionRejectionReason;
+    }
+
+    impl FindTransactionRejectionReasonByHash {
+        /// Construct [`FindTransactionRejectionReasonByHash`].
+        pub fn new(hash: impl Into<EvaluatesTo<Hash>>) -> Self {
+            let hash = hash.into();
+            FindTransactionRejectionReasonByHash { hash }
+        }
+    }
+
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllTransactions, FindTransactionByHash, FindTransactionsByAccountId};
+        pub use super::{
+            FindAllTransactions, FindTransactionByHash, FindTransactionProofByHash,
+            FindTransactionRejectionReasonByHash, FindTransactionsByAccountId,
+        };
     }
 }
 
@@ -1618,6 +2069,7 @@ pub mod block {
     use crate::{
         block_value::{BlockHeaderValue, BlockValue},
         prelude::EvaluatesTo,
+        transaction::TransactionQueryResult,
     };
 
     /// [`FindAllBlocks`] Iroha Query lists all blocks sorted by
@@ -1716,19 +2168,485 @@ pub mod block {
         }
     }
 
+    /// [`FindBlockByHeight`] Iroha Query finds block by its height
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find block at `{}` height", height)]
+    pub struct FindBlockByHeight {
+        /// Block height.
+        pub height: EvaluatesTo<u128>,
+    }
+
+    impl Query for FindBlockByHeight {
+        type Output = BlockValue;
+    }
+
+    impl FindBlockByHeight {
+        /// Construct [`FindBlockByHeight`].
+        pub fn new(height: impl Into<EvaluatesTo<u128>>) -> Self {
+            Self {
+                height: height.into(),
+            }
+        }
+    }
+
+    /// [`FindBlocksInHeightRange`] Iroha Query lists blocks with heights
+    /// from `start` to `end` inclusive, sorted by height in ascending order
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find blocks from `{}` to `{}` height", start, end)]
+    pub struct FindBlocksInHeightRange {
+        /// Height of the first block.
+        pub start: EvaluatesTo<u128>,
+        /// Height of the last block.
+        pub end: EvaluatesTo<u128>,
+    }
+
+    impl Query for FindBlocksInHeightRange {
+        type Output = Vec<BlockValue>;
+    }
+
+    impl FindBlocksInHeightRange {
+        /// Construct [`FindBlocksInHeightRange`].
+        pub fn new(start: impl Into<EvaluatesTo<u128>>, end: impl Into<EvaluatesTo<u128>>) -> Self {
+            Self {
+                start: start.into(),
+                end: end.into(),
+            }
+        }
+    }
+
+    /// [`FindTransactionsInBlock`] Iroha Query lists committed and
+    /// rejected transactions of the block at the given height
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find transactions of block at `{}` height", height)]
+    pub struct FindTransactionsInBlock {
+        /// Block height.
+        pub height: EvaluatesTo<u128>,
+    }
+
+    impl Query for FindTransactionsInBlock {
+        type Output = Vec<TransactionQueryResult>;
+    }
+
+    impl FindTransactionsInBlock {
+        /// Construct [`FindTransactionsInBlock`].
+        pub fn new(height: impl Into<EvaluatesTo<u128>>) -> Self {
+            Self {
+                height: height.into(),
+            }
+        }
+    }
+
+    /// [`FindLatestBlockHeader`] Iroha Query finds header of the latest committed block
+    #[derive(
+        Default,
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find latest block header")]
+    pub struct FindLatestBlockHeader;
+
+    impl Query for FindLatestBlockHeader {
+        type Output = BlockHeaderValue;
+    }
+
+    impl FindLatestBlockHeader {
+        /// Construct [`FindLatestBlockHeader`].
+        pub const fn new() -> Self {
+            Self
+        }
+    }
+
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAllBlockHeaders, FindAllBlocks, FindBlockHeaderByHash};
+        pub use super::{
+            FindAllBlockHeaders, FindAllBlocks, FindBlockByHeight, FindBlockHeaderByHash,
+            FindBlocksInHeightRange, FindLatestBlockHeader, FindTransactionsInBlock,
+        };
     }
 }
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+pub mod aggregate {
+    //! Queries aggregating the output of other queries.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+    use derive_more::Display;
+    use iroha_schema::prelude::*;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::{Query, QueryBox};
+    use crate::{predicate::PredicateBox, Value};
+
+    /// Function reducing the entries of a query output to a single [`Value`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    pub enum AggregateFunction {
+        /// Number of entries, as [`Value::U128`].
+        #[display(fmt = "count")]
+        Count,
+        /// Sum of quantities of asset entries or of numeric entries.
+        #[display(fmt = "sum")]
+        Sum,
+        /// Smallest quantity of asset entries or of numeric entries.
+        #[display(fmt = "min")]
+        Min,
+        /// Largest quantity of asset entries or of numeric entries.
+        #[display(fmt = "max")]
+        Max,
+    }
+
+    /// Criterion by which entries are grouped before aggregation.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    pub enum GroupBy {
+        /// Definition of an asset, or the asset definition itself.
+        #[display(fmt = "asset definition")]
+        AssetDefinition,
+        /// Domain of an asset holder, an account, an asset definition, or the domain itself.
+        #[display(fmt = "domain")]
+        Domain,
+        /// Holder of an asset, or the account itself.
+        #[display(fmt = "account")]
+        Account,
+    }
+
+    /// [`FindAggregate`] Iroha Query executes another query, keeps the entries
+    /// of its output that pass `filter` and reduces them with `function`.
+    ///
+    /// Without grouping the output is the aggregated [`Value`]. With grouping it is
+    /// a [`Value::Vec`] of `[group id, aggregated value]` pairs ordered by the group id.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find {} of query output", function)]
+    pub struct FindAggregate {
+        /// Query whose output is aggregated.
+        pub query: Box<QueryBox>,
+        /// Filter applied to the entries of the output before aggregation.
+        pub filter: PredicateBox,
+        /// Aggregate function.
+        pub function: AggregateFunction,
+        /// Grouping of the entries, if any.
+        pub group_by: Option<GroupBy>,
+    }
+
+    impl Query for FindAggregate {
+        type Output = Value;
+    }
+
+    impl FindAggregate {
+        /// Construct [`FindAggregate`] applying `function` to the whole output of `query`.
+        pub fn new(query: impl Into<QueryBox>, function: AggregateFunction) -> Self {
+            Self {
+                query: Box::new(query.into()),
+                filter: PredicateBox::default(),
+                function,
+                group_by: None,
+            }
+        }
+
+        /// Aggregate only the entries that pass `filter`.
+        #[must_use]
+        pub fn with_filter(mut self, filter: PredicateBox) -> Self {
+            self.filter = filter;
+            self
+        }
+
+        /// Aggregate entries in groups.
+        #[must_use]
+        pub const fn group_by(mut self, group_by: GroupBy) -> Self {
+            self.group_by = Some(group_by);
+            self
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::{AggregateFunction, FindAggregate, GroupBy};
+    }
+}
+
+pub mod batch {
+    //! Queries evaluated together.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+    use iroha_schema::prelude::*;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::{Query, QueryBox};
+    use crate::Value;
+
+    /// [`FindBatch`] Iroha Query evaluates several queries against the same
+    /// state of the world and returns their outputs in the same order.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Default,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        PartialOrd,
+        Ord,
+    )]
+    #[display(fmt = "Find outputs of {} queries", "queries.len()")]
+    pub struct FindBatch {
+        /// Queries to evaluate.
+        pub queries: Vec<QueryBox>,
+    }
+
+    impl Query for FindBatch {
+        type Output = Vec<Value>;
+    }
+
+    impl FindBatch {
+        /// Construct [`FindBatch`].
+        pub fn new(queries: impl IntoIterator<Item = impl Into<QueryBox>>) -> Self {
+            Self {
+                queries: queries.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindBatch;
+    }
+}
+
+pub mod subscription {
+    //! Messages of live query subscriptions, which push query outputs
+    //! to the client whenever they change.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{boxed::Box, collections::btree_map, format, string::String, vec::Vec};
+    #[cfg(feature = "std")]
+    use std::collections::btree_map;
+
+    use iroha_macro::FromVariant;
+    use iroha_schema::prelude::*;
+    use iroha_version::prelude::*;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::SignedQueryRequest;
+    use crate::Value;
+
+    declare_versioned_with_scale!(VersionedQueryPublisherMessage 1..2, Debug, Clone, FromVariant, IntoSchema);
+
+    impl VersionedQueryPublisherMessage {
+        /// Performs the conversion from `VersionedQueryPublisherMessage` to V1
+        pub fn into_v1(self) -> QueryPublisherMessage {
+            match self {
+                Self::V1(v1) => v1,
+            }
+        }
+    }
+
+    /// Message sent by the peer to a query subscriber
+    #[version_with_scale(n = 1, versioned = "VersionedQueryPublisherMessage")]
+    #[derive(Debug, Clone, Decode, Encode, FromVariant, IntoSchema)]
+    pub enum QueryPublisherMessage {
+        /// The query passed validation and its outputs will be supplied
+        /// starting with the next message.
+        SubscriptionAccepted,
+        /// Whole output of the query.
+        /// Sent first and whenever an output which isn't a [`Value::Vec`] changes.
+        Result(Value),
+        /// Change of an output made of [`Value::Vec`] since the previous message.
+        Delta(QueryDelta),
+        /// The peer stopped evaluating the query, e.g. because the account lost
+        /// the permission to run it. No messages follow.
+        SubscriptionEnded(String),
+    }
+
+    declare_versioned_with_scale!(VersionedQuerySubscriberMessage 1..2, Debug, Clone, FromVariant, IntoSchema);
+
+    impl VersionedQuerySubscriberMessage {
+        /// Performs the conversion from `VersionedQuerySubscriberMessage` to V1
+        pub fn into_v1(self) -> QuerySubscriberMessage {
+            match self {
+                Self::V1(v1) => v1,
+            }
+        }
+    }
+
+    /// Message sent by a query subscriber to the peer
+    #[version_with_scale(n = 1, versioned = "VersionedQuerySubscriberMessage")]
+    #[derive(Debug, Clone, Decode, Encode, FromVariant, IntoSchema)]
+    pub enum QuerySubscriberMessage {
+        /// Request to evaluate the query after every committed block.
+        SubscriptionRequest(SignedQueryRequest),
+        /// Acknowledgment of receiving an output sent from the peer.
+        OutputReceived,
+    }
+
+    /// Difference between two outputs of a query, which doesn't take the order of values into account.
+    #[derive(
+        Debug, Clone, Default, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub struct QueryDelta {
+        /// Values present in the new output, but not in the old one.
+        pub added: Vec<Value>,
+        /// Values present in the old output, but not in the new one.
+        pub removed: Vec<Value>,
+    }
+
+    impl QueryDelta {
+        /// Construct [`QueryDelta`] turning `old` output into `new` one.
+        pub fn between(old: &[Value], new: &[Value]) -> Self {
+            let mut counts = btree_map::BTreeMap::<&Value, isize>::new();
+            for value in old {
+                *counts.entry(value).or_default() -= 1;
+            }
+            for value in new {
+                *counts.entry(value).or_default() += 1;
+            }
+
+            let mut delta = Self::default();
+            for (value, count) in counts {
+                let (values, count) = if count < 0 {
+                    (&mut delta.removed, count.unsigned_abs())
+                } else {
+                    (&mut delta.added, count.unsigned_abs())
+                };
+                values.extend(core::iter::repeat(value).take(count).cloned());
+            }
+            delta
+        }
+
+        /// Whether the outputs are the same up to the order of values.
+        pub fn is_empty(&self) -> bool {
+            self.added.is_empty() && self.removed.is_empty()
+        }
+
+        /// Apply [`Self`] to the old `output`. Added values are appended to the end.
+        pub fn apply(&self, output: &mut Vec<Value>) {
+            for removed in &self.removed {
+                if let Some(position) = output.iter().position(|value| value == removed) {
+                    output.remove(position);
+                }
+            }
+            output.extend(self.added.iter().cloned());
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::{
+            QueryDelta, QueryPublisherMessage, QuerySubscriberMessage,
+            VersionedQueryPublisherMessage, VersionedQuerySubscriberMessage,
+        };
+    }
+}
+
 pub mod prelude {
     pub use super::{
-        account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
-        peer::prelude::*, permissions::prelude::*, role::prelude::*, transaction::*,
-        trigger::prelude::*, PaginatedQueryResult, Query, QueryBox, QueryResult,
-        VersionedPaginatedQueryResult, VersionedQueryResult,
+        account::prelude::*, aggregate::prelude::*, asset::prelude::*, batch::prelude::*,
+        block::prelude::*, domain::prelude::*, peer::prelude::*, permissions::prelude::*,
+        role::prelude::*, subscription::prelude::*, transaction::*, trigger::prelude::*,
+        PaginatedQueryResult, Query, QueryBox, QueryResponsePayload, QueryResponseSignature,
+        QueryResult, ResponseSigningRequest, VersionedPaginatedQueryResult, VersionedQueryResult,
     };
     #[cfg(feature = "warp")]
     pub use super::{QueryRequest, VersionedSignedQueryRequest};
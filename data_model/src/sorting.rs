@@ -5,20 +5,226 @@ use alloc::{
     string::{String, ToString as _},
     vec::Vec,
 };
+use core::{cmp::Ordering, fmt, str::FromStr};
 
+use iroha_schema::IntoSchema;
+use iroha_version::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "warp")]
 use warp::{Filter, Rejection};
 
-use crate::prelude::*;
+use crate::{prelude::*, HasMetadata, ParseError};
 
 const SORT_BY_KEY: &str = "sort_by_metadata_key";
+const SORT_BY: &str = "sort_by";
+
+/// Field of a query result entry by which it can be sorted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Decode, Encode, IntoSchema)]
+pub enum SortField {
+    /// Id of an entity, or the id itself.
+    Id,
+    /// Quantity of an asset.
+    ///
+    /// Integer quantities are compared with each other as `u128` and fixed
+    /// ones with each other as [`Fixed`](iroha_primitives::fixed::Fixed).
+    /// The two kinds aren't compared by amount: in ascending order, all
+    /// integer quantities come before all fixed ones.
+    Quantity,
+    /// Account owning an asset, the account itself or the creator of a transaction.
+    AccountId,
+    /// Height of a block.
+    BlockHeight,
+    /// Creation time of a transaction or timestamp of a block.
+    CreationTime,
+    /// Value under the key in the entity's metadata or in an asset store.
+    Metadata(Name),
+}
+
+impl SortField {
+    /// Prefix of the [`SortField::Metadata`] string representation.
+    const METADATA_PREFIX: &'static str = "metadata.";
+
+    /// Extract the value of this field from `value`, if it has one.
+    pub fn extract(&self, value: &Value) -> Option<Value> {
+        match (self, value) {
+            (Self::Id, Value::Identifiable(identifiable)) => Some(Value::Id(identifiable.id_box())),
+            (Self::Id, Value::Id(_)) => Some(value.clone()),
+            (Self::Quantity, Value::Identifiable(IdentifiableBox::Asset(asset))) => {
+                match asset.value() {
+                    AssetValue::Quantity(quantity) => Some(Value::U128((*quantity).into())),
+                    AssetValue::BigQuantity(quantity) => Some(Value::U128(*quantity)),
+                    AssetValue::Fixed(quantity) => Some(Value::Fixed(*quantity)),
+                    AssetValue::Store(_) => None,
+                }
+            }
+            (Self::Quantity, Value::U32(quantity)) => Some(Value::U128((*quantity).into())),
+            (Self::Quantity, Value::U128(_) | Value::Fixed(_)) => Some(value.clone()),
+            (Self::AccountId, Value::Identifiable(IdentifiableBox::Asset(asset))) => {
+                Some(IdBox::AccountId(asset.id().account_id.clone()).into())
+            }
+            (Self::AccountId, Value::Identifiable(IdentifiableBox::Account(account))) => {
+                Some(IdBox::AccountId(account.id().clone()).into())
+            }
+            (Self::AccountId, Value::Id(IdBox::AssetId(id))) => {
+                Some(IdBox::AccountId(id.account_id.clone()).into())
+            }
+            (Self::AccountId, Value::Id(IdBox::AccountId(_))) => Some(value.clone()),
+            (Self::AccountId, Value::TransactionValue(tx)) => {
+                Some(IdBox::AccountId(tx.payload().account_id.clone()).into())
+            }
+            (Self::AccountId, Value::TransactionQueryResult(tx)) => {
+                Some(IdBox::AccountId(tx.payload().account_id.clone()).into())
+            }
+            (Self::AccountId, Value::AccountStatementEntry(entry)) => {
+                Some(IdBox::AccountId(entry.asset_id.account_id.clone()).into())
+            }
+            (Self::BlockHeight, Value::Block(block)) => {
+                Some(Value::U128(block.header.height.into()))
+            }
+            (Self::BlockHeight, Value::BlockHeader(header)) => {
+                Some(Value::U128(header.height.into()))
+            }
+            (Self::BlockHeight, Value::AccountStatementEntry(entry)) => {
+                Some(Value::U128(entry.block_height.into()))
+            }
+            (Self::CreationTime, Value::TransactionValue(tx)) => {
+                Some(Value::U128(tx.payload().creation_time.into()))
+            }
+            (Self::CreationTime, Value::TransactionQueryResult(tx)) => {
+                Some(Value::U128(tx.payload().creation_time.into()))
+            }
+            (Self::CreationTime, Value::Block(block)) => Some(Value::U128(block.header.timestamp)),
+            (Self::CreationTime, Value::BlockHeader(header)) => Some(Value::U128(header.timestamp)),
+            (Self::Metadata(key), Value::Identifiable(IdentifiableBox::Asset(asset))) => {
+                match asset.value() {
+                    AssetValue::Store(store) => store.get(key).cloned(),
+                    _ => None,
+                }
+            }
+            (Self::Metadata(key), Value::Identifiable(identifiable)) => {
+                TryInto::<&dyn HasMetadata>::try_into(identifiable)
+                    .ok()
+                    .and_then(|has_metadata| has_metadata.metadata().get(key).cloned())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SortField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id => f.write_str("id"),
+            Self::Quantity => f.write_str("quantity"),
+            Self::AccountId => f.write_str("account_id"),
+            Self::BlockHeight => f.write_str("block_height"),
+            Self::CreationTime => f.write_str("creation_time"),
+            Self::Metadata(key) => write!(f, "{}{key}", Self::METADATA_PREFIX),
+        }
+    }
+}
+
+impl FromStr for SortField {
+    type Err = ParseError;
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        match field {
+            "id" => Ok(Self::Id),
+            "quantity" => Ok(Self::Quantity),
+            "account_id" => Ok(Self::AccountId),
+            "block_height" => Ok(Self::BlockHeight),
+            "creation_time" => Ok(Self::CreationTime),
+            _ => field
+                .strip_prefix(Self::METADATA_PREFIX)
+                .ok_or(ParseError {
+                    reason: "Unknown sorting field",
+                })
+                .and_then(Name::from_str)
+                .map(Self::Metadata),
+        }
+    }
+}
+
+/// Direction of sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Decode, Encode, IntoSchema)]
+pub enum SortOrder {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+/// Single key of a [`Sorting`]: field and direction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Decode, Encode, IntoSchema)]
+pub struct SortKey {
+    /// Field to sort by.
+    pub field: SortField,
+    /// Direction of sorting.
+    pub order: SortOrder,
+}
+
+impl SortKey {
+    /// Sort by `field` in ascending order.
+    pub const fn ascending(field: SortField) -> Self {
+        Self {
+            field,
+            order: SortOrder::Ascending,
+        }
+    }
+
+    /// Sort by `field` in descending order.
+    pub const fn descending(field: SortField) -> Self {
+        Self {
+            field,
+            order: SortOrder::Descending,
+        }
+    }
+
+    /// Compare extracted field values. Entries without the field are
+    /// treated as the smallest ones.
+    fn compare(&self, left: Option<&Value>, right: Option<&Value>) -> Ordering {
+        match self.order {
+            SortOrder::Ascending => left.cmp(&right),
+            SortOrder::Descending => right.cmp(&left),
+        }
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.order {
+            SortOrder::Ascending => write!(f, "{}:asc", self.field),
+            SortOrder::Descending => write!(f, "{}:desc", self.field),
+        }
+    }
+}
+
+impl FromStr for SortKey {
+    type Err = ParseError;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        match key.rsplit_once(':') {
+            Some((field, "asc")) => field.parse().map(Self::ascending),
+            Some((field, "desc")) => field.parse().map(Self::descending),
+            _ => key.parse().map(Self::ascending),
+        }
+    }
+}
 
 /// Enum for sorting requests
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Decode, Encode, IntoSchema,
+)]
 pub struct Sorting {
     /// Sort query result using [`Name`] of the key in [`Asset`]'s metadata.
+    /// Only `U128` values are compared, entries with any other value or
+    /// without one are sorted as if they had `0`.
     pub sort_by_metadata_key: Option<Name>,
+    /// Keys to sort query result by, in order of priority. Applied after
+    /// [`Self::sort_by_metadata_key`], if it is set.
+    #[serde(default, with = "sort_keys")]
+    pub sort_by: Vec<SortKey>,
 }
 
 impl Sorting {
@@ -26,8 +232,59 @@ impl Sorting {
     pub fn by_metadata_key(key: Name) -> Self {
         Self {
             sort_by_metadata_key: Some(key),
+            sort_by: Vec::new(),
         }
     }
+
+    /// Creates a sorting by `keys`, in order of priority.
+    pub fn by_keys(keys: impl IntoIterator<Item = SortKey>) -> Self {
+        Self {
+            sort_by_metadata_key: None,
+            sort_by: keys.into_iter().collect(),
+        }
+    }
+
+    /// Sort `values` in place. The sort is stable, so entries equal by
+    /// every key keep their order.
+    pub fn sort(&self, values: &mut Vec<Value>) {
+        let keys: Vec<_> = self
+            .sort_by_metadata_key
+            .iter()
+            .map(|key| SortKey::ascending(SortField::Metadata(key.clone())))
+            .chain(self.sort_by.iter().cloned())
+            .collect();
+        if keys.is_empty() {
+            return;
+        }
+
+        let mut keyed: Vec<_> = core::mem::take(values)
+            .into_iter()
+            .map(|value| {
+                let mut fields: Vec<_> = keys.iter().map(|key| key.field.extract(&value)).collect();
+                if let (Some(_), Some(field)) = (&self.sort_by_metadata_key, fields.first_mut()) {
+                    *field = Some(legacy_metadata_value(field.take()));
+                }
+                (fields, value)
+            })
+            .collect();
+        keyed.sort_by(|(left, _), (right, _)| {
+            keys.iter()
+                .zip(left.iter().zip(right))
+                .map(|(key, (left, right))| key.compare(left.as_ref(), right.as_ref()))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+        *values = keyed.into_iter().map(|(_, value)| value).collect();
+    }
+}
+
+/// Value by which [`Sorting::sort_by_metadata_key`] sorts, kept as it
+/// was before [`Sorting::sort_by`] was introduced.
+fn legacy_metadata_value(value: Option<Value>) -> Value {
+    match value {
+        Some(Value::U128(num)) => Value::U128(num),
+        _ => Value::U128(0),
+    }
 }
 
 impl From<Sorting> for Vec<(&'static str, String)> {
@@ -36,10 +293,48 @@ impl From<Sorting> for Vec<(&'static str, String)> {
         if let Some(key) = sorting.sort_by_metadata_key {
             vec.push((SORT_BY_KEY, key.to_string()));
         }
+        if !sorting.sort_by.is_empty() {
+            vec.push((SORT_BY, sort_keys::to_string(&sorting.sort_by)));
+        }
         vec
     }
 }
 
+/// (De)serialization of sort keys as a comma-separated string, so that
+/// they fit into a single URL query parameter.
+mod sort_keys {
+    #[cfg(not(feature = "std"))]
+    use alloc::{
+        string::{String, ToString as _},
+        vec::Vec,
+    };
+
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use super::SortKey;
+
+    pub fn to_string(keys: &[SortKey]) -> String {
+        keys.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    pub fn serialize<S: Serializer>(keys: &[SortKey], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_string(keys))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<SortKey>, D::Error> {
+        let keys = String::deserialize(deserializer)?;
+        keys.split(',')
+            .filter(|key| !key.is_empty())
+            .map(|key| key.parse().map_err(D::Error::custom))
+            .collect()
+    }
+}
+
 #[cfg(feature = "warp")]
 /// Filter for warp which extracts sorting
 pub fn sorting() -> impl Filter<Extract = (Sorting,), Error = Rejection> + Copy {
@@ -50,3 +345,124 @@ pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
     pub use super::*;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(definition: &str, quantity: u32) -> Value {
+        let id = AssetId::new(
+            definition.parse().expect("Valid"),
+            "alice@wonderland".parse().expect("Valid"),
+        );
+        Asset::new(id, AssetValue::Quantity(quantity)).into()
+    }
+
+    #[test]
+    fn sort_keys_roundtrip() {
+        let keys = vec![
+            SortKey::descending(SortField::Quantity),
+            SortKey::ascending(SortField::Metadata("rank".parse().expect("Valid"))),
+        ];
+        let string = sort_keys::to_string(&keys);
+        assert_eq!(string, "quantity:desc,metadata.rank:asc");
+        let parsed: Result<Vec<SortKey>, _> = string.split(',').map(str::parse).collect();
+        assert_eq!(parsed.expect("Valid"), keys);
+        assert!("height:desc".parse::<SortKey>().is_err());
+    }
+
+    fn asset_with_rank(definition: &str, rank: Option<Value>) -> Value {
+        let id = AssetId::new(
+            definition.parse().expect("Valid"),
+            "alice@wonderland".parse().expect("Valid"),
+        );
+        let mut store = Metadata::new();
+        if let Some(rank) = rank {
+            store
+                .insert_with_limits(
+                    "rank".parse().expect("Valid"),
+                    rank,
+                    MetadataLimits::new(10, 100),
+                )
+                .expect("Within limits");
+        }
+        Asset::new(id, AssetValue::Store(store)).into()
+    }
+
+    #[test]
+    fn metadata_key_sorting_treats_other_values_as_zero() {
+        let mut values = vec![
+            asset_with_rank("rose#wonderland", Some(Value::U128(2))),
+            asset_with_rank("tulip#wonderland", Some(Value::U32(1))),
+            asset_with_rank("lily#wonderland", None),
+            asset_with_rank("daisy#wonderland", Some(Value::U128(1))),
+            asset_with_rank("iris#wonderland", Some(Value::U128(0))),
+        ];
+        Sorting::by_metadata_key("rank".parse().expect("Valid")).sort(&mut values);
+
+        assert_eq!(
+            values,
+            vec![
+                asset_with_rank("tulip#wonderland", Some(Value::U32(1))),
+                asset_with_rank("lily#wonderland", None),
+                asset_with_rank("iris#wonderland", Some(Value::U128(0))),
+                asset_with_rank("daisy#wonderland", Some(Value::U128(1))),
+                asset_with_rank("rose#wonderland", Some(Value::U128(2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn integer_quantities_come_before_fixed_ones() {
+        let fixed = |definition: &str, quantity: f64| -> Value {
+            let id = AssetId::new(
+                definition.parse().expect("Valid"),
+                "alice@wonderland".parse().expect("Valid"),
+            );
+            let quantity = quantity.try_into().expect("Valid");
+            Asset::new(id, AssetValue::Fixed(quantity)).into()
+        };
+        let mut values = vec![
+            fixed("gold#wonderland", 0.5),
+            asset("rose#wonderland", 7),
+            fixed("silver#wonderland", 100.0),
+            asset("tulip#wonderland", 2),
+        ];
+        Sorting::by_keys([SortKey::ascending(SortField::Quantity)]).sort(&mut values);
+
+        assert_eq!(
+            values,
+            vec![
+                asset("tulip#wonderland", 2),
+                asset("rose#wonderland", 7),
+                fixed("gold#wonderland", 0.5),
+                fixed("silver#wonderland", 100.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_key_sorting() {
+        let mut values = vec![
+            asset("rose#wonderland", 1),
+            asset("tulip#wonderland", 5),
+            asset("lily#wonderland", 5),
+            Value::U32(3),
+        ];
+        Sorting::by_keys([
+            SortKey::descending(SortField::Quantity),
+            SortKey::ascending(SortField::Id),
+        ])
+        .sort(&mut values);
+
+        assert_eq!(
+            values,
+            vec![
+                asset("lily#wonderland", 5),
+                asset("tulip#wonderland", 5),
+                Value::U32(3),
+                asset("rose#wonderland", 1),
+            ]
+        );
+    }
+}
@@ -1,17 +1,28 @@
 //! [`Transaction`] structures and related implementations.
 #![allow(clippy::std_instead_of_core)]
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, collections::btree_set, format, string::String, vec, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{btree_map, btree_set},
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
 use core::{
     cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
     iter::IntoIterator,
 };
 #[cfg(feature = "std")]
-use std::{collections::btree_set, time::Duration, vec};
+use std::{
+    collections::{btree_map, btree_set},
+    time::Duration,
+    vec,
+};
 
 use derive_more::Display;
-use iroha_crypto::{Hash, SignatureOf, SignaturesOf};
+use iroha_crypto::{Hash, MerkleProof, PublicKey, Signature, SignatureOf, SignaturesOf};
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
 use iroha_version::{declare_versioned, declare_versioned_with_scale, version, version_with_scale};
@@ -20,7 +31,15 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "warp")]
 use warp::{reply::Response, Reply};
 
-use crate::{account::Account, isi::Instruction, metadata::UnlimitedMetadata, Identifiable};
+use crate::{
+    account::Account,
+    asset::AssetDefinition,
+    block_value::EncodedBlockHeader,
+    events::prelude::DataEvent,
+    isi::{Instruction, InstructionKind},
+    metadata::UnlimitedMetadata,
+    ChainId, IdBox, Identifiable, IdentifiableBox,
+};
 
 /// Default maximum number of instructions and expressions per transaction
 pub const DEFAULT_MAX_INSTRUCTION_NUMBER: u64 = 2_u64.pow(12);
@@ -192,6 +211,8 @@ impl AsRef<[u8]> for WasmSmartContract {
 /// Iroha [`Transaction`] payload.
 #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
 pub struct Payload {
+    /// Identifier of the network this transaction is meant for.
+    pub chain_id: ChainId,
     /// Account ID of transaction creator.
     pub account_id: <Account as Identifiable>::Id,
     /// Instructions or WebAssembly smartcontract
@@ -202,6 +223,12 @@ pub struct Payload {
     pub time_to_live_ms: u64,
     /// Random value to make different hashes for transactions which occur repeatedly and simultaneously
     pub nonce: Option<u32>,
+    /// Strictly increasing per-account sequence number.
+    ///
+    /// Transactions of the same account which have it are committed in its order
+    /// without gaps, starting with `0`. It also prevents such transactions from being replayed.
+    /// `u32::MAX` is reserved, so `u32::MAX - 1` is the last one an account can use.
+    pub sequence_number: Option<u32>,
     /// Metadata.
     pub metadata: UnlimitedMetadata,
 }
@@ -209,9 +236,11 @@ pub struct Payload {
 impl Payload {
     /// Used to compare the contents of the transaction independent of when it was created.
     pub fn equals_excluding_creation_time(&self, other: &Payload) -> bool {
-        self.account_id == other.account_id
+        self.chain_id == other.chain_id
+            && self.account_id == other.account_id
             && self.instructions == other.instructions
             && self.time_to_live_ms == other.time_to_live_ms
+            && self.sequence_number == other.sequence_number
             && self.metadata == other.metadata
     }
 }
@@ -225,6 +254,127 @@ pub struct TransactionLimits {
     pub max_wasm_size_bytes: u64,
 }
 
+/// Schedule of fees charged for submitting transactions.
+///
+/// All fees are paid in [`Self::asset_definition_id`] from the account of the transaction
+/// submitter to [`Self::treasury_account_id`] when the transaction is committed.
+/// Rejected transactions aren't charged, even for the fuel their execution consumed.
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+#[display(
+    fmt = "Fees paid in `{}` to `{}`",
+    asset_definition_id,
+    treasury_account_id
+)]
+pub struct FeeSchedule {
+    /// Asset in which the fees are paid.
+    pub asset_definition_id: <AssetDefinition as Identifiable>::Id,
+    /// Account which receives the fees.
+    pub treasury_account_id: <Account as Identifiable>::Id,
+    /// Flat fee charged for every transaction.
+    pub per_transaction: u32,
+    /// Fee charged for every instruction of the given kind. Nested instructions are counted too.
+    pub per_instruction: btree_map::BTreeMap<InstructionKind, u32>,
+    /// Fee charged for every unit of fuel consumed by a `WebAssembly` smartcontract.
+    pub per_wasm_fuel_unit: u32,
+}
+
+impl FeeSchedule {
+    /// Construct [`FeeSchedule`] which charges only a flat fee per transaction.
+    pub fn new(
+        asset_definition_id: <AssetDefinition as Identifiable>::Id,
+        treasury_account_id: <Account as Identifiable>::Id,
+        per_transaction: u32,
+    ) -> Self {
+        Self {
+            asset_definition_id,
+            treasury_account_id,
+            per_transaction,
+            per_instruction: btree_map::BTreeMap::new(),
+            per_wasm_fuel_unit: 0,
+        }
+    }
+
+    /// Set fee charged for every instruction of the given `kind`.
+    #[must_use]
+    pub fn with_instruction_fee(mut self, kind: InstructionKind, fee: u32) -> Self {
+        self.per_instruction.insert(kind, fee);
+        self
+    }
+
+    /// Set fee charged for every unit of `WebAssembly` fuel.
+    #[must_use]
+    pub const fn with_wasm_fuel_unit_fee(mut self, fee: u32) -> Self {
+        self.per_wasm_fuel_unit = fee;
+        self
+    }
+
+    /// Compute fee for the given instructions.
+    ///
+    /// Returns `None` on overflow.
+    pub fn instructions_fee<'instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = &'instruction Instruction>,
+    ) -> Option<u32> {
+        instructions
+            .into_iter()
+            .try_fold(self.per_transaction, |acc, instruction| {
+                acc.checked_add(self.instruction_fee(instruction)?)
+            })
+    }
+
+    /// Compute fee for a `WebAssembly` smartcontract which consumed `fuel`.
+    ///
+    /// Returns `None` on overflow.
+    pub fn wasm_fee(&self, fuel: u64) -> Option<u32> {
+        let fuel_fee = u64::from(self.per_wasm_fuel_unit).checked_mul(fuel)?;
+        u32::try_from(fuel_fee)
+            .ok()?
+            .checked_add(self.per_transaction)
+    }
+
+    fn instruction_fee(&self, instruction: &Instruction) -> Option<u32> {
+        let own = self
+            .per_instruction
+            .get(&instruction.kind())
+            .copied()
+            .unwrap_or(0);
+        let nested = match instruction {
+            Instruction::If(if_box) => {
+                let otherwise = if_box
+                    .otherwise
+                    .as_ref()
+                    .map_or(Some(0), |otherwise| self.instruction_fee(otherwise))?;
+                self.instruction_fee(&if_box.then)?.checked_add(otherwise)?
+            }
+            Instruction::Pair(pair) => self
+                .instruction_fee(&pair.left_instruction)?
+                .checked_add(self.instruction_fee(&pair.right_instruction)?)?,
+            Instruction::Sequence(sequence) => sequence
+                .instructions
+                .iter()
+                .try_fold(0_u32, |acc, instruction| {
+                    acc.checked_add(self.instruction_fee(instruction)?)
+                })?,
+            _ => 0,
+        };
+        own.checked_add(nested)
+    }
+}
+
 declare_versioned!(
     VersionedTransaction 1..2,
     Debug,
@@ -316,11 +466,13 @@ impl Transaction {
 
         Self {
             payload: Payload {
+                chain_id: ChainId::default(),
                 account_id,
                 instructions,
                 creation_time,
                 time_to_live_ms: proposed_ttl_ms,
                 nonce: None,
+                sequence_number: None,
                 metadata: UnlimitedMetadata::new(),
             },
             signatures: btree_set::BTreeSet::new(),
@@ -335,6 +487,14 @@ impl Transaction {
         self
     }
 
+    /// Sets identifier of the network this `Transaction` is meant for
+    #[must_use]
+    #[inline]
+    pub fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.payload.chain_id = chain_id;
+        self
+    }
+
     /// Adds nonce to the `Transaction`
     #[must_use]
     #[inline]
@@ -343,6 +503,14 @@ impl Transaction {
         self
     }
 
+    /// Adds per-account sequence number to the `Transaction`
+    #[must_use]
+    #[inline]
+    pub fn with_sequence_number(mut self, sequence_number: u32) -> Self {
+        self.payload.sequence_number = Some(sequence_number);
+        self
+    }
+
     /// Sign transaction with the provided key pair.
     ///
     /// # Errors
@@ -437,6 +605,89 @@ impl IntoIterator for PendingTransactions {
     }
 }
 
+declare_versioned_with_scale!(VersionedTransactionSimulation 1..2, Debug, Clone, FromVariant);
+
+impl VersionedTransactionSimulation {
+    /// Converts from `&VersionedTransactionSimulation` to V1 reference
+    #[inline]
+    pub const fn as_v1(&self) -> &TransactionSimulation {
+        match self {
+            Self::V1(v1) => v1,
+        }
+    }
+
+    /// Converts from `&mut VersionedTransactionSimulation` to V1 mutable reference
+    #[inline]
+    pub fn as_mut_v1(&mut self) -> &mut TransactionSimulation {
+        match self {
+            Self::V1(v1) => v1,
+        }
+    }
+
+    /// Performs the conversion from `VersionedTransactionSimulation` to V1
+    #[inline]
+    pub fn into_v1(self) -> TransactionSimulation {
+        match self {
+            Self::V1(v1) => v1,
+        }
+    }
+}
+
+#[cfg(feature = "warp")]
+impl Reply for VersionedTransactionSimulation {
+    #[inline]
+    fn into_response(self) -> Response {
+        use iroha_version::scale::EncodeVersioned;
+        Response::new(self.encode_versioned().into())
+    }
+}
+
+/// Outcome of executing a transaction against the current world state without committing it.
+#[version_with_scale(n = 1, versioned = "VersionedTransactionSimulation")]
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct TransactionSimulation {
+    /// The reason the transaction would be rejected for, `None` if it would be accepted.
+    pub rejection_reason: Option<TransactionRejectionReason>,
+    /// Data events the transaction would emit.
+    pub events: Vec<DataEvent>,
+    /// WebAssembly fuel the transaction would consume.
+    pub fuel_consumed: u64,
+    /// Changes the transaction would make to the world state.
+    pub state_diff: Vec<StateChange>,
+}
+
+impl TransactionSimulation {
+    /// Construct [`TransactionSimulation`] of a rejected transaction.
+    #[inline]
+    pub fn rejected(rejection_reason: TransactionRejectionReason) -> Self {
+        Self {
+            rejection_reason: Some(rejection_reason),
+            events: Vec::new(),
+            fuel_consumed: 0,
+            state_diff: Vec::new(),
+        }
+    }
+
+    /// Whether the simulated transaction would be accepted.
+    #[inline]
+    pub const fn is_accepted(&self) -> bool {
+        self.rejection_reason.is_none()
+    }
+}
+
+/// Change of a single entity of the world state.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+pub struct StateChange {
+    /// Identification of the changed entity.
+    pub id: IdBox,
+    /// The entity before the change, `None` if it is registered by the transaction.
+    pub before: Option<IdentifiableBox>,
+    /// The entity after the change, `None` if it is unregistered by the transaction.
+    pub after: Option<IdentifiableBox>,
+}
+
 /// Transaction Value used in Instructions and Queries
 #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
 pub enum TransactionValue {
@@ -493,6 +744,100 @@ impl TransactionQueryResult {
     }
 }
 
+/// Committed transaction together with the proof of its inclusion in
+/// a block signed by the peers, used in `FindTransactionProofByHash` query
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct TransactionProof {
+    /// Transaction
+    pub transaction: VersionedValidTransaction,
+    /// Proof of inclusion of the transaction hash in the tree whose
+    /// root is the transactions hash of the block header
+    pub merkle_proof: MerkleProof<VersionedTransaction>,
+    /// Header of the block to which the transaction belongs
+    pub block_header: EncodedBlockHeader,
+    /// Signatures of the peers which committed the block, made with
+    /// the block hash as payload
+    pub signatures: Vec<Signature>,
+}
+
+impl TransactionProof {
+    /// Check that the transaction is included in the block signed by at
+    /// least `min_signatures` of `trusted_peers`, without trusting the
+    /// peer which returned the proof.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't included in the block or if the
+    /// block isn't signed by enough trusted peers.
+    #[cfg(feature = "std")]
+    pub fn verify<'key>(
+        &self,
+        trusted_peers: impl IntoIterator<Item = &'key PublicKey>,
+        min_signatures: usize,
+    ) -> Result<(), TransactionProofError> {
+        if !self.merkle_proof.verify(
+            &self.transaction.hash(),
+            &self.block_header.transactions_hash,
+        ) {
+            return Err(TransactionProofError::NotIncluded);
+        }
+
+        let block_hash = self.block_header.hash();
+        let trusted_peers: btree_set::BTreeSet<_> = trusted_peers.into_iter().collect();
+        let signed_by: btree_set::BTreeSet<_> = self
+            .signatures
+            .iter()
+            .filter(|signature| trusted_peers.contains(signature.public_key()))
+            .filter(|signature| signature.verify(block_hash.as_ref()).is_ok())
+            .map(Signature::public_key)
+            .collect();
+        if signed_by.len() < min_signatures {
+            return Err(TransactionProofError::NotEnoughSignatures {
+                expected: min_signatures,
+                actual: signed_by.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Ord for TransactionProof {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.block_header.height, self.merkle_proof.leaf_index())
+            .cmp(&(other.block_header.height, other.merkle_proof.leaf_index()))
+    }
+}
+
+impl PartialOrd for TransactionProof {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reason why a [`TransactionProof`] doesn't prove that the transaction was committed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum TransactionProofError {
+    /// Transaction hash isn't included in the block's transactions
+    #[display(fmt = "Transaction isn't included in the block's transactions")]
+    NotIncluded,
+    /// Block isn't signed by enough trusted peers
+    #[display(
+        fmt = "Block is signed by {} trusted peers, expected at least {}",
+        actual,
+        expected
+    )]
+    NotEnoughSignatures {
+        /// Minimal number of signatures
+        expected: usize,
+        /// Number of valid signatures of trusted peers
+        actual: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionProofError {}
+
 impl Ord for TransactionQueryResult {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
@@ -650,10 +995,38 @@ impl std::error::Error for UnsatisfiedSignatureConditionFail {}
 pub struct InstructionExecutionFail {
     /// Instruction which execution failed
     pub instruction: Instruction,
+    /// Location of the failed instruction in the transaction
+    pub location: InstructionLocation,
     /// Error which happened during execution
     pub reason: String,
 }
 
+/// Location of an instruction inside of transaction instructions.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Default, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+pub struct InstructionLocation {
+    /// Index of the top-level instruction in the transaction.
+    pub index: u32,
+    /// Path from the top-level instruction to the located one through nested instructions.
+    ///
+    /// Each element is an index of an instruction inside of a `Sequence`,
+    /// `0`/`1` for the left/right instruction of a `Pair`, or
+    /// `0`/`1` for the `then`/`otherwise` branch of an `If`.
+    /// Empty if the located instruction is the top-level one.
+    pub nested_path: Vec<u32>,
+}
+
+impl Display for InstructionLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.index)?;
+        for index in &self.nested_path {
+            write!(f, ".{}", index)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for InstructionExecutionFail {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         use Instruction::*;
@@ -672,11 +1045,13 @@ impl Display for InstructionExecutionFail {
             Grant(_) => "grant",
             Revoke(_) => "revoke",
             ExecuteTrigger(_) => "execute trigger",
+            SetParameter(_) => "set parameter",
+            EnableTrigger(_) => "enable trigger",
         };
         write!(
             f,
-            "Failed to execute instruction of type {}: {}",
-            kind, self.reason
+            "Failed to execute instruction #{} of type {}: {}",
+            self.location, kind, self.reason
         )
     }
 }
@@ -691,6 +1066,19 @@ impl std::error::Error for InstructionExecutionFail {}
 pub struct WasmExecutionFail {
     /// Error which happened during execution
     pub reason: String,
+    /// Details of the trap if execution was aborted by one
+    pub trap: Option<WasmTrap>,
+}
+
+/// Details of a trap which aborted execution of `WebAssembly` binary
+#[derive(
+    Debug, Clone, PartialEq, Eq, Default, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+pub struct WasmTrap {
+    /// Code of the trap if it was raised by the runtime itself (e.g. `unreachable`)
+    pub code: Option<String>,
+    /// Frames of the wasm call stack at the moment of the trap, innermost first
+    pub backtrace: Vec<String>,
 }
 
 #[cfg(feature = "std")]
@@ -704,11 +1092,54 @@ impl std::error::Error for WasmExecutionFail {}
 pub struct NotPermittedFail {
     /// The cause of failure.
     pub reason: String,
+    /// Index of the denied instruction in the transaction, if denial concerns a single instruction.
+    pub instruction_index: Option<u32>,
+    /// Name of the permission validator which denied the action, if known.
+    pub validator: Option<String>,
+}
+
+impl NotPermittedFail {
+    /// Construct [`NotPermittedFail`] with unknown denied instruction and validator.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            instruction_index: None,
+            validator: None,
+        }
+    }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for NotPermittedFail {}
 
+/// Transaction was rejected because its submitter could not pay the fee
+#[derive(
+    Debug, Clone, PartialEq, Eq, Display, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+#[display(fmt = "Failed to pay transaction fee: {}", reason)]
+pub struct FeePaymentFail {
+    /// The cause of failure.
+    pub reason: String,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeePaymentFail {}
+
+/// Transaction was rejected because its sequence number is not the next one expected for the account
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Display, Decode, Encode, Deserialize, Serialize, IntoSchema,
+)]
+#[display(fmt = "Expected sequence number {}, but got {}", expected, actual)]
+pub struct SequenceNumberFail {
+    /// Next sequence number expected for the account.
+    pub expected: u32,
+    /// Sequence number of the transaction.
+    pub actual: u32,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SequenceNumberFail {}
+
 /// The reason for rejecting transaction which happened because of new blocks.
 #[derive(
     Debug,
@@ -765,9 +1196,18 @@ pub enum TransactionRejectionReason {
     /// Failed to execute WebAssembly binary.
     #[display(fmt = "Transaction rejected due to failure in WebAssembly execution")]
     WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
+    /// Failed to pay transaction fee.
+    #[display(fmt = "Transaction rejected due to failure in fee payment")]
+    FeePayment(#[cfg_attr(feature = "std", source)] FeePaymentFail),
+    /// Sequence number is replayed or out of order.
+    #[display(fmt = "Transaction rejected due to an unexpected sequence number")]
+    SequenceNumber(#[cfg_attr(feature = "std", source)] SequenceNumberFail),
     /// Genesis account can sign only transactions in the genesis block.
     #[display(fmt = "The genesis account can only sign transactions in the genesis block.")]
     UnexpectedGenesisAccountSignature,
+    /// Transaction is meant for another chain.
+    #[display(fmt = "Transaction rejected as it is meant for another chain")]
+    ChainIdMismatch,
 }
 
 /// The reason for rejecting pipeline entity such as transaction or block.
@@ -797,11 +1237,13 @@ pub enum RejectionReason {
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
-        BlockRejectionReason, Executable, InstructionExecutionFail, NotPermittedFail, Payload,
-        PendingTransactions, RejectedTransaction, RejectionReason, Transaction, TransactionLimits,
-        TransactionQueryResult, TransactionRejectionReason, TransactionValue, Txn,
+        BlockRejectionReason, Executable, FeePaymentFail, FeeSchedule, InstructionExecutionFail,
+        InstructionLocation, NotPermittedFail, Payload, PendingTransactions, RejectedTransaction,
+        RejectionReason, SequenceNumberFail, StateChange, Transaction, TransactionLimits,
+        TransactionProof, TransactionProofError, TransactionQueryResult,
+        TransactionRejectionReason, TransactionSimulation, TransactionValue, Txn,
         UnsatisfiedSignatureConditionFail, ValidTransaction, VersionedPendingTransactions,
-        VersionedRejectedTransaction, VersionedTransaction, VersionedValidTransaction,
-        WasmExecutionFail,
+        VersionedRejectedTransaction, VersionedTransaction, VersionedTransactionSimulation,
+        VersionedValidTransaction, WasmExecutionFail, WasmTrap,
     };
 }
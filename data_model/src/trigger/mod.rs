@@ -10,8 +10,8 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    events::prelude::*, metadata::Metadata, prelude::Domain, transaction::Executable, Identifiable,
-    Name, ParseError, Registered,
+    events::prelude::*, expression::EvaluatesTo, metadata::Metadata, prelude::Domain,
+    transaction::Executable, Identifiable, Name, ParseError, Registered,
 };
 
 pub mod set;
@@ -57,20 +57,14 @@ impl TryFrom<Trigger<FilterBox>> for Trigger<DataEventFilter> {
     type Error = &'static str;
 
     fn try_from(boxed: Trigger<FilterBox>) -> Result<Self, Self::Error> {
-        if let FilterBox::Data(data_filter) = boxed.action.filter {
-            let action = action::Action::new(
-                boxed.action.executable,
-                boxed.action.repeats,
-                boxed.action.technical_account,
-                data_filter,
-            );
-            Ok(Self {
-                id: boxed.id,
-                action,
-            })
-        } else {
-            Err("Expected `FilterBox::Data`, but another variant found")
-        }
+        let action = boxed.action.try_map_filter(|filter| match filter {
+            FilterBox::Data(data_filter) => Ok(data_filter),
+            _ => Err("Expected `FilterBox::Data`, but another variant found"),
+        })?;
+        Ok(Self {
+            id: boxed.id,
+            action,
+        })
     }
 }
 
@@ -78,20 +72,14 @@ impl TryFrom<Trigger<FilterBox>> for Trigger<PipelineEventFilter> {
     type Error = &'static str;
 
     fn try_from(boxed: Trigger<FilterBox>) -> Result<Self, Self::Error> {
-        if let FilterBox::Pipeline(pipeline_filter) = boxed.action.filter {
-            let action = action::Action::new(
-                boxed.action.executable,
-                boxed.action.repeats,
-                boxed.action.technical_account,
-                pipeline_filter,
-            );
-            Ok(Self {
-                id: boxed.id,
-                action,
-            })
-        } else {
-            Err("Expected `FilterBox::Pipeline`, but another variant found")
-        }
+        let action = boxed.action.try_map_filter(|filter| match filter {
+            FilterBox::Pipeline(pipeline_filter) => Ok(pipeline_filter),
+            _ => Err("Expected `FilterBox::Pipeline`, but another variant found"),
+        })?;
+        Ok(Self {
+            id: boxed.id,
+            action,
+        })
     }
 }
 
@@ -99,20 +87,14 @@ impl TryFrom<Trigger<FilterBox>> for Trigger<TimeEventFilter> {
     type Error = &'static str;
 
     fn try_from(boxed: Trigger<FilterBox>) -> Result<Self, Self::Error> {
-        if let FilterBox::Time(time_filter) = boxed.action.filter {
-            let action = action::Action::new(
-                boxed.action.executable,
-                boxed.action.repeats,
-                boxed.action.technical_account,
-                time_filter,
-            );
-            Ok(Self {
-                id: boxed.id,
-                action,
-            })
-        } else {
-            Err("Expected `FilterBox::Time`, but another variant found")
-        }
+        let action = boxed.action.try_map_filter(|filter| match filter {
+            FilterBox::Time(time_filter) => Ok(time_filter),
+            _ => Err("Expected `FilterBox::Time`, but another variant found"),
+        })?;
+        Ok(Self {
+            id: boxed.id,
+            action,
+        })
     }
 }
 
@@ -120,20 +102,14 @@ impl TryFrom<Trigger<FilterBox>> for Trigger<ExecuteTriggerEventFilter> {
     type Error = &'static str;
 
     fn try_from(boxed: Trigger<FilterBox>) -> Result<Self, Self::Error> {
-        if let FilterBox::ExecuteTrigger(execute_trigger_filter) = boxed.action.filter {
-            let action = action::Action::new(
-                boxed.action.executable,
-                boxed.action.repeats,
-                boxed.action.technical_account,
-                execute_trigger_filter,
-            );
-            Ok(Self {
-                id: boxed.id,
-                action,
-            })
-        } else {
-            Err("Expected `FilterBox::ExecuteTrigger`, but another variant found")
-        }
+        let action = boxed.action.try_map_filter(|filter| match filter {
+            FilterBox::ExecuteTrigger(execute_trigger_filter) => Ok(execute_trigger_filter),
+            _ => Err("Expected `FilterBox::ExecuteTrigger`, but another variant found"),
+        })?;
+        Ok(Self {
+            id: boxed.id,
+            action,
+        })
     }
 }
 
@@ -237,6 +213,26 @@ pub mod action {
         /// Get action metadata
         fn metadata(&self) -> &Metadata;
 
+        /// Get action condition
+        fn condition(&self) -> Option<&EvaluatesTo<bool>>;
+
+        /// Get action failure policy
+        fn failure_policy(&self) -> &FailurePolicy;
+
+        /// Get action execution statistics
+        fn stats(&self) -> &ExecutionStats;
+
+        /// Check if action was disabled by its [`FailurePolicy`]
+        fn is_disabled(&self) -> bool;
+
+        /// Record the outcome of an execution at the block of the given `height`.
+        ///
+        /// Returns `true` if the action got disabled by its [`FailurePolicy`] as a result.
+        fn record_execution(&mut self, height: u64, error: Option<String>) -> bool;
+
+        /// Enable the action disabled by its [`FailurePolicy`], resetting its consecutive failures
+        fn enable(&mut self);
+
         /// Check if action is mintable.
         fn mintable(&self) -> bool;
 
@@ -275,6 +271,26 @@ pub mod action {
         pub filter: F,
         /// Metadata used as persistent storage for trigger data.
         pub metadata: Metadata,
+        /// If some, the `Action` is executed only if the condition evaluates to `true`.
+        /// Otherwise the triggering event is skipped without consuming [`Repeats`].
+        ///
+        /// Besides the built-in context values, the condition can refer to
+        /// [`TRIGGER_AUTHORITY_VALUE`](crate::expression::TRIGGER_AUTHORITY_VALUE),
+        /// [`TRIGGERING_EVENT_ORIGIN_VALUE`](crate::expression::TRIGGERING_EVENT_ORIGIN_VALUE)
+        /// and [`TRIGGER_CALLER_VALUE`](crate::expression::TRIGGER_CALLER_VALUE).
+        #[serde(default)]
+        pub condition: Option<EvaluatesTo<bool>>,
+        /// What to do with the `Action` when its execution fails.
+        #[serde(default)]
+        pub failure_policy: FailurePolicy,
+        /// Execution statistics. Maintained by the peer and reset on registration.
+        #[serde(default)]
+        pub stats: ExecutionStats,
+        /// If `true`, the `Action` is not executed anymore.
+        /// Set by the peer according to the [`FailurePolicy`] and reset on registration
+        /// or by [`EnableTriggerBox`](crate::isi::EnableTriggerBox).
+        #[serde(default)]
+        pub disabled: bool,
     }
 
     impl<F: Filter> HasMetadata for Action<F> {
@@ -298,15 +314,62 @@ pub mod action {
                 technical_account,
                 filter,
                 metadata: Metadata::new(),
+                condition: None,
+                failure_policy: FailurePolicy::default(),
+                stats: ExecutionStats::default(),
+                disabled: false,
             }
         }
 
+        /// Handle execution failures according to `failure_policy`
+        #[must_use]
+        pub fn with_failure_policy(mut self, failure_policy: FailurePolicy) -> Self {
+            self.failure_policy = failure_policy;
+            self
+        }
+
+        /// Execute the action only if `condition` evaluates to `true`
+        #[must_use]
+        pub fn with_condition(mut self, condition: impl Into<EvaluatesTo<bool>>) -> Self {
+            self.condition = Some(condition.into());
+            self
+        }
+
         /// Add [`Metadata`] to the trigger replacing previously defined
         #[must_use]
         pub fn with_metadata(mut self, metadata: Metadata) -> Self {
             self.metadata = metadata;
             self
         }
+
+        /// Convert the filter of the action with `f`, keeping the other fields.
+        pub(super) fn try_map_filter<G: Filter, E>(
+            self,
+            f: impl FnOnce(F) -> Result<G, E>,
+        ) -> Result<Action<G>, E> {
+            let Self {
+                executable,
+                repeats,
+                technical_account,
+                filter,
+                metadata,
+                condition,
+                failure_policy,
+                stats,
+                disabled,
+            } = self;
+            Ok(Action {
+                executable,
+                repeats,
+                technical_account,
+                filter: f(filter)?,
+                metadata,
+                condition,
+                failure_policy,
+                stats,
+                disabled,
+            })
+        }
     }
 
     impl<F: Filter + Into<FilterBox> + Clone> ActionTrait for Action<F> {
@@ -330,26 +393,59 @@ pub mod action {
             &self.metadata
         }
 
+        fn condition(&self) -> Option<&EvaluatesTo<bool>> {
+            self.condition.as_ref()
+        }
+
+        fn failure_policy(&self) -> &FailurePolicy {
+            &self.failure_policy
+        }
+
+        fn stats(&self) -> &ExecutionStats {
+            &self.stats
+        }
+
+        fn is_disabled(&self) -> bool {
+            self.disabled
+        }
+
+        fn record_execution(&mut self, height: u64, error: Option<String>) -> bool {
+            let error = match error {
+                None => {
+                    self.stats.record_success(height);
+                    return false;
+                }
+                Some(error) => error,
+            };
+            self.stats.record_failure(height, error);
+            if self.disabled {
+                return false;
+            }
+            self.disabled = match self.failure_policy {
+                FailurePolicy::Ignore => false,
+                FailurePolicy::DisableAfter(limit) => self.stats.consecutive_failures >= limit,
+            };
+            self.disabled
+        }
+
+        fn enable(&mut self) {
+            self.disabled = false;
+            self.stats.consecutive_failures = 0;
+        }
+
         fn mintable(&self) -> bool {
             self.filter.mintable()
         }
 
         fn into_boxed(self) -> Action<FilterBox> {
-            Action::<FilterBox>::new(
-                self.executable,
-                self.repeats,
-                self.technical_account,
-                self.filter.into(),
-            )
+            match self.try_map_filter(|filter| Ok::<_, core::convert::Infallible>(filter.into())) {
+                Ok(action) => action,
+                Err(infallible) => match infallible {},
+            }
         }
 
         fn clone_and_box(&self) -> Action<FilterBox> {
-            Action::<FilterBox>::new(
-                self.executable.clone(),
-                self.repeats.clone(),
-                self.technical_account.clone(),
-                self.filter.clone().into(),
-            )
+            self.clone().into_boxed()
         }
     }
 
@@ -406,9 +502,76 @@ pub mod action {
         }
     }
 
+    /// Defines what happens to an [`Action`] which execution fails.
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        Encode,
+        Decode,
+        Serialize,
+        Deserialize,
+        IntoSchema,
+    )]
+    pub enum FailurePolicy {
+        /// Keep executing the action regardless of failures.
+        #[default]
+        Ignore,
+        /// Disable the action after the given number of consecutive failures.
+        DisableAfter(u32),
+    }
+
+    /// Execution statistics of an [`Action`].
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        PartialEq,
+        Eq,
+        Hash,
+        Encode,
+        Decode,
+        Serialize,
+        Deserialize,
+        IntoSchema,
+    )]
+    pub struct ExecutionStats {
+        /// Number of successful executions.
+        pub successes: u64,
+        /// Number of failed executions.
+        pub failures: u64,
+        /// Number of failed executions since the last successful one.
+        pub consecutive_failures: u32,
+        /// Error of the last failed execution.
+        pub last_error: Option<String>,
+        /// Height of the block in which the action was last executed.
+        pub last_execution_height: Option<u64>,
+    }
+
+    impl ExecutionStats {
+        /// Record a successful execution at the block of the given `height`
+        pub fn record_success(&mut self, height: u64) {
+            self.successes = self.successes.saturating_add(1);
+            self.consecutive_failures = 0;
+            self.last_execution_height = Some(height);
+        }
+
+        /// Record a failed execution at the block of the given `height`
+        pub fn record_failure(&mut self, height: u64, error: String) {
+            self.failures = self.failures.saturating_add(1);
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+            self.last_error = Some(error);
+            self.last_execution_height = Some(height);
+        }
+    }
+
     pub mod prelude {
         //! Re-exports of commonly used types.
-        pub use super::{Action, ActionTrait, Repeats};
+        pub use super::{Action, ActionTrait, ExecutionStats, FailurePolicy, Repeats};
     }
 }
 
@@ -11,7 +11,7 @@
 #![cfg(feature = "std")]
 #![allow(clippy::expect_used)]
 
-use core::{cmp::min, result::Result};
+use core::{cmp::min, fmt::Display, result::Result};
 
 use dashmap::DashMap;
 use tokio::{sync::RwLock, task};
@@ -202,6 +202,45 @@ impl Set {
         })
     }
 
+    /// Apply `f` to the trigger identified by `id`, allowing to modify it
+    ///
+    /// Returns [`None`] if [`Set`] doesn't contain the trigger with the given `id`.
+    fn inspect_by_id_mut<F, R>(&self, id: &Id, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut dyn ActionTrait) -> R,
+    {
+        self.ids.get(id).map(|pair| match pair.value() {
+            EventType::Data => self
+                .data_triggers
+                .get_mut(id)
+                .map(|mut entry| f(entry.value_mut()))
+                .expect("`Set::data_triggers` doesn't contain required id. This is a bug"),
+            EventType::Pipeline => self
+                .pipeline_triggers
+                .get_mut(id)
+                .map(|mut entry| f(entry.value_mut()))
+                .expect("`Set::pipeline_triggers` doesn't contain required id. This is a bug"),
+            EventType::Time => self
+                .time_triggers
+                .get_mut(id)
+                .map(|mut entry| f(entry.value_mut()))
+                .expect("`Set::time_triggers` doesn't contain required id. This is a bug"),
+            EventType::ExecuteTrigger => self
+                .by_call_triggers
+                .get_mut(id)
+                .map(|mut entry| f(entry.value_mut()))
+                .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+        })
+    }
+
+    /// Enable the trigger identified by `id` if it was disabled by its failure policy.
+    ///
+    /// Returns `false` if [`Set`] doesn't contain the trigger with the given `id`.
+    pub fn enable(&self, id: &Id) -> bool {
+        self.inspect_by_id_mut(id, |action| action.enable())
+            .is_some()
+    }
+
     /// Remove a trigger from the [`Set`].
     ///
     /// Returns `false` if [`Set`] doesn't contain the trigger with the given `id`.
@@ -317,6 +356,9 @@ impl Set {
     pub fn handle_time_event(&self, event: TimeEvent) {
         for entry in &self.time_triggers {
             let action = entry.value();
+            if action.disabled {
+                continue;
+            }
 
             let mut count = action.filter.count_matches(&event);
             if let Repeats::Exactly(atomic) = &action.repeats {
@@ -339,6 +381,7 @@ impl Set {
     /// Match and insert a [`TriggerId`] into the set of matched ids.
     ///
     /// Skips insertion:
+    /// - If the action is disabled
     /// - If the action's filter doesn't match an event
     /// - If the action's repeats count equals to 0
     fn match_and_insert_trigger<E: Into<Event>, F: EventFilter<Event = E>>(
@@ -346,7 +389,7 @@ impl Set {
         event: E,
         (id, action): (&Id, &Action<F>),
     ) {
-        if !action.filter.matches(&event) {
+        if action.disabled || !action.filter.matches(&event) {
             return;
         }
 
@@ -362,22 +405,40 @@ impl Set {
     /// Calls `f` for every action, matched by previously called `handle_` methods.
     /// Decreases action repeats count if inspection succeed.
     ///
+    /// If the action has a condition, `f` is called only if `condition` evaluates it to `true`.
+    /// Otherwise the action is skipped without decreasing its repeats count.
+    ///
+    /// Outcome of every inspection is recorded into the action [`ExecutionStats`] with the
+    /// given block `height`. Actions failed too many times in a row are disabled according
+    /// to their [`FailurePolicy`].
+    ///
     /// Matched actions are cleared after this function call.
     /// If an action was matched by calling `handle_` method and removed before this method call,
     /// then it won't be presented.
     ///
+    /// Returns [`TriggerEvent`]s about failed and disabled actions
+    /// along with the inspection result.
+    ///
     /// # Errors
     /// Returns `Err(Vec<E>)` if one or more error occurred during action inspection.
     ///
     /// Failed actions won't appear on the next `inspect_matched()` call if they don't match new
     /// events by calling `handle_` methods.
     /// Repeats count of failed actions won't be decreased.
-    pub async fn inspect_matched<F, E>(&self, f: F) -> Result<(), Vec<E>>
+    pub async fn inspect_matched<C, F, E>(
+        &self,
+        height: u64,
+        condition: C,
+        f: F,
+    ) -> (Vec<TriggerEvent>, Result<(), Vec<E>>)
     where
+        C: Fn(&EvaluatesTo<bool>, &dyn ActionTrait, &Event) -> std::result::Result<bool, E>
+            + Send
+            + Copy,
         F: Fn(&dyn ActionTrait, Event) -> std::result::Result<(), E> + Send + Copy,
-        E: Send + Sync,
+        E: Display + Send + Sync,
     {
-        let (succeed, res) = self.map_matched(f).await;
+        let (succeed, events, res) = self.map_matched(height, condition, f).await;
 
         for id in &succeed {
             // Ignoring error if trigger has not `Repeats::Exact(_)` but something else
@@ -396,27 +457,48 @@ impl Set {
         self.remove_zeros(&self.time_triggers);
         self.remove_zeros(&self.by_call_triggers);
 
-        res
+        (events, res)
     }
 
     /// Map `f` to every trigger from `self.matched_ids`
+    /// recording the outcome into the trigger [`ExecutionStats`]
     ///
-    /// Returns vector of successfully executed triggers
+    /// Returns vector of successfully executed triggers,
+    /// events about failed and disabled triggers
     /// and result with errors vector if there are some
-    async fn map_matched<F, E>(&self, f: F) -> (Vec<Id>, Result<(), Vec<E>>)
+    async fn map_matched<C, F, E>(
+        &self,
+        height: u64,
+        condition: C,
+        f: F,
+    ) -> (Vec<Id>, Vec<TriggerEvent>, Result<(), Vec<E>>)
     where
+        C: Fn(&EvaluatesTo<bool>, &dyn ActionTrait, &Event) -> std::result::Result<bool, E>
+            + Send
+            + Copy,
         F: Fn(&dyn ActionTrait, Event) -> std::result::Result<(), E> + Send + Copy,
-        E: Send + Sync,
+        E: Display + Send + Sync,
     {
         let mut succeed = Vec::new();
+        let mut events = Vec::new();
         let mut errors = Vec::new();
 
         let apply_f = move |action: &dyn ActionTrait, event: Event| {
+            if action.is_disabled() {
+                return None;
+            }
             if let Repeats::Exactly(atomic) = action.repeats() {
                 if atomic.get() == 0 {
                     return None;
                 }
             }
+            if let Some(action_condition) = action.condition() {
+                match condition(action_condition, action, &event) {
+                    Ok(true) => {}
+                    Ok(false) => return None,
+                    Err(err) => return Some(Err(err)),
+                }
+            }
             Some(f(action, event))
         };
 
@@ -450,8 +532,22 @@ impl Set {
             };
 
             match result.flatten() {
-                Some(Ok(_)) => succeed.push(id),
-                Some(Err(err)) => errors.push(err),
+                Some(Ok(_)) => {
+                    self.inspect_by_id_mut(&id, |action| action.record_execution(height, None));
+                    succeed.push(id);
+                }
+                Some(Err(err)) => {
+                    let disabled = self
+                        .inspect_by_id_mut(&id, |action| {
+                            action.record_execution(height, Some(err.to_string()))
+                        })
+                        .unwrap_or(false);
+                    events.push(TriggerEvent::ExecutionFailed(id.clone()));
+                    if disabled {
+                        events.push(TriggerEvent::Disabled(id));
+                    }
+                    errors.push(err);
+                }
                 None => {}
             };
 
@@ -459,9 +555,9 @@ impl Set {
         }
 
         if errors.is_empty() {
-            return (succeed, Ok(()));
+            return (succeed, events, Ok(()));
         }
-        (succeed, Err(errors))
+        (succeed, events, Err(errors))
     }
 
     /// Remove actions with zero execution count from `triggers`
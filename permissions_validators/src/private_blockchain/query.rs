@@ -25,13 +25,19 @@ impl IsAllowed for OnlyAccountsDomain {
     ) -> ValidatorVerdict {
         use QueryBox::*;
         match query {
-            FindAssetsByAssetDefinitionId(_) | FindAssetsByName(_) | FindAllAssets(_) => {
+            FindAssetsByAssetDefinitionId(_)
+            | FindAssetsByName(_)
+            | FindAssetsByMetadataKeyValue(_)
+            | FindAllAssets(_) => {
                 Deny("Only access to the assets of the same domain is permitted.".to_owned())
             }
-            FindAllAccounts(_) | FindAccountsByName(_) | FindAccountsWithAsset(_) => {
+            FindAllAccounts(_)
+            | FindAccountsByName(_)
+            | FindAccountsByMetadataKeyValue(_)
+            | FindAccountsWithAsset(_) => {
                 Deny("Only access to the accounts of the same domain is permitted.".to_owned())
             }
-            FindAllAssetsDefinitions(_) => Deny(
+            FindAllAssetsDefinitions(_) | FindAssetDefinitionsByMetadataKeyValue(_) => Deny(
                 "Only access to the asset definitions of the same domain is permitted.".to_owned(),
             ),
             FindAllDomains(_) => {
@@ -46,7 +52,17 @@ impl IsAllowed for OnlyAccountsDomain {
                 Deny("Only access to roles of the same domain is permitted.".to_owned())
             }
             FindAllPeers(_) => Allow, // Can be obtained in other ways, so why hide it.
+            FindAllParameters(_) => Allow, // Fees have to be known to submit transactions.
             FindAllActiveTriggerIds(_) => Allow,
+            // Aggregates reveal as much as the aggregated query does.
+            FindAggregate(query) => self.check(authority, &query.query, wsv),
+            // A batch is as permitted as its least permitted query.
+            FindBatch(batch) => batch
+                .queries
+                .iter()
+                .map(|query| self.check(authority, query, wsv))
+                .min()
+                .unwrap_or(Allow),
             // Private blockchains should have debugging too, hence
             // all accounts should also be
             FindTriggerById(query) => {
@@ -111,6 +127,17 @@ impl IsAllowed for OnlyAccountsDomain {
                     ))
                 }
             }
+            FindAccountNextSequenceNumber(query) => {
+                let account_id = try_evaluate_or_deny!(query.id, wsv);
+                if account_id.domain_id == authority.domain_id {
+                    Allow
+                } else {
+                    Deny(format!(
+                        "Cannot access account {} as it is in a different domain.",
+                        account_id
+                    ))
+                }
+            }
             FindAccountKeyValueByIdAndKey(query) => {
                 let account_id = try_evaluate_or_deny!(query.id, wsv);
                 if account_id.domain_id == authority.domain_id {
@@ -224,7 +251,11 @@ impl IsAllowed for OnlyAccountsDomain {
             FindAllBlockHeaders(_) => {
                 Deny("You are not permitted to access all blocks.".to_owned())
             }
-            FindBlockHeaderByHash(_) => {
+            FindBlockHeaderByHash(_)
+            | FindBlockByHeight(_)
+            | FindBlocksInHeightRange(_)
+            | FindTransactionsInBlock(_)
+            | FindLatestBlockHeader(_) => {
                 Deny("You are not permitted to access arbitrary blocks.".to_owned())
             }
             FindAllTransactions(_) => {
@@ -241,7 +272,20 @@ impl IsAllowed for OnlyAccountsDomain {
                     ))
                 }
             }
-            FindTransactionByHash(_query) => Allow,
+            FindTransactionByHash(_)
+            | FindTransactionProofByHash(_)
+            | FindTransactionRejectionReasonByHash(_) => Allow,
+            FindAccountStatement(query) => {
+                let account_id = try_evaluate_or_deny!(query.account_id, wsv);
+                if account_id.domain_id == authority.domain_id {
+                    Allow
+                } else {
+                    Deny(format!(
+                        "Cannot access account {} as it is in a different domain.",
+                        account_id
+                    ))
+                }
+            }
             FindRolesByAccountId(query) => {
                 let account_id = try_evaluate_or_deny!(query.id, wsv);
                 if account_id.domain_id == authority.domain_id {
@@ -300,6 +344,7 @@ impl IsAllowed for OnlyAccountsData {
 
         match query {
             FindAccountsByName(_)
+                | FindAccountsByMetadataKeyValue(_)
                 | FindAccountsByDomainId(_)
                 | FindAccountsWithAsset(_)
                 | FindAllAccounts(_) => {
@@ -312,6 +357,8 @@ impl IsAllowed for OnlyAccountsData {
                 },
             FindAssetsByDomainIdAndAssetDefinitionId(_)
                 | FindAssetsByName(_) // TODO: I think this is a mistake.
+                | FindAssetsByMetadataKeyValue(_)
+                | FindAssetDefinitionsByMetadataKeyValue(_)
                 | FindAssetsByDomainId(_)
                 | FindAllAssetsDefinitions(_)
                 | FindAssetsByAssetDefinitionId(_)
@@ -326,7 +373,7 @@ impl IsAllowed for OnlyAccountsData {
             FindAllActiveTriggerIds(_) | FindTriggersByDomainId(_) => {
                 Deny("Only the access to the triggers of your own account is permitted.".to_owned())
             }
-            FindAllPeers(_) => {
+            FindAllPeers(_) | FindAllParameters(_) => {
                 Deny("Only the access to the local data of your account is permitted.".to_owned())
             }
             FindTriggerById(query) => {
@@ -367,6 +414,17 @@ impl IsAllowed for OnlyAccountsData {
                     ))
                 }
             }
+            FindAccountNextSequenceNumber(query) => {
+                let account_id = try_evaluate_or_deny!(query.id, wsv);
+                if &account_id == authority {
+                    Allow
+                } else {
+                    Deny(format!(
+                        "Cannot access account {} as only access to your own account is permitted..",
+                        account_id
+                    ))
+                }
+            }
             FindAccountKeyValueByIdAndKey(query) => {
                 let account_id = try_evaluate_or_deny!(query.id, wsv);
                 if &account_id == authority {
@@ -433,6 +491,12 @@ impl IsAllowed for OnlyAccountsData {
             FindBlockHeaderByHash(_) => {
                 Deny("Access to arbitrary block headers not permitted".to_owned())
             }
+            FindBlockByHeight(_)
+            | FindBlocksInHeightRange(_)
+            | FindTransactionsInBlock(_)
+            | FindLatestBlockHeader(_) => {
+                Deny("Access to arbitrary blocks not permitted".to_owned())
+            }
             FindAllTransactions(_) => {
                 Deny("Cannot access transactions of another account.".to_owned())
             },
@@ -445,7 +509,17 @@ impl IsAllowed for OnlyAccountsData {
                     Deny(format!("Cannot access another account: {}.", account_id))
                 }
             }
-            FindTransactionByHash(_query) => Allow,
+            FindTransactionByHash(_)
+            | FindTransactionProofByHash(_)
+            | FindTransactionRejectionReasonByHash(_) => Allow,
+            FindAccountStatement(query) => {
+                let account_id = try_evaluate_or_deny!(query.account_id, wsv);
+                if &account_id == authority {
+                    Allow
+                } else {
+                    Deny(format!("Cannot access another account: {}.", account_id))
+                }
+            }
             FindRolesByAccountId(query) => {
                 let account_id = try_evaluate_or_deny!(query.id, wsv);
                 if &account_id == authority {
@@ -455,6 +529,13 @@ impl IsAllowed for OnlyAccountsData {
                 }
             }
             FindAllPermissionTokenDefinitions(_) => Deny("Only the access to the permission tokens of your own account is permitted.".to_owned()),
+            FindAggregate(query) => self.check(authority, &query.query, wsv),
+            FindBatch(batch) => batch
+                .queries
+                .iter()
+                .map(|query| self.check(authority, query, wsv))
+                .min()
+                .unwrap_or(Allow),
             FindPermissionTokensByAccountId(query) => {
                 let account_id = try_evaluate_or_deny!(query.id, wsv);
                 if &account_id == authority {
@@ -11,6 +11,7 @@ use super::*;
 pub mod burn;
 pub mod key_value;
 pub mod mint;
+pub mod parameter;
 pub mod transfer;
 pub mod unregister;
 
@@ -43,6 +44,8 @@ pub enum PredefinedPermissionToken {
     TransferOnlyFixedNumberOfTimesPerPeriod(transfer::CanTransferOnlyFixedNumberOfTimesPerPeriod),
     /// Can un-register asset with the corresponding asset definition.
     UnregisterAssetWithDefinition(unregister::CanUnregisterAssetWithDefinition),
+    /// Can set on-chain parameters.
+    SetParameters(parameter::CanSetParameters),
 }
 
 impl From<PredefinedPermissionToken> for PermissionToken {
@@ -62,6 +65,7 @@ impl From<PredefinedPermissionToken> for PermissionToken {
                 inner.into()
             }
             PredefinedPermissionToken::UnregisterAssetWithDefinition(inner) => inner.into(),
+            PredefinedPermissionToken::SetParameters(inner) => inner.into(),
         }
     }
 }
@@ -83,6 +87,7 @@ pub fn default_permission_token_definitions(
         mint::CanMintUserAssetDefinitions::definition(),
         transfer::CanTransferUserAssets::definition(),
         transfer::CanTransferOnlyFixedNumberOfTimesPerPeriod::definition(),
+        parameter::CanSetParameters::definition(),
     ]
 }
 
@@ -101,6 +106,7 @@ pub fn default_permissions() -> InstructionJudgeBoxed {
             .with_validator(key_value::GrantMyMetadataAccessRemove.into_validator())
             .with_validator(key_value::GrantMyAssetDefinitionSet.into_validator())
             .with_validator(key_value::GrantMyAssetDefinitionRemove.into_validator())
+            .with_validator(parameter::GrantByGenesisOnly.into_validator())
             .no_denies()
             .disable_display_of_operation_on_error()
             .build()
@@ -150,6 +156,9 @@ pub fn default_permissions() -> InstructionJudgeBoxed {
                 key_value::AssetDefinitionRemoveOnlyForSignerAccount
                     .or(key_value::RemoveGrantedByAssetDefinitionOwner.into_validator()),
             )
+            .with_recursive_validator(
+                parameter::OnlyGenesis.or(parameter::GrantedAllowedSetParameters.into_validator()),
+            )
             .no_denies()
             .at_least_one_allow()
             .build(),
@@ -695,6 +704,29 @@ mod tests {
             .is_deny());
     }
 
+    #[test]
+    fn set_parameter_only_by_genesis_or_granted() {
+        let alice_id = AccountId::from_str("alice@test").expect("Valid");
+        let bob_id = AccountId::from_str("bob@test").expect("Valid");
+        let mut domain = Domain::new(DomainId::from_str("test").expect("Valid")).build();
+        let bob_account = Account::new(bob_id.clone(), []).build();
+        assert!(domain.add_account(bob_account).is_none());
+        let wsv = WorldStateView::new(World::with([domain], BTreeSet::new()));
+        assert!(wsv.add_account_permission(&bob_id, parameter::CanSetParameters::new().into()));
+        let set_parameter =
+            Instruction::SetParameter(SetParameterBox::new(Parameter::BlockTime(1000)));
+        let validator =
+            parameter::OnlyGenesis.or(parameter::GrantedAllowedSetParameters.into_validator());
+        assert!(validator.check(&alice_id, &set_parameter, &wsv).is_deny());
+        assert!(validator.check(&bob_id, &set_parameter, &wsv).is_allow());
+        assert!(validator
+            .check(&AccountId::genesis(), &set_parameter, &wsv)
+            .is_allow());
+        assert!(default_permissions()
+            .judge(&alice_id, &set_parameter, &wsv)
+            .is_err());
+    }
+
     #[test]
     fn default_permission_token_definitions_are_unique() {
         let permissions_set = HashSet::from(default_permission_token_definitions());
@@ -0,0 +1,72 @@
+//! Module with permission for setting on-chain parameters
+use super::*;
+
+declare_token!(
+    /// Can set on-chain parameters, e.g. transaction fees.
+    CanSetParameters {},
+    "can_set_parameters"
+);
+
+/// Checks that only the genesis account sets on-chain parameters.
+#[derive(Debug, Display, Copy, Clone, Serialize)]
+#[display(fmt = "Allow to set parameters only for the genesis account")]
+pub struct OnlyGenesis;
+
+impl IsAllowed for OnlyGenesis {
+    type Operation = Instruction;
+
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        _wsv: &WorldStateView,
+    ) -> ValidatorVerdict {
+        if !matches!(instruction, Instruction::SetParameter(_)) {
+            return Skip;
+        }
+
+        if *authority != AccountId::genesis() {
+            return Deny("Only the genesis account can set parameters.".to_owned());
+        }
+        Allow
+    }
+}
+
+/// Allows setting on-chain parameters for accounts with the corresponding permission token.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantedAllowedSetParameters;
+
+impl HasToken for GrantedAllowedSetParameters {
+    type Token = CanSetParameters;
+
+    fn token(
+        &self,
+        _authority: &AccountId,
+        _instruction: &Instruction,
+        _wsv: &WorldStateView,
+    ) -> core::result::Result<Self::Token, String> {
+        Ok(CanSetParameters::new())
+    }
+}
+
+/// Validator that checks Grant instruction so that only the genesis
+/// account can grant the right to set on-chain parameters.
+#[derive(Debug, Display, Copy, Clone, Serialize)]
+#[display(fmt = "the signer is the genesis account")]
+pub struct GrantByGenesisOnly;
+
+impl IsGrantAllowed for GrantByGenesisOnly {
+    type Token = CanSetParameters;
+
+    fn check(
+        &self,
+        authority: &AccountId,
+        _token: Self::Token,
+        _wsv: &WorldStateView,
+    ) -> ValidatorVerdict {
+        if *authority != AccountId::genesis() {
+            return Deny("Only the genesis account can grant setting parameters.".to_owned());
+        }
+        Allow
+    }
+}
@@ -62,8 +62,10 @@ pub fn generate_map() -> DumpDecodedMap {
         AccountEventFilter,
         AccountFilter,
         AccountId,
+        AccountStatementEntry,
         Action<FilterBox>,
         Add,
+        AggregateFunction,
         And,
         Asset,
         AssetDefinition,
@@ -72,6 +74,7 @@ pub fn generate_map() -> DumpDecodedMap {
         AssetDefinitionEventFilter,
         AssetDefinitionFilter,
         AssetDefinitionId,
+        AssetDelta,
         AssetEvent,
         AssetEventFilter,
         AssetFilter,
@@ -82,6 +85,7 @@ pub fn generate_map() -> DumpDecodedMap {
         BTreeMap<AccountId, Account>,
         BTreeMap<AssetDefinitionId, AssetDefinitionEntry>,
         BTreeMap<AssetId, Asset>,
+        BTreeMap<InstructionKind, u32>,
         BTreeMap<Name, Value>,
         BTreeMap<PublicKey, SignatureOf<block::CommittedBlock>>,
         BTreeMap<PublicKey, SignatureOf<sumeragi::view_change::Proof>>,
@@ -94,12 +98,17 @@ pub fn generate_map() -> DumpDecodedMap {
         BTreeSet<permissions::PermissionToken>,
         BlockHeaderValue,
         BlockRejectionReason,
+        BlockSchedule,
+        BlockScheduleStart,
         BlockValue,
         BurnBox,
+        ChainId,
         Contains,
         ContainsAll,
         ContainsAny,
         ContextValue,
+        Cron,
+        Cursor,
         DataEntityFilter,
         DataEvent,
         DataEventFilter,
@@ -109,14 +118,21 @@ pub fn generate_map() -> DumpDecodedMap {
         DomainEventFilter,
         DomainFilter,
         DomainId,
+        EnableTriggerBox,
+        EncodedBlockHeader,
         Equal,
+        EventSubscriptionRequest,
         Executable,
         ExecuteTriggerBox,
         ExecuteTriggerEvent,
         ExecuteTriggerEventFilter,
+        ExecutionStats,
         ExecutionTime,
         Expression,
         FailBox,
+        FailurePolicy,
+        FeePaymentFail,
+        FeeSchedule,
         FilterOpt<AccountEventFilter>,
         FilterOpt<AccountFilter>,
         FilterOpt<AssetDefinitionEventFilter>,
@@ -140,9 +156,13 @@ pub fn generate_map() -> DumpDecodedMap {
         FilterOpt<TriggerFilter>,
         FindAccountById,
         FindAccountKeyValueByIdAndKey,
+        FindAccountStatement,
+        FindAccountNextSequenceNumber,
         FindAccountsByDomainId,
+        FindAccountsByMetadataKeyValue,
         FindAccountsByName,
         FindAccountsWithAsset,
+        FindAggregate,
         FindAllAccounts,
         FindAllActiveTriggerIds,
         FindAllAssets,
@@ -159,31 +179,42 @@ pub fn generate_map() -> DumpDecodedMap {
         FindAssetById,
         FindAssetDefinitionById,
         FindAssetDefinitionKeyValueByIdAndKey,
+        FindAssetDefinitionsByMetadataKeyValue,
         FindAssetKeyValueByIdAndKey,
         FindAssetQuantityById,
         FindAssetsByAccountId,
         FindAssetsByAssetDefinitionId,
         FindAssetsByDomainId,
         FindAssetsByDomainIdAndAssetDefinitionId,
+        FindAssetsByMetadataKeyValue,
         FindAssetsByName,
+        FindBatch,
+        FindBlockByHeight,
         FindBlockHeaderByHash,
+        FindBlocksInHeightRange,
         FindDomainById,
         FindDomainKeyValueByIdAndKey,
+        FindLatestBlockHeader,
         FindPermissionTokensByAccountId,
         FindRoleByRoleId,
         FindRolesByAccountId,
         FindTransactionByHash,
+        FindTransactionProofByHash,
+        FindTransactionRejectionReasonByHash,
         FindTransactionsByAccountId,
+        FindTransactionsInBlock,
         FindTriggerById,
         FindTriggerKeyValueByIdAndKey,
         FindTriggersByDomainId,
         GenesisDomain,
         GrantBox,
         Greater,
+        GroupBy,
         Hash,
         HashOf<MerkleTree<transaction::VersionedTransaction>>,
         HashOf<block::VersionedCommittedBlock>,
         HashOf<block::VersionedValidBlock>,
+        HashOf<query::Payload>,
         HashOf<sumeragi::view_change::Proof>,
         HashOf<transaction::VersionedTransaction>,
         IdBox,
@@ -199,7 +230,10 @@ pub fn generate_map() -> DumpDecodedMap {
         IfInstruction,
         Instruction,
         InstructionExecutionFail,
+        InstructionKind,
+        InstructionLocation,
         Less,
+        MerkleProof<transaction::VersionedTransaction>,
         Metadata,
         MetadataLimits,
         MintBox,
@@ -208,16 +242,31 @@ pub fn generate_map() -> DumpDecodedMap {
         Name,
         Not,
         NotPermittedFail,
+        Option<AccountId>,
+        Option<Cron>,
+        Option<Cursor>,
+        Option<GroupBy>,
         Option<Hash>,
+        Option<HashOf<transaction::VersionedTransaction>>,
+        Option<IdentifiableBox>,
+        Option<Name>,
+        Option<QueryResponseSignature>,
+        Option<String>,
+        Option<TransactionRejectionReason>,
+        Option<WasmTrap>,
         Option<core::time::Duration>,
         Option<domain::Id>,
         Option<domain::IpfsPath>,
         Option<events::pipeline::EntityKind>,
         Option<events::pipeline::StatusKind>,
         Option<events::time::Interval>,
+        Option<expression::EvaluatesTo<AssetDefinitionId>>,
+        Option<expression::EvaluatesTo<bool>>,
+        Option<expression::EvaluatesTo<u128>>,
         Option<isi::Instruction>,
         Option<sumeragi::network_topology::Topology>,
         Option<u32>,
+        Option<u64>,
         Or,
         PaginatedQueryResult,
         Pagination,
@@ -238,10 +287,16 @@ pub fn generate_map() -> DumpDecodedMap {
         PipelineEvent,
         PipelineEventFilter,
         PipelineStatus,
+        Projection,
         PublicKey,
         QueryBox,
+        QueryDelta,
+        QueryPublisherMessage,
         QueryRequest,
+        QueryResponsePayload,
+        QueryResponseSignature,
         QueryResult,
+        QuerySubscriberMessage,
         RaiseTo,
         RawVersioned,
         RegisterBox,
@@ -257,18 +312,27 @@ pub fn generate_map() -> DumpDecodedMap {
         RoleFilter,
         RoleId,
         SequenceBox,
+        SequenceNumberFail,
+        SequencedEvent,
         SetKeyValueBox,
+        SetParameterBox,
         Signature,
         SignatureCheckCondition,
         SignatureOf<block::CommittedBlock>,
         SignatureOf<block::ValidBlock>,
         SignatureOf<query::Payload>,
+        SignatureOf<QueryResponsePayload>,
         SignatureOf<sumeragi::view_change::Proof>,
         SignatureOf<transaction::Payload>,
         SignaturesOf<block::CommittedBlock>,
         SignaturesOf<sumeragi::view_change::Proof>,
         SignaturesOf<transaction::Payload>,
         SignedQueryRequest,
+        SortField,
+        SortKey,
+        SortOrder,
+        Sorting,
+        StateChange,
         String,
         Subtract,
         TimeEvent,
@@ -276,7 +340,9 @@ pub fn generate_map() -> DumpDecodedMap {
         TimeInterval,
         TimeSchedule,
         Transaction,
+        TransactionProof,
         TransactionRejectionReason,
+        TransactionSimulation,
         TransactionValue,
         TransactionQueryResult,
         TransferBox,
@@ -290,11 +356,18 @@ pub fn generate_map() -> DumpDecodedMap {
         UnsupportedVersion,
         ValidTransaction,
         Value,
+        Vec<DataEvent>,
         Vec<Hash>,
         Vec<HashOf<block::VersionedValidBlock>>,
+        Vec<Option<HashOf<transaction::VersionedTransaction>>>,
         Vec<PeerId>,
+        Vec<Signature>,
+        Vec<SortField>,
+        Vec<SortKey>,
         Vec<SignatureOf<block::ValidBlock>>,
         Vec<SignatureOf<transaction::Payload>>,
+        Vec<StateChange>,
+        Vec<String>,
         Vec<Value>,
         Vec<events::Event>,
         Vec<iroha_data_model::predicate::PredicateBox>,
@@ -306,15 +379,20 @@ pub fn generate_map() -> DumpDecodedMap {
         Vec<transaction::VersionedValidTransaction>,
         Vec<transaction::TransactionValue>,
         Vec<transaction::TransactionQueryResult>,
+        Vec<u32>,
         Vec<u8>,
         VersionedPaginatedQueryResult,
         VersionedPendingTransactions,
+        VersionedQueryPublisherMessage,
         VersionedQueryResult,
+        VersionedQuerySubscriberMessage,
         VersionedRejectedTransaction,
         VersionedSignedQueryRequest,
         VersionedTransaction,
+        VersionedTransactionSimulation,
         VersionedValidTransaction,
         WasmExecutionFail,
+        WasmTrap,
         Where,
         [u8; 32],
         u16,
@@ -383,6 +461,11 @@ pub fn generate_map() -> DumpDecodedMap {
         iroha_data_model::predicate::value::Container,
         iroha_data_model::predicate::value::Predicate,
         iroha_data_model::predicate::value::ValueOfKey,
+        iroha_data_model::predicate::account::AssetQuantity,
+        iroha_data_model::predicate::account::Predicate,
+        iroha_data_model::predicate::asset_definition::Predicate,
+        iroha_data_model::predicate::trigger::Predicate,
+        iroha_data_model::predicate::transaction::Predicate,
         query::Payload,
         role::NewRole,
         smartcontracts::isi::error::FindError,